@@ -0,0 +1,185 @@
+use crate::{DefaultHashBuilder, Symbol};
+
+use std::{
+    collections::HashMap,
+    hash::BuildHasher,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, OnceLock, PoisonError,
+    },
+};
+
+/// The number of bits of the length of the first segment.
+const FIRST_SEGMENT_BITS: u32 = 5;
+
+/// The number of segments required to address the entire `usize` index space.
+const SEGMENTS: usize = (usize::BITS - FIRST_SEGMENT_BITS) as usize;
+
+/// A segment of the append-only store. Every entry is written exactly once.
+type Segment = Box<[OnceLock<Arc<str>>]>;
+
+/// An append-only store of strings indexed by `usize`.
+///
+/// Strings are stored in segments with geometrically growing sizes which are never
+/// reallocated, so stored strings can be read wait-free while others are written.
+#[derive(Debug)]
+pub(crate) struct Segments {
+    segments: [OnceLock<Segment>; SEGMENTS],
+}
+
+impl Segments {
+    /// Creates a new empty store without allocating any segments.
+    pub fn new() -> Self {
+        Segments {
+            segments: std::array::from_fn(|_| OnceLock::new()),
+        }
+    }
+
+    /// Stores the given string at the given index.
+    ///
+    /// # Panics
+    ///
+    /// If the index is out of the addressable range or has already been written.
+    pub fn set(&self, index: usize, string: Arc<str>) {
+        let (segment, offset) =
+            locate(index).expect("append-only store exceeded its addressable range");
+        let segment = self.segments[segment].get_or_init(|| {
+            (0..(1 << (segment as u32 + FIRST_SEGMENT_BITS)))
+                .map(|_| OnceLock::new())
+                .collect()
+        });
+        if segment[offset].set(string).is_err() {
+            unreachable!("entries of the append-only store are written exactly once")
+        }
+    }
+
+    /// Returns the string stored at the given index if it has been written.
+    ///
+    /// This never blocks.
+    pub fn get(&self, index: usize) -> Option<&Arc<str>> {
+        let (segment, offset) = locate(index)?;
+        self.segments.get(segment)?.get()?.get(offset)?.get()
+    }
+}
+
+/// A string interner optimized for read-heavy workloads across threads.
+///
+/// Strings are stored in an append-only list of segments with geometrically growing sizes.
+/// Segments are never reallocated, so resolved strings are never invalidated and resolution
+/// is wait-free: it performs only a few atomic loads and never takes a lock.
+///
+/// # Note
+///
+/// Interning takes `&self` and is serialized on an internal lock.
+/// It never blocks concurrent resolution of already interned strings.
+#[derive(Debug)]
+pub struct AppendOnlyStringInterner<S, H = DefaultHashBuilder>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    map: Mutex<HashMap<Arc<str>, S, H>>,
+    segments: Segments,
+    len: AtomicUsize,
+}
+
+/// Returns the segment and the offset within it for the given index.
+///
+/// Returns `None` if the index is out of the addressable range.
+fn locate(index: usize) -> Option<(usize, usize)> {
+    let biased = index.checked_add(1 << FIRST_SEGMENT_BITS)?;
+    let bits = usize::BITS - 1 - biased.leading_zeros();
+    Some(((bits - FIRST_SEGMENT_BITS) as usize, biased - (1 << bits)))
+}
+
+impl<S> Default for AppendOnlyStringInterner<S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn default() -> Self {
+        AppendOnlyStringInterner::new()
+    }
+}
+
+impl<S> AppendOnlyStringInterner<S>
+where
+    S: Symbol,
+{
+    /// Creates a new empty `AppendOnlyStringInterner`.
+    #[inline]
+    pub fn new() -> Self {
+        AppendOnlyStringInterner::with_hasher(DefaultHashBuilder::default())
+    }
+}
+
+impl<S, H> AppendOnlyStringInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `AppendOnlyStringInterner` with the given hasher.
+    pub fn with_hasher(hash_builder: H) -> Self {
+        AppendOnlyStringInterner {
+            map: Mutex::new(HashMap::with_hasher(hash_builder)),
+            segments: Segments::new(),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    /// Interns the given value.
+    ///
+    /// Returns a symbol to access it within this interner.
+    ///
+    /// The contents of the string are copied only if it has not been interned before.
+    pub fn get_or_intern<T>(&self, val: T) -> S
+    where
+        T: AsRef<str>,
+    {
+        // Poisoning can be ignored since the symbol is created before any mutation.
+        let mut map = self.map.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(&sym) = map.get(val.as_ref()) {
+            return sym;
+        }
+        let index = self.len.load(Ordering::Relaxed);
+        let sym = S::from_usize(index);
+        let new_val: Arc<str> = Arc::from(val.as_ref());
+        self.segments.set(index, Arc::clone(&new_val));
+        map.insert(new_val, sym);
+        self.len.store(index + 1, Ordering::Release);
+        sym
+    }
+
+    /// Returns the symbol associated with the given string for this interner
+    /// if existent, otherwise returns `None`.
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        self.map
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(val.as_ref())
+            .cloned()
+    }
+
+    /// Returns the string slice associated with the given symbol if available,
+    /// otherwise returns `None`.
+    ///
+    /// This never blocks and is safe to call while other threads are interning.
+    pub fn resolve(&self, symbol: S) -> Option<&str> {
+        self.segments.get(symbol.to_usize()).map(AsRef::as_ref)
+    }
+
+    /// Returns the number of uniquely interned strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Acquire)
+    }
+
+    /// Returns true if the string interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{DefaultHashBuilder, Symbol};
+
+use std::{cell::RefCell, collections::HashMap, hash::BuildHasher};
+
+/// The capacity of the first chunk of a `StringArena`.
+const FIRST_CHUNK_SIZE: usize = 1024;
+
+/// An arena that allocates strings into large chunks which are freed all at once.
+///
+/// Allocation takes `&self` so that the arena can be shared by an `ArenaStringInterner`
+/// and other arena-allocated data structures such as AST nodes.
+///
+/// # Note
+///
+/// Chunks never grow beyond their initial capacity, so allocated strings never move
+/// and stay valid for as long as the arena is borrowed.
+#[derive(Debug, Default)]
+pub struct StringArena {
+    chunks: RefCell<Vec<String>>,
+}
+
+impl StringArena {
+    /// Creates a new empty `StringArena`.
+    #[inline]
+    pub fn new() -> Self {
+        StringArena::default()
+    }
+
+    /// Copies the given string into the arena and returns a reference to the copy.
+    pub fn alloc_str(&self, string: &str) -> &str {
+        let mut chunks = self.chunks.borrow_mut();
+        let fits = chunks
+            .last()
+            .map(|chunk| chunk.capacity() - chunk.len() >= string.len())
+            .unwrap_or(false);
+        if !fits {
+            let next_size = chunks
+                .last()
+                .map(|chunk| chunk.capacity() * 2)
+                .unwrap_or(FIRST_CHUNK_SIZE);
+            chunks.push(String::with_capacity(std::cmp::max(
+                next_size,
+                string.len(),
+            )));
+        }
+        let chunk = chunks.last_mut().expect("a chunk has been pushed above");
+        let start = chunk.len();
+        chunk.push_str(string);
+        let allocated: *const str = &chunk[start..];
+        // This is safe since chunks never reallocate because they never grow beyond
+        // their capacity and are only dropped together with the arena.
+        unsafe { &*allocated }
+    }
+
+    /// Returns the number of bytes allocated by the arena.
+    #[inline]
+    pub fn allocated_bytes(&self) -> usize {
+        self.chunks.borrow().iter().map(String::capacity).sum()
+    }
+}
+
+/// A string interner that stores its strings in a borrowed `StringArena`.
+///
+/// Resolved strings borrow from the arena instead of the interner and therefore
+/// may outlive the interner itself.
+#[derive(Debug)]
+pub struct ArenaStringInterner<'a, S, H = DefaultHashBuilder>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    arena: &'a StringArena,
+    map: HashMap<&'a str, S, H>,
+    values: Vec<&'a str>,
+}
+
+impl<'a, S> ArenaStringInterner<'a, S>
+where
+    S: Symbol,
+{
+    /// Creates a new empty `ArenaStringInterner` storing its strings in the given arena.
+    #[inline]
+    pub fn new(arena: &'a StringArena) -> Self {
+        ArenaStringInterner::with_hasher(arena, DefaultHashBuilder::default())
+    }
+}
+
+impl<'a, S, H> ArenaStringInterner<'a, S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `ArenaStringInterner` with the given hasher
+    /// storing its strings in the given arena.
+    #[inline]
+    pub fn with_hasher(arena: &'a StringArena, hash_builder: H) -> Self {
+        ArenaStringInterner {
+            arena,
+            map: HashMap::with_hasher(hash_builder),
+            values: Vec::new(),
+        }
+    }
+
+    /// Returns the arena this interner stores its strings in.
+    #[inline]
+    pub fn arena(&self) -> &'a StringArena {
+        self.arena
+    }
+
+    /// Interns the given value.
+    ///
+    /// Returns a symbol to access it within this interner.
+    /// The value is copied into the arena only if it has not been interned before.
+    pub fn get_or_intern<T>(&mut self, val: T) -> S
+    where
+        T: AsRef<str>,
+    {
+        let val = val.as_ref();
+        if let Some(&sym) = self.map.get(val) {
+            return sym;
+        }
+        let sym = S::from_usize(self.values.len());
+        let val = self.arena.alloc_str(val);
+        self.values.push(val);
+        self.map.insert(val, sym);
+        sym
+    }
+
+    /// Returns the symbol associated with the given string for this interner
+    /// if existent, otherwise returns `None`.
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        self.map.get(val.as_ref()).cloned()
+    }
+
+    /// Returns the string slice associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&'a str> {
+        self.values.get(symbol.to_usize()).cloned()
+    }
+
+    /// Returns the number of uniquely interned strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if the string interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,179 @@
+use super::{Backend, StableBackend};
+use crate::{StringInterner, Symbol};
+
+use std::{
+    collections::TryReserveError, fmt, hash::BuildHasher, iter, marker, mem, sync::Arc, vec,
+};
+
+/// A backend that stores every interned string as a reference counted `Arc<str>`.
+///
+/// # Note
+///
+/// Strings that are already held in an `Arc<str>` are interned without copying
+/// and keep being shared with the caller.
+pub struct ArcBackend<S> {
+    values: Vec<Option<Arc<str>>>,
+    mark: marker::PhantomData<S>,
+}
+
+impl<S> fmt::Debug for ArcBackend<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ArcBackend")
+            .field("values", &self.values)
+            .finish()
+    }
+}
+
+impl<S> Default for ArcBackend<S> {
+    #[inline]
+    fn default() -> Self {
+        ArcBackend {
+            values: Vec::new(),
+            mark: marker::PhantomData,
+        }
+    }
+}
+
+impl<S> Clone for ArcBackend<S> {
+    fn clone(&self) -> Self {
+        ArcBackend {
+            values: self.values.clone(),
+            mark: marker::PhantomData,
+        }
+    }
+}
+
+impl<S> ArcBackend<S>
+where
+    S: Symbol,
+{
+    /// Returns a shared reference to the string associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve_arc(&self, symbol: S) -> Option<Arc<str>> {
+        self.values.get(symbol.to_usize())?.clone()
+    }
+}
+
+impl<S, H> StringInterner<S, ArcBackend<S>, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Returns a shared reference to the string associated with the given symbol if available,
+    /// otherwise returns `None`.
+    ///
+    /// The returned string may outlive the interner and be sent across threads
+    /// without copying its contents.
+    #[inline]
+    pub fn resolve_arc(&self, symbol: S) -> Option<Arc<str>> {
+        self.backend.resolve_arc(symbol)
+    }
+}
+
+impl<S> Backend<S> for ArcBackend<S>
+where
+    S: Symbol,
+{
+    type IntoStrings =
+        iter::Map<vec::IntoIter<Option<Arc<str>>>, fn(Option<Arc<str>>) -> Option<String>>;
+
+    #[inline]
+    fn with_capacity(cap: usize) -> Self {
+        ArcBackend {
+            values: Vec::with_capacity(cap),
+            mark: marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    fn intern(&mut self, string: &str) -> S {
+        self.intern_arc(Arc::from(string))
+    }
+
+    #[inline]
+    fn intern_arc(&mut self, string: Arc<str>) -> S {
+        let symbol = S::from_usize(self.values.len());
+        self.values.push(Some(string));
+        symbol
+    }
+
+    #[inline]
+    fn resolve(&self, symbol: S) -> Option<&str> {
+        self.values.get(symbol.to_usize())?.as_deref()
+    }
+
+    #[inline]
+    unsafe fn resolve_unchecked(&self, symbol: S) -> &str {
+        self.values
+            .get_unchecked(symbol.to_usize())
+            .as_deref()
+            .unwrap_unchecked()
+    }
+
+    #[inline]
+    fn remove(&mut self, symbol: S) -> Option<String> {
+        self.values
+            .get_mut(symbol.to_usize())?
+            .take()
+            .map(|string| String::from(&*string))
+    }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        self.values.truncate(len)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.values.capacity()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional)
+    }
+
+    #[inline]
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.values.try_reserve(additional)
+    }
+
+    #[inline]
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.values.shrink_to(min_capacity)
+    }
+
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.values.shrink_to_fit()
+    }
+
+    #[inline]
+    fn values_heap_bytes(&self) -> usize {
+        self.values.capacity() * mem::size_of::<Option<Arc<str>>>()
+    }
+
+    fn strings_heap_bytes(&self) -> usize {
+        // Every `Arc<str>` allocation holds two reference counts in front of the string.
+        self.values
+            .iter()
+            .flatten()
+            .map(|string| 2 * mem::size_of::<usize>() + string.len())
+            .sum()
+    }
+
+    fn into_strings(self) -> Self::IntoStrings {
+        self.values
+            .into_iter()
+            .map(|value| value.map(|string| String::from(&*string)))
+    }
+}
+
+// The contents of every `Arc<str>` never move while it is shared.
+unsafe impl<S> StableBackend<S> for ArcBackend<S> where S: Symbol {}
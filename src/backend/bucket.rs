@@ -0,0 +1,260 @@
+use super::{Backend, StableBackend};
+use crate::Symbol;
+
+use std::{collections::TryReserveError, fmt, marker, mem, vec};
+
+/// The default number of bytes of a single bucket.
+const DEFAULT_BUCKET_SIZE: usize = 4096;
+
+/// The bucket index of the spans of removed strings.
+const REMOVED: usize = usize::MAX;
+
+/// The location of an interned string within the buckets.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Span {
+    bucket: usize,
+    start: usize,
+    end: usize,
+}
+
+/// A backend that allocates interned strings into large fixed-size buckets.
+///
+/// # Note
+///
+/// Instead of one heap allocation per interned string this backend only allocates
+/// once per bucket. Strings that do not fit into a single bucket are stored in their
+/// own dedicated bucket. This greatly reduces allocator overhead and fragmentation
+/// for interners holding many short strings.
+///
+/// Removing a string only frees memory if it has its own dedicated bucket.
+pub struct BucketBackend<S> {
+    spans: Vec<Span>,
+    buckets: Vec<String>,
+    /// The index of the bucket that is currently being filled.
+    head: Option<usize>,
+    bucket_size: usize,
+    mark: marker::PhantomData<S>,
+}
+
+impl<S> fmt::Debug for BucketBackend<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BucketBackend")
+            .field("spans", &self.spans)
+            .field("buckets", &self.buckets)
+            .field("head", &self.head)
+            .field("bucket_size", &self.bucket_size)
+            .finish()
+    }
+}
+
+impl<S> Default for BucketBackend<S> {
+    #[inline]
+    fn default() -> Self {
+        BucketBackend::with_bucket_size(DEFAULT_BUCKET_SIZE)
+    }
+}
+
+impl<S> Clone for BucketBackend<S> {
+    fn clone(&self) -> Self {
+        BucketBackend {
+            spans: self.spans.clone(),
+            buckets: self.buckets.clone(),
+            head: self.head,
+            bucket_size: self.bucket_size,
+            mark: marker::PhantomData,
+        }
+    }
+}
+
+impl<S> BucketBackend<S> {
+    /// Creates a new empty backend that allocates buckets of the given number of bytes.
+    #[inline]
+    pub fn with_bucket_size(bucket_size: usize) -> Self {
+        BucketBackend {
+            spans: Vec::new(),
+            buckets: Vec::new(),
+            head: None,
+            bucket_size,
+            mark: marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of buckets allocated by the backend.
+    #[inline]
+    pub fn num_buckets(&self) -> usize {
+        self.buckets.len()
+    }
+
+    /// Returns the string at the given index.
+    #[inline]
+    fn get(&self, index: usize) -> Option<&str> {
+        let span = self.spans.get(index)?;
+        Some(&self.buckets.get(span.bucket)?[span.start..span.end])
+    }
+
+    /// Returns the index of a bucket with at least `len` bytes of free capacity.
+    ///
+    /// Allocates a new head bucket if the current head bucket is exhausted.
+    fn bucket_for(&mut self, len: usize) -> usize {
+        if let Some(head) = self.head {
+            let bucket = &self.buckets[head];
+            if bucket.capacity() - bucket.len() >= len {
+                return head;
+            }
+        }
+        let head = self.push_bucket(String::with_capacity(std::cmp::max(len, self.bucket_size)));
+        self.head = Some(head);
+        head
+    }
+
+    /// Pushes the given bucket and returns its index.
+    fn push_bucket(&mut self, bucket: String) -> usize {
+        self.buckets.push(bucket);
+        self.buckets.len() - 1
+    }
+}
+
+impl<S> Backend<S> for BucketBackend<S>
+where
+    S: Symbol,
+{
+    type IntoStrings = vec::IntoIter<Option<String>>;
+
+    #[inline]
+    fn with_capacity(cap: usize) -> Self {
+        let mut backend = BucketBackend::default();
+        backend.spans.reserve(cap);
+        backend
+    }
+
+    fn intern(&mut self, string: &str) -> S {
+        let symbol = S::from_usize(self.spans.len());
+        let bucket = self.bucket_for(string.len());
+        let head = &mut self.buckets[bucket];
+        let start = head.len();
+        head.push_str(string);
+        self.spans.push(Span {
+            bucket,
+            start,
+            end: head.len(),
+        });
+        symbol
+    }
+
+    fn intern_string(&mut self, string: String) -> S {
+        if string.len() < self.bucket_size {
+            return self.intern(&string);
+        }
+        // Large strings are taken over as their own bucket without copying.
+        let symbol = S::from_usize(self.spans.len());
+        let end = string.len();
+        let bucket = self.push_bucket(string);
+        self.spans.push(Span {
+            bucket,
+            start: 0,
+            end,
+        });
+        symbol
+    }
+
+    #[inline]
+    fn resolve(&self, symbol: S) -> Option<&str> {
+        self.get(symbol.to_usize())
+    }
+
+    #[inline]
+    unsafe fn resolve_unchecked(&self, symbol: S) -> &str {
+        let span = self.spans.get_unchecked(symbol.to_usize());
+        self.buckets
+            .get_unchecked(span.bucket)
+            .get_unchecked(span.start..span.end)
+    }
+
+    fn remove(&mut self, symbol: S) -> Option<String> {
+        let span = self.spans.get_mut(symbol.to_usize())?;
+        let bucket = self.buckets.get_mut(span.bucket)?;
+        let dedicated =
+            self.head != Some(span.bucket) && span.start == 0 && span.end == bucket.len();
+        let string = if dedicated {
+            mem::take(bucket)
+        } else {
+            bucket[span.start..span.end].to_owned()
+        };
+        span.bucket = REMOVED;
+        Some(string)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        // Spans are allocated in order, so bytes can be reclaimed from the back.
+        while self.spans.len() > len {
+            let span = self.spans.pop().expect("spans cannot be empty");
+            if span.bucket == REMOVED {
+                continue;
+            }
+            let bucket = &mut self.buckets[span.bucket];
+            if span.end != bucket.len() {
+                continue;
+            }
+            if self.head == Some(span.bucket) {
+                bucket.truncate(span.start);
+            } else if span.start == 0 {
+                mem::take(bucket);
+                if span.bucket + 1 == self.buckets.len() {
+                    self.buckets.pop();
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.spans.capacity()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        self.spans.reserve(additional)
+    }
+
+    #[inline]
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.spans.try_reserve(additional)
+    }
+
+    #[inline]
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.spans.shrink_to(min_capacity)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.spans.shrink_to_fit();
+        self.buckets.shrink_to_fit();
+        if let Some(head) = self.head.take() {
+            self.buckets[head].shrink_to_fit();
+        }
+    }
+
+    fn values_heap_bytes(&self) -> usize {
+        self.spans.capacity() * mem::size_of::<Span>()
+            + self.buckets.capacity() * mem::size_of::<String>()
+    }
+
+    fn strings_heap_bytes(&self) -> usize {
+        self.buckets.iter().map(String::capacity).sum()
+    }
+
+    fn into_strings(self) -> Self::IntoStrings {
+        (0..self.spans.len())
+            .map(|index| self.get(index).map(str::to_owned))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+// Buckets are never grown beyond their capacity, so their contents never move.
+unsafe impl<S> StableBackend<S> for BucketBackend<S> where S: Symbol {}
@@ -0,0 +1,300 @@
+use super::Backend;
+use crate::{StringInterner, Symbol};
+
+use std::{
+    collections::TryReserveError, convert::TryFrom, fmt, hash::BuildHasher, marker, mem, vec,
+};
+
+/// The location of an interned string within the buffer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Span {
+    offset: u32,
+    len: u32,
+}
+
+/// The span of removed strings. It is never valid since it exceeds the addressable range.
+const REMOVED: Span = Span {
+    offset: u32::MAX,
+    len: u32::MAX,
+};
+
+/// A symbol that directly encodes the location of its string within a `BufferBackend`.
+///
+/// Obtained by `StringInterner::get_or_intern_span` or `StringInterner::span_of`.
+///
+/// # Note
+///
+/// Resolving a `SpanSym` does not look up any table. It is a mere bounds check
+/// followed by the construction of the string slice. In exchange it has a memory
+/// footprint of 64 bits and is not densely allocated, so it does not implement `Symbol`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SpanSym {
+    offset: u32,
+    len: u32,
+}
+
+impl SpanSym {
+    /// Returns the byte offset of the string within the buffer.
+    #[inline]
+    pub fn offset(self) -> usize {
+        self.offset as usize
+    }
+
+    /// Returns the length of the string in bytes.
+    #[inline]
+    pub fn len(self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns true if the string of this symbol is empty.
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        self.len == 0
+    }
+}
+
+/// A backend that appends all interned strings to a single contiguous buffer.
+///
+/// # Note
+///
+/// Every interned string is identified by its 32-bit byte offset and length
+/// within the buffer. This avoids pointer chasing on resolution and has
+/// a very small per-string overhead of 8 bytes.
+/// Removing a string does not free its bytes within the buffer.
+///
+/// # Panics
+///
+/// Interning panics if the total size of all interned strings exceeds `u32::MAX` bytes.
+pub struct BufferBackend<S> {
+    spans: Vec<Span>,
+    buffer: String,
+    mark: marker::PhantomData<S>,
+}
+
+impl<S> fmt::Debug for BufferBackend<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BufferBackend")
+            .field("spans", &self.spans)
+            .field("buffer", &self.buffer)
+            .finish()
+    }
+}
+
+impl<S> Default for BufferBackend<S> {
+    #[inline]
+    fn default() -> Self {
+        BufferBackend {
+            spans: Vec::new(),
+            buffer: String::new(),
+            mark: marker::PhantomData,
+        }
+    }
+}
+
+impl<S> Clone for BufferBackend<S> {
+    fn clone(&self) -> Self {
+        BufferBackend {
+            spans: self.spans.clone(),
+            buffer: self.buffer.clone(),
+            mark: marker::PhantomData,
+        }
+    }
+}
+
+impl<S> BufferBackend<S> {
+    /// Returns the number of bytes of all interned strings.
+    #[inline]
+    pub fn buffer_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns the string at the given index.
+    #[inline]
+    fn get(&self, index: usize) -> Option<&str> {
+        let span = self.spans.get(index).filter(|&&span| span != REMOVED)?;
+        let offset = span.offset as usize;
+        Some(&self.buffer[offset..offset + span.len as usize])
+    }
+
+    /// Returns the span symbol of the string with the given index.
+    #[inline]
+    fn span_sym(&self, index: usize) -> Option<SpanSym> {
+        self.spans
+            .get(index)
+            .filter(|&&span| span != REMOVED)
+            .map(|span| SpanSym {
+                offset: span.offset,
+                len: span.len,
+            })
+    }
+
+    /// Returns the string slice associated with the given span symbol if available,
+    /// otherwise returns `None`.
+    ///
+    /// Returns `None` if the span exceeds the live bytes of the buffer or does not
+    /// lie on character boundaries.
+    ///
+    /// # Note
+    ///
+    /// Truncating the backend releases the bytes of the truncated strings and
+    /// subsequently interned strings reuse them. A `SpanSym` of a truncated string
+    /// thus either resolves to `None` or to (part of) an unrelated string.
+    #[inline]
+    pub fn resolve_span(&self, symbol: SpanSym) -> Option<&str> {
+        let end = symbol.offset().checked_add(symbol.len())?;
+        if end > self.buffer.len() {
+            return None;
+        }
+        self.buffer.get(symbol.offset()..end)
+    }
+}
+
+impl<S, H> StringInterner<S, BufferBackend<S>, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Interns the given value and returns a symbol encoding its location within the buffer.
+    #[inline]
+    pub fn get_or_intern_span<T>(&mut self, val: T) -> SpanSym
+    where
+        T: AsRef<str>,
+    {
+        let sym = self.get_or_intern(val);
+        self.backend
+            .span_sym(sym.to_usize())
+            .expect("interned strings always have a span")
+    }
+
+    /// Returns the span symbol associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn span_of(&self, symbol: S) -> Option<SpanSym> {
+        self.backend.span_sym(symbol.to_usize())
+    }
+
+    /// Returns the string slice associated with the given span symbol if available,
+    /// otherwise returns `None`.
+    ///
+    /// This does not access any table of the interner.
+    ///
+    /// # Note
+    ///
+    /// A `SpanSym` is only guaranteed to resolve to its original string as long as
+    /// that string is not removed by `truncate`, `pop`, `rollback` or `drain`,
+    /// since these release its bytes for reuse by subsequently interned strings.
+    #[inline]
+    pub fn resolve_span(&self, symbol: SpanSym) -> Option<&str> {
+        self.backend.resolve_span(symbol)
+    }
+}
+
+impl<S> Backend<S> for BufferBackend<S>
+where
+    S: Symbol,
+{
+    type IntoStrings = vec::IntoIter<Option<String>>;
+
+    #[inline]
+    fn with_capacity(cap: usize) -> Self {
+        BufferBackend {
+            spans: Vec::with_capacity(cap),
+            buffer: String::new(),
+            mark: marker::PhantomData,
+        }
+    }
+
+    fn intern(&mut self, string: &str) -> S {
+        let symbol = S::from_usize(self.spans.len());
+        let too_large = || -> ! {
+            panic!("`BufferBackend` does not support more than `u32::MAX` bytes of strings")
+        };
+        let offset = u32::try_from(self.buffer.len()).unwrap_or_else(|_| too_large());
+        let len = u32::try_from(string.len()).unwrap_or_else(|_| too_large());
+        if offset.checked_add(len).is_none() {
+            too_large()
+        }
+        self.buffer.push_str(string);
+        self.spans.push(Span { offset, len });
+        symbol
+    }
+
+    #[inline]
+    fn resolve(&self, symbol: S) -> Option<&str> {
+        self.get(symbol.to_usize())
+    }
+
+    #[inline]
+    unsafe fn resolve_unchecked(&self, symbol: S) -> &str {
+        let span = self.spans.get_unchecked(symbol.to_usize());
+        let offset = span.offset as usize;
+        self.buffer
+            .get_unchecked(offset..offset + span.len as usize)
+    }
+
+    fn remove(&mut self, symbol: S) -> Option<String> {
+        let string = self.get(symbol.to_usize())?.to_owned();
+        self.spans[symbol.to_usize()] = REMOVED;
+        Some(string)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        if len >= self.spans.len() {
+            return;
+        }
+        // Strings are appended in order, so the last live retained string
+        // marks the end of the bytes still in use.
+        let end = self.spans[..len]
+            .iter()
+            .rev()
+            .find(|&&span| span != REMOVED)
+            .map_or(0, |span| span.offset as usize + span.len as usize);
+        self.buffer.truncate(end);
+        self.spans.truncate(len);
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.spans.capacity()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        self.spans.reserve(additional)
+    }
+
+    #[inline]
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.spans.try_reserve(additional)
+    }
+
+    #[inline]
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.spans.shrink_to(min_capacity)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.spans.shrink_to_fit();
+        self.buffer.shrink_to_fit();
+    }
+
+    fn values_heap_bytes(&self) -> usize {
+        self.spans.capacity() * mem::size_of::<Span>()
+    }
+
+    fn strings_heap_bytes(&self) -> usize {
+        self.buffer.capacity()
+    }
+
+    fn into_strings(self) -> Self::IntoStrings {
+        (0..self.spans.len())
+            .map(|index| self.get(index).map(str::to_owned))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
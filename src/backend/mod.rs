@@ -0,0 +1,147 @@
+//! Backends define how the strings of a `StringInterner` are stored.
+//!
+//! A backend owns the contents of all interned strings and associates them with
+//! densely allocated symbols. The `StringInterner` itself only takes care of
+//! deduplication and delegates storage and resolution to its backend.
+
+mod arc;
+mod bucket;
+mod buffer;
+mod simple;
+
+pub use self::arc::ArcBackend;
+pub use self::bucket::BucketBackend;
+pub use self::buffer::{BufferBackend, SpanSym};
+pub use self::simple::SimpleBackend;
+
+use crate::Symbol;
+
+use std::{collections::TryReserveError, sync::Arc};
+
+/// The default backend used by `StringInterner`.
+pub type DefaultBackend<S> = SimpleBackend<S>;
+
+/// Types implementing this trait may act as storage backends for string interners.
+///
+/// # Note
+///
+/// Backends must hand out symbols densely in the order in which strings are interned,
+/// i.e. the `n`-th interned string must be associated with `S::from_usize(n)`.
+/// Removing a string leaves a tombstone so that the symbols of all other strings
+/// remain valid. Symbols of removed strings are never handed out again.
+pub trait Backend<S>: Default
+where
+    S: Symbol,
+{
+    /// Iterator over the owned strings of a consumed backend in the order of their symbols.
+    ///
+    /// Yields `None` for every removed string.
+    type IntoStrings: Iterator<Item = Option<String>>;
+
+    /// Creates a new backend for the given expected number of strings.
+    fn with_capacity(cap: usize) -> Self;
+
+    /// Interns the given string and returns its new symbol.
+    ///
+    /// # Note
+    ///
+    /// Does not deduplicate. Deduplication is the task of the `StringInterner`.
+    fn intern(&mut self, string: &str) -> S;
+
+    /// Interns the given owned string and returns its new symbol.
+    ///
+    /// Backends that can take over the allocation of the string should override this.
+    #[inline]
+    fn intern_string(&mut self, string: String) -> S {
+        self.intern(&string)
+    }
+
+    /// Interns the given static string and returns its new symbol.
+    ///
+    /// Backends that can reference static strings without copying should override this.
+    #[inline]
+    fn intern_static(&mut self, string: &'static str) -> S {
+        self.intern(string)
+    }
+
+    /// Interns the given shared string and returns its new symbol.
+    ///
+    /// Backends that can store the shared string without copying should override this.
+    #[inline]
+    fn intern_arc(&mut self, string: Arc<str>) -> S {
+        self.intern(&string)
+    }
+
+    /// Returns the string associated with the given symbol if available.
+    fn resolve(&self, symbol: S) -> Option<&str>;
+
+    /// Returns the string associated with the given symbol without bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the symbol has an associated string in this backend.
+    unsafe fn resolve_unchecked(&self, symbol: S) -> &str;
+
+    /// Removes the string associated with the given symbol and returns it if available.
+    ///
+    /// The symbol no longer resolves afterwards.
+    fn remove(&mut self, symbol: S) -> Option<String>;
+
+    /// Removes the strings of all symbols with an index of at least `len`.
+    ///
+    /// Unlike `remove` this frees the symbols themselves so that they are handed out again.
+    fn truncate(&mut self, len: usize);
+
+    /// Returns the number of symbols handed out by the backend including those of removed strings.
+    fn len(&self) -> usize;
+
+    /// Returns true if the backend has not handed out any symbols.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of strings the backend can store without reallocating.
+    fn capacity(&self) -> usize;
+
+    /// Reserves capacity for at least `additional` more strings.
+    fn reserve(&mut self, additional: usize);
+
+    /// Tries to reserve capacity for at least `additional` more strings.
+    ///
+    /// Returns an error instead of aborting if the allocation fails.
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError>;
+
+    /// Shrinks the capacity of the backend as much as possible.
+    fn shrink_to_fit(&mut self);
+
+    /// Shrinks the capacity of the backend with a lower bound.
+    ///
+    /// The capacity remains at least as large as both the length and the given value.
+    fn shrink_to(&mut self, min_capacity: usize);
+
+    /// Returns the number of heap bytes used to index the strings of the backend.
+    fn values_heap_bytes(&self) -> usize;
+
+    /// Returns the number of heap bytes used by the contents of the strings of the backend.
+    fn strings_heap_bytes(&self) -> usize;
+
+    /// Consumes the backend and returns an iterator over its owned strings.
+    fn into_strings(self) -> Self::IntoStrings;
+}
+
+/// Backends whose resolved strings keep their address while further strings are interned.
+///
+/// This allows a `LocalStringInterner` to hand out string slices that stay valid
+/// across later calls to `get_or_intern`.
+///
+/// # Safety
+///
+/// Interning strings and reserving capacity must never move or mutate the contents
+/// of strings that have already been interned. Only removing, truncating and shrinking
+/// may invalidate previously resolved strings.
+pub unsafe trait StableBackend<S>: Backend<S>
+where
+    S: Symbol,
+{
+}
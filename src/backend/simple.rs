@@ -0,0 +1,307 @@
+use super::{Backend, StableBackend};
+use crate::Symbol;
+
+#[cfg(feature = "nightly")]
+use std::alloc::{Allocator, Global};
+use std::{collections::TryReserveError, fmt, marker, mem, vec};
+
+/// A string stored by the `SimpleBackend`.
+#[derive(Clone)]
+enum Value<#[cfg(feature = "nightly")] A: Allocator = Global> {
+    /// An owned string whose allocation has been taken over.
+    Owned(String),
+    /// A static string that is referenced without any allocation.
+    Static(&'static str),
+    /// A string that has been copied into the allocator of the backend.
+    #[cfg(feature = "nightly")]
+    Allocated(Box<[u8], A>),
+}
+
+#[cfg(not(feature = "nightly"))]
+impl Value {
+    /// Copies the given string into the allocator of the given values.
+    #[inline]
+    fn copy(string: &str, _values: &[Option<Value>]) -> Self {
+        Value::Owned(string.to_owned())
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A> Value<A>
+where
+    A: Allocator + Clone,
+{
+    /// Copies the given string into the allocator of the given values.
+    #[inline]
+    fn copy(string: &str, values: &Vec<Option<Value<A>>, A>) -> Self {
+        let mut bytes = Vec::with_capacity_in(string.len(), values.allocator().clone());
+        bytes.extend_from_slice(string.as_bytes());
+        Value::Allocated(bytes.into_boxed_slice())
+    }
+}
+
+/// Creates the values of a `SimpleBackend` for the given expected number of strings.
+#[cfg(not(feature = "nightly"))]
+#[inline]
+fn values_with_capacity(cap: usize) -> Vec<Option<Value>> {
+    Vec::with_capacity(cap)
+}
+
+/// Creates the values of a `SimpleBackend` for the given expected number of strings.
+#[cfg(feature = "nightly")]
+#[inline]
+fn values_with_capacity<A>(cap: usize) -> Vec<Option<Value<A>>, A>
+where
+    A: Allocator + Default,
+{
+    Vec::with_capacity_in(cap, A::default())
+}
+
+/// Implements the methods of a string stored by the `SimpleBackend`.
+macro_rules! impl_value {
+    ([$($generics:tt)*], $value:ty) => {
+        impl<$($generics)*> fmt::Debug for $value {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Debug::fmt(self.as_str(), f)
+            }
+        }
+
+        impl<$($generics)*> $value {
+            /// Returns the contents of the string.
+            #[inline]
+            fn as_str(&self) -> &str {
+                match self {
+                    Value::Owned(string) => string,
+                    Value::Static(string) => string,
+                    // Allocated values are only ever created by copying the bytes of a `str`.
+                    #[cfg(feature = "nightly")]
+                    Value::Allocated(bytes) => unsafe { std::str::from_utf8_unchecked(bytes) },
+                }
+            }
+
+            /// Converts the value into an owned string.
+            #[inline]
+            fn into_string(self) -> String {
+                match self {
+                    Value::Owned(string) => string,
+                    Value::Static(string) => String::from(string),
+                    #[cfg(feature = "nightly")]
+                    Value::Allocated(_) => String::from(self.as_str()),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "nightly"))]
+impl_value!([], Value);
+#[cfg(feature = "nightly")]
+impl_value!([A: Allocator], Value<A>);
+
+/// The simplest backend that allocates every interned string separately.
+///
+/// # Note
+///
+/// Since every string has its own heap allocation, resolved strings never move
+/// while the backend grows. Owned strings are taken over without copying
+/// or reallocating and static strings are referenced without any allocation.
+/// Excess capacity of owned strings is only released by `shrink_to_fit`.
+/// Removing a string frees its allocation right away.
+///
+/// With the `nightly` crate feature the backend is generic over the allocator `A`
+/// of its values and of the strings it copies, e.g. by `StringInterner::get_or_intern`.
+/// Owned strings taken over by `intern_string` keep their original allocation and
+/// the hash map of the `StringInterner` always uses the global allocator.
+/// New backends use `A::default()` since interners create their backends via `Backend`.
+pub struct SimpleBackend<S, #[cfg(feature = "nightly")] A: Allocator = Global> {
+    /// The string of every symbol or `None` for removed strings.
+    #[cfg(not(feature = "nightly"))]
+    values: Vec<Option<Value>>,
+    /// The string of every symbol or `None` for removed strings.
+    #[cfg(feature = "nightly")]
+    values: Vec<Option<Value<A>>, A>,
+    mark: marker::PhantomData<S>,
+}
+
+/// Implements the traits of the `SimpleBackend`.
+///
+/// With the `nightly` crate feature the implementations are generic over the allocator.
+macro_rules! impl_simple_backend {
+    ([$($generics:tt)*], $backend:ty, $value:ty) => {
+        impl<$($generics)*> fmt::Debug for $backend {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_struct("SimpleBackend")
+                    .field("values", &self.values)
+                    .finish()
+            }
+        }
+
+        impl<$($generics)*> Default for $backend {
+            #[inline]
+            fn default() -> Self {
+                SimpleBackend {
+                    values: values_with_capacity(0),
+                    mark: marker::PhantomData,
+                }
+            }
+        }
+
+        impl<$($generics)*> Clone for $backend {
+            fn clone(&self) -> Self {
+                SimpleBackend {
+                    values: self.values.clone(),
+                    mark: marker::PhantomData,
+                }
+            }
+        }
+
+        impl<$($generics)*> PartialEq for $backend {
+            fn eq(&self, rhs: &Self) -> bool {
+                self.values.len() == rhs.values.len()
+                    && self.values.iter().zip(&rhs.values).all(|(lhs, rhs)| {
+                        lhs.as_ref().map(<$value>::as_str) == rhs.as_ref().map(<$value>::as_str)
+                    })
+            }
+        }
+
+        impl<$($generics)*> Eq for $backend {}
+
+        impl<$($generics)*> Backend<S> for $backend
+        where
+            S: Symbol,
+        {
+            type IntoStrings = vec::IntoIter<Option<String>>;
+
+            #[inline]
+            fn with_capacity(cap: usize) -> Self {
+                SimpleBackend {
+                    values: values_with_capacity(cap),
+                    mark: marker::PhantomData,
+                }
+            }
+
+            #[inline]
+            fn intern(&mut self, string: &str) -> S {
+                let symbol = S::from_usize(self.values.len());
+                let value = Value::copy(string, &self.values);
+                self.values.push(Some(value));
+                symbol
+            }
+
+            #[inline]
+            fn intern_string(&mut self, string: String) -> S {
+                let symbol = S::from_usize(self.values.len());
+                self.values.push(Some(Value::Owned(string)));
+                symbol
+            }
+
+            #[inline]
+            fn intern_static(&mut self, string: &'static str) -> S {
+                let symbol = S::from_usize(self.values.len());
+                self.values.push(Some(Value::Static(string)));
+                symbol
+            }
+
+            #[inline]
+            fn resolve(&self, symbol: S) -> Option<&str> {
+                self.values
+                    .get(symbol.to_usize())?
+                    .as_ref()
+                    .map(<$value>::as_str)
+            }
+
+            #[inline]
+            unsafe fn resolve_unchecked(&self, symbol: S) -> &str {
+                self.values
+                    .get_unchecked(symbol.to_usize())
+                    .as_ref()
+                    .unwrap_unchecked()
+                    .as_str()
+            }
+
+            #[inline]
+            fn remove(&mut self, symbol: S) -> Option<String> {
+                self.values
+                    .get_mut(symbol.to_usize())?
+                    .take()
+                    .map(<$value>::into_string)
+            }
+
+            #[inline]
+            fn truncate(&mut self, len: usize) {
+                self.values.truncate(len)
+            }
+
+            #[inline]
+            fn len(&self) -> usize {
+                self.values.len()
+            }
+
+            #[inline]
+            fn capacity(&self) -> usize {
+                self.values.capacity()
+            }
+
+            #[inline]
+            fn reserve(&mut self, additional: usize) {
+                self.values.reserve(additional)
+            }
+
+            #[inline]
+            fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+                self.values.try_reserve(additional)
+            }
+
+            #[inline]
+            fn shrink_to(&mut self, min_capacity: usize) {
+                self.values.shrink_to(min_capacity)
+            }
+
+            fn shrink_to_fit(&mut self) {
+                for value in &mut self.values {
+                    if let Some(Value::Owned(string)) = value {
+                        string.shrink_to_fit();
+                    }
+                }
+                self.values.shrink_to_fit()
+            }
+
+            #[inline]
+            fn values_heap_bytes(&self) -> usize {
+                self.values.capacity() * mem::size_of::<Option<$value>>()
+            }
+
+            fn strings_heap_bytes(&self) -> usize {
+                self.values
+                    .iter()
+                    .map(|value| match value {
+                        Some(Value::Owned(string)) => string.capacity(),
+                        #[cfg(feature = "nightly")]
+                        Some(Value::Allocated(bytes)) => bytes.len(),
+                        _ => 0,
+                    })
+                    .sum()
+            }
+
+            fn into_strings(self) -> Self::IntoStrings {
+                self.values
+                    .into_iter()
+                    .map(|value| value.map(<$value>::into_string))
+                    .collect::<Vec<_>>()
+                    .into_iter()
+            }
+        }
+
+        // Every string has its own allocation that is never reallocated while interning.
+        unsafe impl<$($generics)*> StableBackend<S> for $backend where S: Symbol {}
+    };
+}
+
+#[cfg(not(feature = "nightly"))]
+impl_simple_backend!([S], SimpleBackend<S>, Value);
+#[cfg(feature = "nightly")]
+impl_simple_backend!(
+    [S, A: Allocator + Clone + Default],
+    SimpleBackend<S, A>,
+    Value<A>
+);
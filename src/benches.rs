@@ -1,8 +1,9 @@
 use super::*;
+use crate::backend::DefaultBackend;
 
 use ::fnv::FnvHasher;
 use lazy_static::lazy_static;
-use std::hash::BuildHasherDefault;
+use std::{collections::hash_map::RandomState, hash::BuildHasherDefault};
 use test::{black_box, Bencher};
 
 fn read_file_to_string(path: &str) -> String {
@@ -70,7 +71,7 @@ impl<H> EmptySetup<H>
 where
     H: BuildHasher + Clone,
 {
-    pub fn empty_interner(&self) -> StringInterner<Sym, H> {
+    pub fn empty_interner(&self) -> StringInterner<Sym, DefaultBackend<Sym>, H> {
         StringInterner::with_capacity_and_hasher(self.lines.len(), self.build_hasher.clone())
     }
 }
@@ -84,14 +85,15 @@ where
     H: BuildHasher,
 {
     lines: &'static [&'static str],
-    interner: StringInterner<Sym, H>,
+    interner: StringInterner<Sym, DefaultBackend<Sym>, H>,
     symbols: Vec<Sym>,
 }
 
 impl FilledSetup<RandomState> {
     pub fn new() -> Self {
         let lines = bench_lines();
-        let mut interner = StringInterner::with_capacity(lines.len());
+        let mut interner =
+            StringInterner::with_capacity_and_hasher(lines.len(), RandomState::new());
         let symbols = lines
             .into_iter()
             .map(|&line| interner.get_or_intern(line))
@@ -132,11 +134,11 @@ where
         self.lines
     }
 
-    pub fn filled_interner(&self) -> &StringInterner<Sym, H> {
+    pub fn filled_interner(&self) -> &StringInterner<Sym, DefaultBackend<Sym>, H> {
         &self.interner
     }
 
-    pub fn filled_interner_mut(&mut self) -> &mut StringInterner<Sym, H> {
+    pub fn filled_interner_mut(&mut self) -> &mut StringInterner<Sym, DefaultBackend<Sym>, H> {
         &mut self.interner
     }
 
@@ -0,0 +1,70 @@
+/// The number of bits per expected element.
+const BITS_PER_ELEMENT: usize = 8;
+
+/// The number of bits set per element.
+const PROBES: u32 = 3;
+
+/// A probabilistic set of string hashes for fast negative look-ups.
+///
+/// # Note
+///
+/// With 8 bits per element and 3 probes the false positive rate stays below 4%
+/// as long as the filter holds at most `capacity` elements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BloomFilter {
+    bits: Vec<u64>,
+    capacity: usize,
+}
+
+impl BloomFilter {
+    /// Creates a new empty filter for at least `capacity` elements.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(64).next_power_of_two();
+        BloomFilter {
+            bits: vec![0; capacity * BITS_PER_ELEMENT / 64],
+            capacity,
+        }
+    }
+
+    /// Returns the number of elements the filter holds before its false positive rate degrades.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of heap bytes used by the filter.
+    #[inline]
+    pub fn heap_bytes(&self) -> usize {
+        self.bits.capacity() * std::mem::size_of::<u64>()
+    }
+
+    /// Returns the bit positions of the given hash.
+    #[inline]
+    fn positions(&self, hash: u64) -> impl Iterator<Item = usize> {
+        let mask = (self.bits.len() * 64 - 1) as u64;
+        // Derives all probes from the hash via double hashing.
+        let (h1, h2) = (hash, hash.rotate_left(32) | 1);
+        (0..u64::from(PROBES)).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) & mask) as usize)
+    }
+
+    /// Inserts the given hash into the filter.
+    #[inline]
+    pub fn insert(&mut self, hash: u64) {
+        for bit in self.positions(hash) {
+            self.bits[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    /// Returns false if the given hash has definitely not been inserted.
+    #[inline]
+    pub fn may_contain(&self, hash: u64) -> bool {
+        self.positions(hash)
+            .all(|bit| self.bits[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Removes all hashes from the filter.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.bits.iter_mut().for_each(|word| *word = 0);
+    }
+}
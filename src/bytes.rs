@@ -0,0 +1,96 @@
+use crate::{slice::SliceInterner, DefaultHashBuilder, Symbol};
+
+use std::hash::BuildHasher;
+
+/// An interner for arbitrary byte strings that are not necessarily valid UTF-8.
+///
+/// All byte strings are stored contiguously within a single buffer.
+#[derive(Debug, Clone)]
+pub struct ByteInterner<S, H = DefaultHashBuilder>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    inner: SliceInterner<u8, S, H>,
+}
+
+impl<S> Default for ByteInterner<S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn default() -> Self {
+        ByteInterner::new()
+    }
+}
+
+impl<S> ByteInterner<S>
+where
+    S: Symbol,
+{
+    /// Creates a new empty `ByteInterner`.
+    #[inline]
+    pub fn new() -> Self {
+        ByteInterner::with_hasher(DefaultHashBuilder::default())
+    }
+}
+
+impl<S, H> ByteInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `ByteInterner` with the given hasher.
+    #[inline]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        ByteInterner {
+            inner: SliceInterner::with_hasher(hash_builder),
+        }
+    }
+
+    /// Interns the given bytes.
+    ///
+    /// Returns a symbol to access them within this interner.
+    #[inline]
+    pub fn get_or_intern<T>(&mut self, val: T) -> S
+    where
+        T: AsRef<[u8]>,
+    {
+        self.inner.get_or_intern(val.as_ref())
+    }
+
+    /// Returns the symbol associated with the given bytes if existent,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<[u8]>,
+    {
+        self.inner.get(val.as_ref())
+    }
+
+    /// Returns the bytes associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&[u8]> {
+        self.inner.resolve(symbol)
+    }
+
+    /// Returns the number of uniquely interned byte strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the symbols and byte strings in insertion order.
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (S, &[u8])> + ExactSizeIterator + '_ {
+        self.inner.iter()
+    }
+}
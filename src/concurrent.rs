@@ -0,0 +1,189 @@
+use crate::{append_only::Segments, eq, key_of, DefaultHashBuilder, HashToSymbol, Symbol};
+
+use std::{
+    collections::hash_map,
+    hash::BuildHasher,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+};
+
+/// The number of bits of a string hash that select its shard.
+const SHARD_BITS: u32 = 5;
+
+/// The number of shards of a `ConcurrentStringInterner`.
+const SHARDS: usize = 1 << SHARD_BITS;
+
+/// A single shard mapping the hashes of its strings to their symbols.
+#[derive(Debug)]
+struct Shard<S> {
+    map: HashToSymbol<S>,
+    collisions: HashToSymbol<Vec<S>>,
+}
+
+/// A string interner that can be shared between threads without external locking.
+///
+/// Interning and look-ups take `&self` and can be performed concurrently.
+/// Strings are distributed over several internally locked shards by their hash,
+/// so threads interning different strings rarely contend for the same lock.
+/// The strings themselves are kept in a single append-only store so that resolution
+/// never takes a lock and resolved strings stay valid for as long as the interner is borrowed.
+///
+/// # Note
+///
+/// Symbols are handed out densely from a counter shared by all shards, so the interner
+/// holds as many strings as the symbol type can represent. Their order reflects the order
+/// in which concurrent calls to `get_or_intern` reserved them.
+#[derive(Debug)]
+pub struct ConcurrentStringInterner<S, H = DefaultHashBuilder>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    shards: Box<[Mutex<Shard<S>>]>,
+    values: Segments,
+    len: AtomicUsize,
+    hash_builder: H,
+}
+
+impl<S> Default for ConcurrentStringInterner<S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn default() -> Self {
+        ConcurrentStringInterner::new()
+    }
+}
+
+impl<S> ConcurrentStringInterner<S>
+where
+    S: Symbol,
+{
+    /// Creates a new empty `ConcurrentStringInterner`.
+    #[inline]
+    pub fn new() -> Self {
+        ConcurrentStringInterner::with_hasher(DefaultHashBuilder::default())
+    }
+}
+
+impl<S, H> ConcurrentStringInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `ConcurrentStringInterner` with the given hasher.
+    pub fn with_hasher(hash_builder: H) -> Self {
+        let shards = (0..SHARDS)
+            .map(|_| {
+                Mutex::new(Shard {
+                    map: HashToSymbol::default(),
+                    collisions: HashToSymbol::default(),
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_boxed_slice();
+        ConcurrentStringInterner {
+            shards,
+            values: Segments::new(),
+            len: AtomicUsize::new(0),
+            hash_builder,
+        }
+    }
+
+    /// Locks and returns the shard responsible for the given string hash.
+    #[inline]
+    fn lock(&self, hash: u64) -> MutexGuard<'_, Shard<S>> {
+        // The shard maps are keyed by the lower bits of the hash, so the upper bits are used.
+        let index = (hash >> (u64::BITS - SHARD_BITS)) as usize;
+        // Poisoning can be ignored since the symbol is created before any mutation.
+        self.shards[index]
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the symbol of the given string within the given shard if existent.
+    fn find(&self, shard: &Shard<S>, hash: u64, val: &str) -> Option<S> {
+        let is_match = |sym: S| {
+            self.values
+                .get(sym.to_usize())
+                .is_some_and(|s| eq::str_eq(s, val))
+        };
+        let key = key_of(hash);
+        let sym = *shard.map.get(&key)?;
+        if is_match(sym) {
+            return Some(sym);
+        }
+        shard
+            .collisions
+            .get(&key)?
+            .iter()
+            .cloned()
+            .find(|&sym| is_match(sym))
+    }
+
+    /// Interns the given value.
+    ///
+    /// Returns a symbol to access it within this interner.
+    ///
+    /// The contents of the string are copied only if it has not been interned before.
+    /// The string is hashed exactly once and only the shard of the string is locked.
+    ///
+    /// # Panics
+    ///
+    /// If the symbol type cannot represent the symbol of a new string.
+    pub fn get_or_intern<T>(&self, val: T) -> S
+    where
+        T: AsRef<str>,
+    {
+        let val = val.as_ref();
+        let hash = self.hash_builder.hash_one(val);
+        let mut shard = self.lock(hash);
+        if let Some(sym) = self.find(&shard, hash, val) {
+            return sym;
+        }
+        let index = self.len.fetch_add(1, Ordering::Relaxed);
+        let sym = S::from_usize(index);
+        self.values.set(index, Arc::from(val));
+        let key = key_of(hash);
+        match shard.map.entry(key) {
+            hash_map::Entry::Vacant(entry) => {
+                entry.insert(sym);
+            }
+            hash_map::Entry::Occupied(_) => shard.collisions.entry(key).or_default().push(sym),
+        }
+        sym
+    }
+
+    /// Returns the symbol associated with the given string for this interner
+    /// if existent, otherwise returns `None`.
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        let val = val.as_ref();
+        let hash = self.hash_builder.hash_one(val);
+        self.find(&self.lock(hash), hash, val)
+    }
+
+    /// Returns the string slice associated with the given symbol if available,
+    /// otherwise returns `None`.
+    ///
+    /// This never blocks and is safe to call while other threads are interning.
+    pub fn resolve(&self, symbol: S) -> Option<&str> {
+        self.values.get(symbol.to_usize()).map(AsRef::as_ref)
+    }
+
+    /// Returns the number of uniquely interned strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    /// Returns true if the string interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,163 @@
+use crate::ContentCollision;
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    slice,
+};
+
+/// The offset basis of the 128-bit FNV-1a hash.
+const FNV_OFFSET: u128 = 0x6c62_272e_07bb_0142_62b8_2175_6295_c58d;
+
+/// The prime of the 128-bit FNV-1a hash.
+const FNV_PRIME: u128 = 0x0000_0000_0100_0000_0000_0000_0000_013b;
+
+/// A symbol derived from the contents of its string.
+///
+/// # Note
+///
+/// The symbol is the 128-bit FNV-1a hash of the UTF-8 bytes of the string.
+/// It is the same for the same string across interners, processes, machines and
+/// versions of this crate, so it can be exchanged without remapping.
+/// Unlike for other symbols the order of `ContentSym` is unrelated to insertion order.
+///
+/// FNV-1a is not a cryptographic hash. Accidental collisions are negligible, but
+/// colliding strings can be constructed deliberately, so symbols of untrusted strings
+/// must not be relied upon to identify them uniquely.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ContentSym(u128);
+
+impl ContentSym {
+    /// Returns the symbol of the given string without interning it.
+    pub const fn of(val: &str) -> ContentSym {
+        let bytes = val.as_bytes();
+        let mut hash = FNV_OFFSET;
+        let mut i = 0;
+        while i < bytes.len() {
+            hash ^= bytes[i] as u128;
+            hash = hash.wrapping_mul(FNV_PRIME);
+            i += 1;
+        }
+        ContentSym(hash)
+    }
+
+    /// Creates a symbol from its `u128` representation, see `to_u128`.
+    #[inline]
+    pub const fn from_u128(val: u128) -> ContentSym {
+        ContentSym(val)
+    }
+
+    /// Returns the `u128` representation of the symbol.
+    #[inline]
+    pub const fn to_u128(self) -> u128 {
+        self.0
+    }
+}
+
+/// A string interner whose symbols are derived from the contents of the strings.
+///
+/// Interning the same string always yields the same `ContentSym`, see `ContentSym::of`.
+///
+/// # Note
+///
+/// Symbols are 128 bits wide and every look-up hashes the whole string.
+/// Use a `StringInterner` instead if symbols do not need to be stable across processes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContentStringInterner {
+    map: HashMap<ContentSym, usize>,
+    strings: Vec<(ContentSym, Box<str>)>,
+}
+
+impl ContentStringInterner {
+    /// Creates a new empty `ContentStringInterner`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Interns the given value.
+    ///
+    /// Returns the symbol of its contents, see `ContentSym::of`.
+    ///
+    /// # Errors
+    ///
+    /// If another string with the same symbol has been interned before.
+    /// This requires a collision of the 128-bit hash, see `ContentSym`.
+    pub fn get_or_intern<T>(&mut self, val: T) -> Result<ContentSym, ContentCollision>
+    where
+        T: AsRef<str>,
+    {
+        let val = val.as_ref();
+        let symbol = ContentSym::of(val);
+        match self.map.entry(symbol) {
+            Entry::Occupied(entry) => {
+                if &*self.strings[*entry.get()].1 != val {
+                    return Err(ContentCollision { symbol });
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(self.strings.len());
+                self.strings.push((symbol, val.into()));
+            }
+        }
+        Ok(symbol)
+    }
+
+    /// Returns the symbol of the given string if it has been interned,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<ContentSym>
+    where
+        T: AsRef<str>,
+    {
+        let val = val.as_ref();
+        let sym = ContentSym::of(val);
+        (self.resolve(sym) == Some(val)).then_some(sym)
+    }
+
+    /// Returns the string slice associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve(&self, symbol: ContentSym) -> Option<&str> {
+        self.map.get(&symbol).map(|&n| &*self.strings[n].1)
+    }
+
+    /// Returns the number of uniquely interned strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns true if the string interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the symbols and strings in insertion order.
+    #[inline]
+    pub fn iter(&self) -> ContentIter<'_> {
+        ContentIter {
+            iter: self.strings.iter(),
+        }
+    }
+}
+
+/// Iterator over the pairs of symbols and interned strings for a `ContentStringInterner`.
+#[derive(Debug, Clone)]
+pub struct ContentIter<'a> {
+    iter: slice::Iter<'a, (ContentSym, Box<str>)>,
+}
+
+impl<'a> Iterator for ContentIter<'a> {
+    type Item = (ContentSym, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(sym, s)| (*sym, &**s))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
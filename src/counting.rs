@@ -0,0 +1,140 @@
+use crate::{
+    backend::{Backend, DefaultBackend},
+    DefaultHashBuilder, StringInterner, Symbol,
+};
+
+use std::{cmp::Reverse, hash::BuildHasher};
+
+/// A string interner that counts how often every string has been interned.
+///
+/// Every call to `get_or_intern` increments the counter of the returned symbol.
+/// The counters are indexed by symbol so that counting requires no additional hashing.
+#[derive(Debug, Clone)]
+pub struct CountingStringInterner<S, B = DefaultBackend<S>, H = DefaultHashBuilder>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    inner: StringInterner<S, B, H>,
+    counts: Vec<u64>,
+}
+
+impl<S, B> Default for CountingStringInterner<S, B>
+where
+    S: Symbol,
+    B: Backend<S>,
+{
+    #[inline]
+    fn default() -> Self {
+        CountingStringInterner::new()
+    }
+}
+
+impl<S, B> CountingStringInterner<S, B>
+where
+    S: Symbol,
+    B: Backend<S>,
+{
+    /// Creates a new empty `CountingStringInterner`.
+    #[inline]
+    pub fn new() -> Self {
+        CountingStringInterner::with_hasher(DefaultHashBuilder::default())
+    }
+}
+
+impl<S, B, H> CountingStringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Creates a new empty `CountingStringInterner` with the given hasher.
+    #[inline]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        CountingStringInterner {
+            inner: StringInterner::with_hasher(hash_builder),
+            counts: Vec::new(),
+        }
+    }
+
+    /// Interns the given value and increments its counter.
+    ///
+    /// Returns a symbol to access it within this interner.
+    pub fn get_or_intern<T>(&mut self, val: T) -> S
+    where
+        T: AsRef<str>,
+    {
+        let symbol = self.inner.get_or_intern(val);
+        let index = symbol.to_usize();
+        if index >= self.counts.len() {
+            self.counts.resize(index + 1, 0);
+        }
+        self.counts[index] += 1;
+        symbol
+    }
+
+    /// Returns the symbol associated with the given string for this interner
+    /// if existent, otherwise returns `None`.
+    ///
+    /// This does not increment the counter of the string.
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        self.inner.get(val)
+    }
+
+    /// Returns the string slice associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&str> {
+        self.inner.resolve(symbol)
+    }
+
+    /// Returns how often the string associated with the given symbol has been interned.
+    #[inline]
+    pub fn count(&self, symbol: S) -> u64 {
+        self.counts.get(symbol.to_usize()).cloned().unwrap_or(0)
+    }
+
+    /// Returns the `n` most frequently interned strings together with their symbols and counts.
+    ///
+    /// The strings are ordered by descending count and strings with equal counts
+    /// by their symbols.
+    pub fn most_common(&self, n: usize) -> Vec<(S, &str, u64)> {
+        let mut entries = self
+            .inner
+            .iter()
+            .map(|(symbol, string)| (symbol, string, self.count(symbol)))
+            .collect::<Vec<_>>();
+        entries.sort_by_key(|&(symbol, _, count)| (Reverse(count), symbol));
+        entries.truncate(n);
+        entries
+    }
+
+    /// Returns the number of uniquely interned strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the string interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the underlying `StringInterner`.
+    #[inline]
+    pub fn as_interner(&self) -> &StringInterner<S, B, H> {
+        &self.inner
+    }
+
+    /// Converts this interner into its underlying `StringInterner` and discards the counters.
+    #[inline]
+    pub fn into_inner(self) -> StringInterner<S, B, H> {
+        self.inner
+    }
+}
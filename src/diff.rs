@@ -0,0 +1,126 @@
+use crate::{backend::Backend, StringInterner, Symbol};
+
+use std::hash::BuildHasher;
+
+/// The differences between the strings of two interners created by `StringInterner::diff`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InternerDiff<'a, S> {
+    only_in_self: Vec<(S, &'a str)>,
+    only_in_other: Vec<(S, &'a str)>,
+    shared: Vec<(&'a str, S, S)>,
+}
+
+impl<'a, S> InternerDiff<'a, S>
+where
+    S: Symbol,
+{
+    /// Returns the strings that are only interned by the first interner together with their symbols.
+    #[inline]
+    pub fn only_in_self(&self) -> &[(S, &'a str)] {
+        &self.only_in_self
+    }
+
+    /// Returns the strings that are only interned by the second interner together with their symbols.
+    #[inline]
+    pub fn only_in_other(&self) -> &[(S, &'a str)] {
+        &self.only_in_other
+    }
+
+    /// Returns the strings interned by both interners together with
+    /// their symbols in the first and in the second interner.
+    #[inline]
+    pub fn shared(&self) -> &[(&'a str, S, S)] {
+        &self.shared
+    }
+
+    /// Returns true if both interners hold the same strings.
+    ///
+    /// The strings might still be associated with different symbols.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.only_in_self.is_empty() && self.only_in_other.is_empty()
+    }
+}
+
+impl<S, B, H> StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Compares the strings of this interner with the strings of `other`.
+    ///
+    /// All lists of the returned diff are ordered by the symbols of their first interner.
+    pub fn diff<'a, B2, H2>(&'a self, other: &'a StringInterner<S, B2, H2>) -> InternerDiff<'a, S>
+    where
+        B2: Backend<S>,
+        H2: BuildHasher,
+    {
+        let mut only_in_self = Vec::new();
+        let mut shared = Vec::new();
+        for (sym, string) in self.iter() {
+            match other.get(string) {
+                Some(other_sym) => shared.push((string, sym, other_sym)),
+                None => only_in_self.push((sym, string)),
+            }
+        }
+        let only_in_other = other
+            .iter()
+            .filter(|&(_, string)| self.get(string).is_none())
+            .collect();
+        InternerDiff {
+            only_in_self,
+            only_in_other,
+            shared,
+        }
+    }
+
+    /// Returns an iterator over the strings of this interner that are also interned by `other`
+    /// together with their symbols in this interner.
+    pub fn intersection<'a, B2, H2>(
+        &'a self,
+        other: &'a StringInterner<S, B2, H2>,
+    ) -> impl Iterator<Item = (S, &'a str)> + 'a
+    where
+        B2: Backend<S>,
+        H2: BuildHasher,
+    {
+        self.iter()
+            .filter(move |&(_, string)| other.contains(string))
+    }
+
+    /// Returns an iterator over the strings of this interner that are not interned by `other`
+    /// together with their symbols in this interner.
+    pub fn difference<'a, B2, H2>(
+        &'a self,
+        other: &'a StringInterner<S, B2, H2>,
+    ) -> impl Iterator<Item = (S, &'a str)> + 'a
+    where
+        B2: Backend<S>,
+        H2: BuildHasher,
+    {
+        self.iter()
+            .filter(move |&(_, string)| other.get(string).is_none())
+    }
+}
+
+impl<S, B, H> StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S> + Clone,
+    H: BuildHasher + Clone,
+{
+    /// Returns a new interner holding the strings of both this interner and `other`.
+    ///
+    /// All symbols of this interner remain valid for the union.
+    /// The strings only interned by `other` are associated with new symbols.
+    pub fn union<B2, H2>(&self, other: &StringInterner<S, B2, H2>) -> Self
+    where
+        B2: Backend<S>,
+        H2: BuildHasher,
+    {
+        let mut union = self.clone();
+        union.merge(other);
+        union
+    }
+}
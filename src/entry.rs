@@ -0,0 +1,232 @@
+use crate::{
+    backend::{Backend, DefaultBackend},
+    DefaultHashBuilder, StringInterner, Symbol,
+};
+
+use std::hash::BuildHasher;
+
+/// A view into a single string of a `StringInterner`, which may be interned or not.
+///
+/// Created by `StringInterner::entry`.
+#[derive(Debug)]
+pub enum Entry<'a, T, S, B = DefaultBackend<S>, H = DefaultHashBuilder>
+where
+    T: AsRef<str>,
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// The string has already been interned.
+    Occupied(OccupiedEntry<'a, T, S, B, H>),
+    /// The string has not been interned yet.
+    Vacant(VacantEntry<'a, T, S, B, H>),
+}
+
+/// A view into a string that has already been interned, see `Entry`.
+#[derive(Debug)]
+pub struct OccupiedEntry<'a, T, S, B = DefaultBackend<S>, H = DefaultHashBuilder>
+where
+    T: AsRef<str>,
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    interner: &'a mut StringInterner<S, B, H>,
+    key: T,
+    symbol: S,
+}
+
+/// A view into a string that has not been interned yet, see `Entry`.
+#[derive(Debug)]
+pub struct VacantEntry<'a, T, S, B = DefaultBackend<S>, H = DefaultHashBuilder>
+where
+    T: AsRef<str>,
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    interner: &'a mut StringInterner<S, B, H>,
+    key: T,
+    hash: u64,
+}
+
+impl<S, B, H> StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Returns the entry of the given string for in-place inspection and interning.
+    ///
+    /// The string is hashed exactly once, also if it is interned through the entry.
+    pub fn entry<T>(&mut self, val: T) -> Entry<'_, T, S, B, H>
+    where
+        T: AsRef<str>,
+    {
+        let hash = self.hash_of(val.as_ref());
+        match self.lookup(hash, val.as_ref()) {
+            Some(symbol) => Entry::Occupied(OccupiedEntry {
+                interner: self,
+                key: val,
+                symbol,
+            }),
+            None => Entry::Vacant(VacantEntry {
+                interner: self,
+                key: val,
+                hash,
+            }),
+        }
+    }
+}
+
+impl<'a, T, S, B, H> Entry<'a, T, S, B, H>
+where
+    T: AsRef<str>,
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Returns the string of this entry.
+    #[inline]
+    pub fn key(&self) -> &str {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Returns the symbol of the string if it has already been interned.
+    #[inline]
+    pub fn symbol(&self) -> Option<S> {
+        match self {
+            Entry::Occupied(entry) => Some(entry.symbol()),
+            Entry::Vacant(_) => None,
+        }
+    }
+
+    /// Interns the string if it has not been interned yet.
+    ///
+    /// Returns a symbol to access it within the interner.
+    #[inline]
+    pub fn or_intern(self) -> S {
+        match self {
+            Entry::Occupied(entry) => entry.symbol(),
+            Entry::Vacant(entry) => entry.insert(),
+        }
+    }
+
+    /// Interns the owned string constructed by `make` if the string has not been interned yet.
+    ///
+    /// Returns a symbol to access it within the interner.
+    ///
+    /// # Panics
+    ///
+    /// In debug mode if the string constructed by `make` is not equal to the key.
+    #[inline]
+    pub fn or_intern_with<F>(self, make: F) -> S
+    where
+        F: FnOnce() -> String,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.symbol(),
+            Entry::Vacant(entry) => entry.insert_owned(make()),
+        }
+    }
+}
+
+impl<'a, T, S, B, H> OccupiedEntry<'a, T, S, B, H>
+where
+    T: AsRef<str>,
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Returns the string used to look up this entry.
+    #[inline]
+    pub fn key(&self) -> &str {
+        self.key.as_ref()
+    }
+
+    /// Returns the symbol of the interned string.
+    #[inline]
+    pub fn symbol(&self) -> S {
+        self.symbol
+    }
+
+    /// Returns the string stored by the interner.
+    #[inline]
+    pub fn get(&self) -> &str {
+        self.interner
+            .backend
+            .resolve(self.symbol)
+            .unwrap_or_else(|| {
+                unreachable!("Should never fail because the symbol has been looked up")
+            })
+    }
+
+    /// Converts the entry into the string stored by the interner.
+    #[inline]
+    pub fn into_str(self) -> &'a str {
+        let OccupiedEntry {
+            interner, symbol, ..
+        } = self;
+        let interner: &'a StringInterner<S, B, H> = interner;
+        interner.backend.resolve(symbol).unwrap_or_else(|| {
+            unreachable!("Should never fail because the symbol has been looked up")
+        })
+    }
+
+    /// Removes the string from the interner and returns it, see `StringInterner::remove`.
+    #[inline]
+    pub fn remove(self) -> String {
+        self.interner.remove(self.symbol).unwrap_or_else(|| {
+            unreachable!("Should never fail because the symbol has been looked up")
+        })
+    }
+}
+
+impl<'a, T, S, B, H> VacantEntry<'a, T, S, B, H>
+where
+    T: AsRef<str>,
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Returns the string used to look up this entry.
+    #[inline]
+    pub fn key(&self) -> &str {
+        self.key.as_ref()
+    }
+
+    /// Returns the key of this entry.
+    #[inline]
+    pub fn into_key(self) -> T {
+        self.key
+    }
+
+    /// Interns a copy of the key.
+    ///
+    /// Returns a symbol to access it within the interner.
+    #[inline]
+    pub fn insert(self) -> S {
+        let new_id = self.interner.backend.intern(self.key.as_ref());
+        self.interner.insert(self.hash, new_id)
+    }
+
+    /// Interns the given owned string which is moved into the interner.
+    ///
+    /// Returns a symbol to access it within the interner.
+    ///
+    /// # Panics
+    ///
+    /// In debug mode if the given string is not equal to the key.
+    #[inline]
+    pub fn insert_owned(self, val: String) -> S {
+        debug_assert_eq!(
+            val,
+            self.key.as_ref(),
+            "the string interned by `VacantEntry::insert_owned` must be equal to its key"
+        );
+        self.interner.intern(self.hash, val)
+    }
+}
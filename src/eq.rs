@@ -0,0 +1,57 @@
+/// The number of bytes compared at once.
+const CHUNK: usize = 16;
+
+/// Returns true if both strings are equal.
+///
+/// # Note
+///
+/// Compares the lengths first and then the last bytes of long strings since long
+/// strings such as paths or queries tend to share long prefixes. The contents are
+/// compared in chunks of 16 bytes using SSE2 instructions where available.
+#[inline]
+pub(crate) fn str_eq(lhs: &str, rhs: &str) -> bool {
+    let (lhs, rhs) = (lhs.as_bytes(), rhs.as_bytes());
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+    if lhs.len() < CHUNK {
+        return lhs == rhs;
+    }
+    let tail = lhs.len() - CHUNK;
+    chunk_eq(&lhs[tail..], &rhs[tail..]) && chunks_eq(lhs, rhs)
+}
+
+/// Returns true if both slices of equal length are equal.
+#[inline]
+fn chunks_eq(lhs: &[u8], rhs: &[u8]) -> bool {
+    let mut lhs_chunks = lhs.chunks_exact(CHUNK);
+    let mut rhs_chunks = rhs.chunks_exact(CHUNK);
+    lhs_chunks
+        .by_ref()
+        .zip(rhs_chunks.by_ref())
+        .all(|(lhs, rhs)| chunk_eq(lhs, rhs))
+        && lhs_chunks.remainder() == rhs_chunks.remainder()
+}
+
+/// Returns true if both chunks of exactly 16 bytes are equal.
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[inline]
+fn chunk_eq(lhs: &[u8], rhs: &[u8]) -> bool {
+    use std::arch::x86_64::{__m128i, _mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8};
+
+    assert!(lhs.len() == CHUNK && rhs.len() == CHUNK);
+    // This is safe since both chunks have been checked to be 16 bytes long,
+    // unaligned loads are used and SSE2 is statically enabled.
+    unsafe {
+        let lhs = _mm_loadu_si128(lhs.as_ptr() as *const __m128i);
+        let rhs = _mm_loadu_si128(rhs.as_ptr() as *const __m128i);
+        _mm_movemask_epi8(_mm_cmpeq_epi8(lhs, rhs)) == 0xffff
+    }
+}
+
+/// Returns true if both chunks of exactly 16 bytes are equal.
+#[cfg(not(all(target_arch = "x86_64", target_feature = "sse2")))]
+#[inline]
+fn chunk_eq(lhs: &[u8], rhs: &[u8]) -> bool {
+    lhs == rhs
+}
@@ -0,0 +1,82 @@
+use crate::ContentSym;
+
+use std::{error, fmt};
+
+/// Error returned when interning a string would exceed the limits of an interner.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InternerFull;
+
+impl fmt::Display for InternerFull {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("the string interner is full")
+    }
+}
+
+impl error::Error for InternerFull {}
+
+/// Error returned when filling or committing symbols reserved by `StringInterner::reserve_symbols`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ReserveError {
+    /// The offset is not within the reserved block.
+    OutOfRange {
+        /// The offset within the reserved block.
+        offset: usize,
+    },
+    /// The slot at the offset has already been filled.
+    AlreadyFilled {
+        /// The offset within the reserved block.
+        offset: usize,
+    },
+    /// The slot at the offset has not been filled.
+    Unfilled {
+        /// The offset within the reserved block.
+        offset: usize,
+    },
+    /// The string of the slot at the offset has already been interned
+    /// or is filled into another slot of the block as well.
+    Duplicate {
+        /// The offset within the reserved block.
+        offset: usize,
+    },
+}
+
+impl fmt::Display for ReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReserveError::OutOfRange { offset } => {
+                write!(f, "offset {} is out of the reserved range", offset)
+            }
+            ReserveError::AlreadyFilled { offset } => {
+                write!(f, "reserved slot {} has already been filled", offset)
+            }
+            ReserveError::Unfilled { offset } => {
+                write!(f, "reserved slot {} has not been filled", offset)
+            }
+            ReserveError::Duplicate { offset } => {
+                write!(f, "the string of reserved slot {} is not unique", offset)
+            }
+        }
+    }
+}
+
+impl error::Error for ReserveError {}
+
+/// Error returned when interning a string into a `ContentStringInterner` whose symbol
+/// is already associated with another string.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ContentCollision {
+    /// The symbol of both strings.
+    pub symbol: ContentSym,
+}
+
+impl fmt::Display for ContentCollision {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "distinct strings with the same content symbol {:#x}",
+            self.symbol.to_u128()
+        )
+    }
+}
+
+impl error::Error for ContentCollision {}
@@ -0,0 +1,184 @@
+use crate::{DefaultHashBuilder, InternerFull, Symbol};
+
+use std::{fmt, hash::BuildHasher, marker, str};
+
+/// A string interner with a fixed capacity that never allocates after construction.
+///
+/// Holds at most `N` strings with at most `BYTES` bytes in total.
+/// Interning returns `InternerFull` if either limit would be exceeded.
+///
+/// # Note
+///
+/// All data is stored inline, so the interner should usually be placed in a `static`
+/// or a long-lived stack frame. Look-ups use an open addressing table with `N` slots.
+pub struct FixedStringInterner<S, const N: usize, const BYTES: usize, H = DefaultHashBuilder>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    hash_builder: H,
+    /// Indices of the strings offset by one. Zero marks an empty slot.
+    table: [usize; N],
+    ends: [usize; N],
+    bytes: [u8; BYTES],
+    len: usize,
+    mark: marker::PhantomData<S>,
+}
+
+impl<S, const N: usize, const BYTES: usize, H> fmt::Debug for FixedStringInterner<S, N, BYTES, H>
+where
+    S: Symbol + fmt::Debug,
+    H: BuildHasher,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map()
+            .entries((0..self.len).map(|n| (S::from_usize(n), self.get_index(n))))
+            .finish()
+    }
+}
+
+impl<S, const N: usize, const BYTES: usize> Default for FixedStringInterner<S, N, BYTES>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn default() -> Self {
+        FixedStringInterner::new()
+    }
+}
+
+impl<S, const N: usize, const BYTES: usize> FixedStringInterner<S, N, BYTES>
+where
+    S: Symbol,
+{
+    /// Creates a new empty `FixedStringInterner`.
+    #[inline]
+    pub fn new() -> Self {
+        FixedStringInterner::with_hasher(DefaultHashBuilder::default())
+    }
+}
+
+impl<S, const N: usize, const BYTES: usize, H> FixedStringInterner<S, N, BYTES, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `FixedStringInterner` with the given hasher.
+    #[inline]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        FixedStringInterner {
+            hash_builder,
+            table: [0; N],
+            ends: [0; N],
+            bytes: [0; BYTES],
+            len: 0,
+            mark: marker::PhantomData,
+        }
+    }
+
+    /// Returns the string with the given index.
+    #[inline]
+    fn get_index(&self, index: usize) -> &str {
+        let start = match index {
+            0 => 0,
+            n => self.ends[n - 1],
+        };
+        str::from_utf8(&self.bytes[start..self.ends[index]])
+            .expect("interned strings are always valid UTF-8")
+    }
+
+    /// Returns the number of bytes used by all interned strings.
+    #[inline]
+    fn bytes_len(&self) -> usize {
+        match self.len {
+            0 => 0,
+            n => self.ends[n - 1],
+        }
+    }
+
+    /// Returns the table slot of the given string.
+    ///
+    /// This is either the slot holding the string or the empty slot it would be inserted into.
+    /// Returns `None` if the string is not interned and the table is full.
+    fn slot_of(&self, val: &str) -> Option<usize> {
+        if N == 0 {
+            return None;
+        }
+        let start = self.hash_builder.hash_one(val) as usize % N;
+        (0..N).map(|n| (start + n) % N).find(|&slot| {
+            let entry = self.table[slot];
+            entry == 0 || self.get_index(entry - 1) == val
+        })
+    }
+
+    /// Interns the given value.
+    ///
+    /// Returns a symbol to access it within this interner or `InternerFull` if the
+    /// interner cannot hold the value without exceeding its capacity.
+    pub fn get_or_intern<T>(&mut self, val: T) -> Result<S, InternerFull>
+    where
+        T: AsRef<str>,
+    {
+        let val = val.as_ref();
+        let slot = self.slot_of(val).ok_or(InternerFull)?;
+        if self.table[slot] != 0 {
+            return Ok(S::from_usize(self.table[slot] - 1));
+        }
+        let start = self.bytes_len();
+        let end = start + val.len();
+        if self.len == N || end > BYTES {
+            return Err(InternerFull);
+        }
+        self.bytes[start..end].copy_from_slice(val.as_bytes());
+        self.ends[self.len] = end;
+        self.len += 1;
+        self.table[slot] = self.len;
+        Ok(S::from_usize(self.len - 1))
+    }
+
+    /// Returns the symbol associated with the given string for this interner
+    /// if existent, otherwise returns `None`.
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        let entry = self.table[self.slot_of(val.as_ref())?];
+        entry.checked_sub(1).map(S::from_usize)
+    }
+
+    /// Returns the string slice associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&str> {
+        let index = symbol.to_usize();
+        if index >= self.len {
+            return None;
+        }
+        Some(self.get_index(index))
+    }
+
+    /// Returns the number of uniquely interned strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the string interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of strings this interner can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of bytes still available for new strings.
+    #[inline]
+    pub fn remaining_bytes(&self) -> usize {
+        BYTES - self.bytes_len()
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{backend::Backend, StringInterner, Symbol};
+
+use std::hash::BuildHasher;
+
+impl<S, B, H> StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Interns the given value and its lowercase form.
+    ///
+    /// Returns the symbol of the given value and the symbol of its lowercase form,
+    /// which are equal if the value is already lowercase.
+    ///
+    /// # Note
+    ///
+    /// The symbol of the lowercase form is cached per symbol,
+    /// so every unique string is lowercased at most once.
+    pub fn get_or_intern_folded<T>(&mut self, val: T) -> (S, S)
+    where
+        T: AsRef<str>,
+    {
+        let sym = self.get_or_intern(val);
+        let index = sym.to_usize();
+        if let Some(Some(folded)) = self.folded.get(index) {
+            if self.backend.resolve(*folded).is_some() {
+                return (sym, self.tag(*folded));
+            }
+        }
+        let string = self.backend.resolve(sym).unwrap_or("");
+        let folded = match string
+            .chars()
+            .any(|c| c.to_lowercase().ne(std::iter::once(c)))
+        {
+            true => {
+                let lower = string.to_lowercase();
+                self.get_or_intern_owned(lower)
+            }
+            false => sym,
+        };
+        if index >= self.folded.len() {
+            self.folded.resize(index + 1, None);
+        }
+        self.folded[index] = Some(folded);
+        (sym, folded)
+    }
+
+    /// Invalidates all cached lowercase forms that refer to symbols of at least `len`.
+    pub(crate) fn truncate_folded(&mut self, len: usize) {
+        self.folded.truncate(len);
+        for folded in &mut self.folded {
+            if folded.is_some_and(|sym| sym.to_usize() >= len) {
+                *folded = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use crate::{backend::Backend, packed::REMOVED, StringInterner, Symbol};
+
+use std::{cmp::Ordering, hash::BuildHasher, marker, ops, str};
+
+/// The number of strings per block of a `FrontCodedStringInterner`.
+///
+/// The first string of every block is stored in full.
+const BLOCK_SIZE: usize = 16;
+
+/// A read-only and prefix-compressed string interner created by `StringInterner::freeze_front_coded`.
+///
+/// The strings are sorted and every string is stored as the length of the prefix it
+/// shares with its predecessor followed by the remaining suffix (front coding). This makes
+/// it much smaller than a `StringInterner` for large sets of similar strings such as
+/// file paths or namespaced identifiers.
+///
+/// All symbols of the original interner remain valid for the front-coded interner.
+/// Symbols of removed strings keep their gaps and do not resolve.
+///
+/// # Note
+///
+/// Strings are not stored contiguously and thus are resolved into owned `String`s.
+/// Resolving a string decodes at most `BLOCK_SIZE - 1` predecessors within its block.
+/// Look-ups binary search the first strings of all blocks and decode a single block.
+/// Iteration decodes every string exactly once.
+///
+/// This is not a `Backend` since backends resolve symbols into borrowed string slices
+/// and append strings in insertion order, neither of which front coding of sorted
+/// strings supports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrontCodedStringInterner<S>
+where
+    S: Symbol,
+{
+    buffer: Box<[u8]>,
+    /// The end offset of every entry in sorted order.
+    ends: Box<[usize]>,
+    /// The symbol index of every entry in sorted order.
+    symbols: Box<[usize]>,
+    /// The sorted position of every symbol index, or `REMOVED` for removed strings.
+    positions: Box<[usize]>,
+    mark: marker::PhantomData<S>,
+}
+
+/// Returns the length of the common prefix of both strings that ends on a character boundary.
+fn common_prefix_len(lhs: &str, rhs: &str) -> usize {
+    let len = lhs
+        .bytes()
+        .zip(rhs.bytes())
+        .take_while(|(l, r)| l == r)
+        .count();
+    (0..=len)
+        .rev()
+        .find(|&n| rhs.is_char_boundary(n))
+        .unwrap_or(0)
+}
+
+/// Appends the given value to the buffer using a variable length encoding.
+fn write_varint(buffer: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        buffer.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+/// Reads a variable length encoded value from the start of the given bytes.
+///
+/// Returns the value and the number of bytes read.
+fn read_varint(bytes: &[u8]) -> (usize, usize) {
+    let mut value = 0;
+    for (n, &byte) in bytes.iter().enumerate() {
+        value |= ((byte & 0x7F) as usize) << (7 * n);
+        if byte & 0x80 == 0 {
+            return (value, n + 1);
+        }
+    }
+    unreachable!("front-coded entries always start with a complete prefix length")
+}
+
+impl<S, B, H> StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Creates a read-only `FrontCodedStringInterner` from the strings of this interner.
+    ///
+    /// All symbols of this interner remain valid for the front-coded interner.
+    /// Symbols of removed strings do not resolve for the front-coded interner either.
+    pub fn freeze_front_coded(&self) -> FrontCodedStringInterner<S> {
+        let mut sorted = self
+            .iter()
+            .map(|(sym, s)| (s, sym.to_usize()))
+            .collect::<Vec<_>>();
+        sorted.sort_unstable();
+        let mut buffer = Vec::new();
+        let mut ends = Vec::with_capacity(sorted.len());
+        let mut symbols = Vec::with_capacity(sorted.len());
+        let mut positions = vec![REMOVED; self.backend.len()];
+        let mut prev = "";
+        for (n, &(string, index)) in sorted.iter().enumerate() {
+            let prefix = match n % BLOCK_SIZE {
+                0 => 0,
+                _ => common_prefix_len(prev, string),
+            };
+            write_varint(&mut buffer, prefix);
+            buffer.extend_from_slice(&string.as_bytes()[prefix..]);
+            ends.push(buffer.len());
+            symbols.push(index);
+            positions[index] = n;
+            prev = string;
+        }
+        FrontCodedStringInterner {
+            buffer: buffer.into_boxed_slice(),
+            ends: ends.into_boxed_slice(),
+            symbols: symbols.into_boxed_slice(),
+            positions: positions.into_boxed_slice(),
+            mark: marker::PhantomData,
+        }
+    }
+}
+
+impl<S> FrontCodedStringInterner<S>
+where
+    S: Symbol,
+{
+    /// Returns the encoded entry at the given sorted position.
+    #[inline]
+    fn entry(&self, pos: usize) -> &[u8] {
+        let start = match pos {
+            0 => 0,
+            n => self.ends[n - 1],
+        };
+        &self.buffer[start..self.ends[pos]]
+    }
+
+    /// Returns the first string of the given block which is stored in full.
+    #[inline]
+    fn head(&self, block: usize) -> &str {
+        let entry = self.entry(block * BLOCK_SIZE);
+        let (_, read) = read_varint(entry);
+        str::from_utf8(&entry[read..]).expect("front-coded suffixes are always valid UTF-8")
+    }
+
+    /// Applies the entry at the given sorted position onto its decoded predecessor.
+    fn decode_into(&self, pos: usize, string: &mut String) {
+        let entry = self.entry(pos);
+        let (prefix, read) = read_varint(entry);
+        string.truncate(prefix);
+        string.push_str(
+            str::from_utf8(&entry[read..]).expect("front-coded suffixes are always valid UTF-8"),
+        );
+    }
+
+    /// Returns the symbol associated with the given string for this interner
+    /// if existent, otherwise returns `None`.
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        let val = val.as_ref();
+        // Finds the last block whose first string is not greater than `val`.
+        let (mut lo, mut hi) = (0, self.len().div_ceil(BLOCK_SIZE));
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match self.head(mid) <= val {
+                true => lo = mid + 1,
+                false => hi = mid,
+            }
+        }
+        let start = lo.checked_sub(1)? * BLOCK_SIZE;
+        let end = std::cmp::min(start + BLOCK_SIZE, self.len());
+        let mut string = String::new();
+        for pos in start..end {
+            self.decode_into(pos, &mut string);
+            match string.as_str().cmp(val) {
+                Ordering::Less => continue,
+                Ordering::Equal => return Some(S::from_usize(self.symbols[pos])),
+                Ordering::Greater => break,
+            }
+        }
+        None
+    }
+
+    /// Returns the string associated with the given symbol if available,
+    /// otherwise returns `None`.
+    pub fn resolve(&self, symbol: S) -> Option<String> {
+        let pos = *self.positions.get(symbol.to_usize())?;
+        if pos == REMOVED {
+            return None;
+        }
+        let mut string = String::new();
+        for n in (pos - pos % BLOCK_SIZE)..=pos {
+            self.decode_into(n, &mut string);
+        }
+        Some(string)
+    }
+
+    /// Returns the number of uniquely interned strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns true if the string interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of bytes used to store the encoded strings.
+    #[inline]
+    pub fn encoded_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns an iterator over the pairs of symbols and decoded strings
+    /// in lexicographic order of the strings.
+    #[inline]
+    pub fn iter(&self) -> FrontCodedIter<'_, S> {
+        FrontCodedIter {
+            interner: self,
+            range: 0..self.len(),
+            current: String::new(),
+        }
+    }
+}
+
+/// Iterator over the pairs of associated symbols and decoded strings for a `FrontCodedStringInterner`.
+pub struct FrontCodedIter<'a, S>
+where
+    S: Symbol,
+{
+    interner: &'a FrontCodedStringInterner<S>,
+    range: ops::Range<usize>,
+    current: String,
+}
+
+impl<'a, S> Iterator for FrontCodedIter<'a, S>
+where
+    S: Symbol,
+{
+    type Item = (S, String);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let pos = self.range.next()?;
+        self.interner.decode_into(pos, &mut self.current);
+        let symbol = S::from_usize(self.interner.symbols[pos]);
+        Some((symbol, self.current.clone()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<'a, S> ExactSizeIterator for FrontCodedIter<'a, S> where S: Symbol {}
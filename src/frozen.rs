@@ -0,0 +1,161 @@
+use crate::{
+    backend::Backend,
+    key_of,
+    packed::{PackedIter, PackedStrings},
+    DefaultHashBuilder, HashToSymbol, StringInterner, Symbol,
+};
+
+use std::{collections::hash_map::Entry, hash::BuildHasher, marker};
+
+/// A read-only string interner created by `StringInterner::freeze`.
+///
+/// Frozen interners only support look-ups, resolution and iteration.
+/// In exchange they store all strings contiguously within a single buffer
+/// and drop the growth slack of their internal hash map which makes them
+/// smaller and more cache friendly than a `StringInterner`.
+///
+/// All symbols of the original interner remain valid for the frozen interner.
+/// Symbols of removed strings keep their gaps and do not resolve.
+#[derive(Debug)]
+pub struct FrozenStringInterner<S, H = DefaultHashBuilder>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    hash_builder: H,
+    /// Maps the keys of the string hashes to symbols, see `StringInterner`.
+    map: HashToSymbol<S>,
+    collisions: HashToSymbol<Vec<S>>,
+    strings: PackedStrings,
+}
+
+impl<S, H> PartialEq for FrozenStringInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    fn eq(&self, rhs: &Self) -> bool {
+        self.strings == rhs.strings
+    }
+}
+
+impl<S, H> Eq for FrozenStringInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+}
+
+impl<S, B, H> StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Converts the interner into a read-only `FrozenStringInterner`.
+    ///
+    /// All symbols of this interner remain valid for the frozen interner.
+    ///
+    /// Symbols of removed strings do not resolve for the frozen interner either.
+    pub fn freeze(self) -> FrozenStringInterner<S, H> {
+        let strings = PackedStrings::new(self.slots(), self.iter_values().map(str::len).sum());
+        let mut map = HashToSymbol::with_capacity_and_hasher(self.len(), Default::default());
+        let mut collisions = HashToSymbol::<Vec<S>>::default();
+        for (index, s) in strings.iter() {
+            let key = key_of(self.hash_of(s));
+            let sym = S::from_usize(index);
+            match map.entry(key) {
+                Entry::Vacant(entry) => {
+                    entry.insert(sym);
+                }
+                Entry::Occupied(_) => collisions.entry(key).or_default().push(sym),
+            }
+        }
+        FrozenStringInterner {
+            hash_builder: self.hash_builder,
+            map,
+            collisions,
+            strings,
+        }
+    }
+}
+
+impl<S, H> FrozenStringInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Returns the symbol associated with the given string for this interner
+    /// if existent, otherwise returns `None`.
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        let val = val.as_ref();
+        let key = key_of(self.hash_builder.hash_one(val));
+        let is_match = |sym: S| self.resolve(sym) == Some(val);
+        let sym = *self.map.get(&key)?;
+        if is_match(sym) {
+            return Some(sym);
+        }
+        self.collisions
+            .get(&key)?
+            .iter()
+            .cloned()
+            .find(|&sym| is_match(sym))
+    }
+
+    /// Returns the string slice associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&str> {
+        self.strings.get(symbol.to_usize())
+    }
+
+    /// Returns the number of uniquely interned strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns true if the string interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the interned strings.
+    #[inline]
+    pub fn iter(&self) -> FrozenIter<'_, S> {
+        FrozenIter {
+            iter: self.strings.iter(),
+            mark: marker::PhantomData,
+        }
+    }
+}
+
+/// Iterator over the pairs of associated symbols and interned strings for a `FrozenStringInterner`.
+pub struct FrozenIter<'a, S> {
+    iter: PackedIter<'a>,
+    mark: marker::PhantomData<S>,
+}
+
+impl<'a, S> Iterator for FrozenIter<'a, S>
+where
+    S: Symbol + 'a,
+{
+    type Item = (S, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(index, s)| (S::from_usize(index), s))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, S> ExactSizeIterator for FrozenIter<'a, S> where S: Symbol + 'a {}
@@ -0,0 +1,62 @@
+use crate::{backend::Backend, BulkOperation, StringInterner, Symbol};
+
+use std::{hash::BuildHasher, marker};
+
+/// Records the live symbols during the mark phase of `StringInterner::gc`.
+#[derive(Debug, Clone)]
+pub struct Marker<S> {
+    marked: Vec<bool>,
+    mark: marker::PhantomData<S>,
+}
+
+impl<S> Marker<S>
+where
+    S: Symbol,
+{
+    /// Marks the given symbol as live.
+    ///
+    /// Symbols that are not associated with a string of the interner are ignored.
+    #[inline]
+    pub fn mark(&mut self, symbol: S) {
+        if let Some(marked) = self.marked.get_mut(symbol.to_usize()) {
+            *marked = true;
+        }
+    }
+
+    /// Returns true if the given symbol has been marked as live.
+    #[inline]
+    pub fn is_marked(&self, symbol: S) -> bool {
+        self.marked.get(symbol.to_usize()).cloned().unwrap_or(false)
+    }
+}
+
+impl<S, B, H> StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Removes all strings whose symbols have not been marked as live by `mark`.
+    ///
+    /// The given closure is expected to mark every symbol still in use via `Marker::mark`.
+    /// Preloaded strings are always kept. The symbols of the kept strings stay valid.
+    ///
+    /// Returns the number of removed strings.
+    pub fn gc<F>(&mut self, mut mark: F) -> usize
+    where
+        F: FnMut(&mut Marker<S>),
+    {
+        let _span = self.span(BulkOperation::Gc);
+        let mut marker = Marker {
+            marked: vec![false; self.backend.len()],
+            mark: marker::PhantomData,
+        };
+        for marked in marker.marked.iter_mut().take(self.preloaded) {
+            *marked = true;
+        }
+        mark(&mut marker);
+        let len = self.len();
+        self.retain(|symbol, _| marker.is_marked(symbol));
+        len - self.len()
+    }
+}
@@ -0,0 +1,108 @@
+use crate::Symbol;
+
+use std::{
+    cmp::Ordering,
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hash, Hasher},
+    num::NonZeroU32,
+    sync::atomic::{self, AtomicU32},
+};
+
+/// Returns a random nonzero generation for a newly created interner.
+pub(crate) fn next_generation() -> u32 {
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+    let count = COUNTER.fetch_add(1, atomic::Ordering::Relaxed);
+    (RandomState::new().hash_one(count) as u32).max(1)
+}
+
+/// A symbol type that carries the generation of the interner that created it.
+///
+/// # Note
+///
+/// Only available with the `debug-validate` crate feature.
+/// Resolving a `TaggedSym` against a `StringInterner` other than the one that created it
+/// or one of its clones panics instead of silently returning a wrong string.
+/// With the feature `Sym` and the symbol types defined by `newtype_symbol!` are tagged
+/// the same way. Other symbol types, such as `SymU16`, are not validated.
+///
+/// All symbols handed out by a `StringInterner`, including those yielded by its iterators,
+/// are tagged. Symbols created via `Symbol::from_usize` carry no generation and are not
+/// validated. Comparisons and hashing only consider the index of the symbol.
+#[derive(Debug, Copy, Clone)]
+pub struct TaggedSym {
+    index: NonZeroU32,
+    generation: u32,
+}
+
+impl PartialEq for TaggedSym {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl Eq for TaggedSym {}
+
+impl PartialOrd for TaggedSym {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TaggedSym {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.index.cmp(&other.index)
+    }
+}
+
+impl Hash for TaggedSym {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state)
+    }
+}
+
+impl Symbol for TaggedSym {
+    /// Creates a `TaggedSym` without a generation from the given `usize`.
+    ///
+    /// # Panics
+    ///
+    /// If the given `usize` is greater than `u32::MAX - 1`.
+    #[inline]
+    fn from_usize(val: usize) -> Self {
+        Self::try_from_usize(val).unwrap_or_else(|| {
+            panic!(
+                "Symbol value {} is too large and not supported by `string_interner::TaggedSym` type",
+                val
+            )
+        })
+    }
+
+    #[inline]
+    fn try_from_usize(val: usize) -> Option<Self> {
+        NonZeroU32::try_from_usize(val).map(|index| TaggedSym {
+            index,
+            generation: 0,
+        })
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        self.index.to_usize()
+    }
+
+    #[inline]
+    fn with_generation(self, generation: u32) -> Self {
+        TaggedSym { generation, ..self }
+    }
+
+    #[inline]
+    fn generation(self) -> Option<u32> {
+        match self.generation {
+            0 => None,
+            generation => Some(generation),
+        }
+    }
+}
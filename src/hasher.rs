@@ -0,0 +1,86 @@
+use std::{
+    convert::TryInto,
+    hash::{BuildHasherDefault, Hasher},
+};
+
+/// The multiplicative constant of the Fx hash function.
+const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+/// A fast but not HashDoS resistant hasher, as used within rustc and Firefox.
+///
+/// # Note
+///
+/// Processes eight bytes at a time with a single rotation and multiplication.
+/// This is considerably faster than SipHash for short strings such as identifiers
+/// but should not be used for untrusted inputs.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct FxHasher {
+    hash: u64,
+}
+
+impl FxHasher {
+    #[inline]
+    fn add_to_hash(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.add_to_hash(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        let mut rest = chunks.remainder();
+        if rest.len() >= 4 {
+            self.add_to_hash(u64::from(u32::from_le_bytes(rest[..4].try_into().unwrap())));
+            rest = &rest[4..];
+        }
+        for &byte in rest {
+            self.add_to_hash(u64::from(byte));
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.add_to_hash(u64::from(i));
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.add_to_hash(u64::from(i));
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.add_to_hash(i);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+/// A `BuildHasher` creating `FxHasher`s.
+pub type FxBuildHasher = BuildHasherDefault<FxHasher>;
+
+/// The `BuildHasher` used by all interners of this crate if none is specified.
+///
+/// This is `RandomState` unless the `fxhash` feature is enabled, in which case
+/// it is the faster but not HashDoS resistant `FxBuildHasher`.
+#[cfg(not(feature = "fxhash"))]
+pub type DefaultHashBuilder = std::collections::hash_map::RandomState;
+
+/// The `BuildHasher` used by all interners of this crate if none is specified.
+///
+/// This is `RandomState` unless the `fxhash` feature is enabled, in which case
+/// it is the faster but not HashDoS resistant `FxBuildHasher`.
+#[cfg(feature = "fxhash")]
+pub type DefaultHashBuilder = FxBuildHasher;
@@ -0,0 +1,97 @@
+use crate::{backend::Backend, key_of, HashToSymbol, StringInterner, Symbol};
+
+use std::hash::{BuildHasher, Hasher};
+
+impl<S, B, H> StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Returns the hash of the ASCII lowercase form of the given string.
+    ///
+    /// The string is lowercased in small chunks on the stack without allocating.
+    pub(crate) fn hash_ignore_ascii_case(&self, val: &str) -> u64 {
+        let mut hasher = self.hash_builder.build_hasher();
+        let mut buffer = [0; 64];
+        for chunk in val.as_bytes().chunks(buffer.len()) {
+            let lower = &mut buffer[..chunk.len()];
+            lower.copy_from_slice(chunk);
+            lower.make_ascii_lowercase();
+            hasher.write(lower);
+        }
+        hasher.write_u8(0xff);
+        hasher.finish()
+    }
+
+    /// Returns the symbol of the first interned string that is equal to the given
+    /// string ignoring ASCII case if existent, otherwise returns `None`.
+    ///
+    /// # Note
+    ///
+    /// This is a constant time look-up once `get_or_intern_ignore_ascii_case` has been used
+    /// and a linear scan over all strings before.
+    pub fn get_ignore_ascii_case<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        let val = val.as_ref();
+        match &self.ascii_folded {
+            Some(_) => self.lookup_ignore_ascii_case(self.hash_ignore_ascii_case(val), val),
+            None => self
+                .iter()
+                .find(|(_, s)| s.eq_ignore_ascii_case(val))
+                .map(|(sym, _)| sym),
+        }
+    }
+
+    /// Interns the given value unless a string equal to it ignoring ASCII case
+    /// has been interned before.
+    ///
+    /// Returns the symbol of the first interned spelling of the string,
+    /// which is the canonical spelling returned by `resolve`.
+    ///
+    /// # Note
+    ///
+    /// The first call builds an index over the ASCII lowercase forms of all strings
+    /// which is kept up to date from then on.
+    pub fn get_or_intern_ignore_ascii_case<T>(&mut self, val: T) -> S
+    where
+        T: AsRef<str>,
+    {
+        if self.ascii_folded.is_none() {
+            self.index_ignore_ascii_case();
+        }
+        let val = val.as_ref();
+        match self.lookup_ignore_ascii_case(self.hash_ignore_ascii_case(val), val) {
+            Some(sym) => sym,
+            None => self.get_or_intern(val),
+        }
+    }
+
+    /// Returns the first matching symbol from the index of ASCII lowercase forms.
+    fn lookup_ignore_ascii_case(&self, hash: u64, val: &str) -> Option<S> {
+        self.ascii_folded
+            .as_ref()?
+            .get(&key_of(hash))?
+            .iter()
+            .cloned()
+            .filter(|&sym| {
+                self.backend
+                    .resolve(sym)
+                    .is_some_and(|s| s.eq_ignore_ascii_case(val))
+            })
+            .min()
+            .map(|sym| self.tag(sym))
+    }
+
+    /// Builds the index of the ASCII lowercase forms of all interned strings.
+    fn index_ignore_ascii_case(&mut self) {
+        let mut index = HashToSymbol::<Vec<S>>::default();
+        for (sym, string) in self.iter() {
+            let key = key_of(self.hash_ignore_ascii_case(string));
+            index.entry(key).or_default().push(sym);
+        }
+        self.ascii_folded = Some(index);
+    }
+}
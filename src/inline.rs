@@ -0,0 +1,129 @@
+use crate::{backend::Backend, StringInterner, Symbol};
+
+use std::{fmt, hash::BuildHasher, str};
+
+/// The maximum number of bytes of strings that are stored inline within an `InlineSym`.
+pub const INLINE_CAPACITY: usize = 7;
+
+/// The tag bit of the last byte signaling that the string is stored inline.
+const INLINE_TAG: u8 = 0x80;
+
+/// A symbol that stores short strings inline and refers to the interner for longer ones.
+///
+/// Strings of up to `INLINE_CAPACITY` bytes are encoded directly within the symbol
+/// and never touch the storage of the interner. Longer strings are interned as usual.
+///
+/// # Note
+///
+/// This symbol type has a memory footprint of 64 bits.
+/// Two `InlineSym` created by the same interner are equal if and only if their strings are equal.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InlineSym([u8; 8]);
+
+impl fmt::Debug for InlineSym {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.as_inline() {
+            Some(string) => f.debug_tuple("InlineSym").field(&string).finish(),
+            None => f.debug_tuple("InlineSym").field(&self.index()).finish(),
+        }
+    }
+}
+
+impl InlineSym {
+    /// Creates an inline symbol for the given string if it is short enough.
+    #[inline]
+    fn inline(string: &str) -> Option<Self> {
+        let len = string.len();
+        if len > INLINE_CAPACITY {
+            return None;
+        }
+        let mut bytes = [0; 8];
+        bytes[..len].copy_from_slice(string.as_bytes());
+        bytes[INLINE_CAPACITY] = INLINE_TAG | len as u8;
+        Some(InlineSym(bytes))
+    }
+
+    /// Creates a symbol referring to the interned string with the given index.
+    ///
+    /// # Panics
+    ///
+    /// If the index does not fit into 56 bits.
+    #[inline]
+    fn indexed(index: usize) -> Self {
+        let index = index as u64;
+        assert!(
+            index < 1 << (8 * INLINE_CAPACITY),
+            "Symbol value {} is too large and not supported by `string_interner::InlineSym` type",
+            index
+        );
+        InlineSym(index.to_le_bytes())
+    }
+
+    /// Returns true if the string of this symbol is stored inline.
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        self.0[INLINE_CAPACITY] & INLINE_TAG != 0
+    }
+
+    /// Returns the string of this symbol if it is stored inline, otherwise returns `None`.
+    #[inline]
+    pub fn as_inline(&self) -> Option<&str> {
+        if !self.is_inline() {
+            return None;
+        }
+        let len = (self.0[INLINE_CAPACITY] & !INLINE_TAG) as usize;
+        // Inline symbols are only ever created from valid strings.
+        str::from_utf8(&self.0[..len]).ok()
+    }
+
+    /// Returns the index of the interned string this symbol refers to.
+    #[inline]
+    fn index(&self) -> usize {
+        u64::from_le_bytes(self.0) as usize
+    }
+}
+
+impl<S, B, H> StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Interns the given value unless it is short enough to be stored inline.
+    ///
+    /// Returns an `InlineSym` to access it within this interner.
+    #[inline]
+    pub fn get_or_intern_inline<T>(&mut self, val: T) -> InlineSym
+    where
+        T: AsRef<str>,
+    {
+        match InlineSym::inline(val.as_ref()) {
+            Some(sym) => sym,
+            None => InlineSym::indexed(self.get_or_intern(val).to_usize()),
+        }
+    }
+
+    /// Returns the `InlineSym` associated with the given string for this interner
+    /// if existent, otherwise returns `None`.
+    ///
+    /// Always succeeds for strings that can be stored inline.
+    #[inline]
+    pub fn get_inline<T>(&self, val: T) -> Option<InlineSym>
+    where
+        T: AsRef<str>,
+    {
+        let val = val.as_ref();
+        InlineSym::inline(val)
+            .or_else(|| self.get(val).map(|sym| InlineSym::indexed(sym.to_usize())))
+    }
+
+    /// Returns the string slice associated with the given `InlineSym` if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve_inline<'a>(&'a self, symbol: &'a InlineSym) -> Option<&'a str> {
+        match symbol.as_inline() {
+            Some(string) => Some(string),
+            None => self.resolve_index(symbol.index()),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 #![cfg_attr(all(feature = "bench", test), feature(test))]
+#![cfg_attr(feature = "nightly", feature(allocator_api))]
 #![doc(html_root_url = "https://docs.rs/crate/string-interner/0.7.1")]
 #![deny(missing_docs)]
 
@@ -26,8 +27,8 @@
 //! ```
 //! # use string_interner::DefaultStringInterner;
 //! let interner = vec!["Elephant", "Tiger", "Horse", "Tiger"]
-//! 	.into_iter()
-//! 	.collect::<DefaultStringInterner>();
+//!     .into_iter()
+//!     .collect::<DefaultStringInterner>();
 //! ```
 //!
 //! ### Example: Look-up
@@ -44,10 +45,10 @@
 //! ```
 //! # use string_interner::DefaultStringInterner;
 //! let interner = vec!["Earth", "Water", "Fire", "Air"]
-//! 	.into_iter()
-//! 	.collect::<DefaultStringInterner>();
+//!     .into_iter()
+//!     .collect::<DefaultStringInterner>();
 //! for (sym, str) in interner {
-//! 	// iteration code here!
+//!     // iteration code here!
 //! }
 //! ```
 
@@ -63,15 +64,248 @@ mod benches;
 #[cfg(feature = "serde_support")]
 mod serde_impl;
 
+pub mod backend;
+
+mod append_only;
+mod arena;
+mod bloom;
+mod bytes;
+mod concurrent;
+mod content;
+mod counting;
+mod diff;
+mod entry;
+mod eq;
+mod error;
+mod fixed;
+mod folded;
+mod front_coded;
+mod frozen;
+mod gc;
+#[cfg(feature = "debug-validate")]
+mod generation;
+mod hasher;
+mod ignore_case;
+mod inline;
+mod local;
+mod lru;
+mod memory;
+mod metrics;
+mod namespace;
+mod normalize;
+mod os;
+mod packed;
+mod perfect;
+mod rc;
+mod remap;
+mod reserve;
+mod shared;
+mod slice;
+mod snapshot;
+mod sorted;
+mod static_interner;
+mod stats;
+mod trace;
+mod wide;
+mod writer;
+
+pub use self::append_only::AppendOnlyStringInterner;
+pub use self::arena::{ArenaStringInterner, StringArena};
+pub use self::bytes::ByteInterner;
+pub use self::concurrent::ConcurrentStringInterner;
+pub use self::content::{ContentIter, ContentStringInterner, ContentSym};
+pub use self::counting::CountingStringInterner;
+pub use self::diff::InternerDiff;
+pub use self::entry::{Entry, OccupiedEntry, VacantEntry};
+pub use self::error::{ContentCollision, InternerFull, ReserveError};
+pub use self::fixed::FixedStringInterner;
+pub use self::front_coded::{FrontCodedIter, FrontCodedStringInterner};
+pub use self::frozen::{FrozenIter, FrozenStringInterner};
+pub use self::gc::Marker;
+#[cfg(feature = "debug-validate")]
+pub use self::generation::TaggedSym;
+pub use self::hasher::{DefaultHashBuilder, FxBuildHasher, FxHasher};
+pub use self::inline::{InlineSym, INLINE_CAPACITY};
+pub use self::local::LocalStringInterner;
+pub use self::lru::LruStringInterner;
+pub use self::memory::MemoryUsage;
+pub use self::metrics::{AtomicMetrics, MetricsRecorder};
+pub use self::namespace::{NamespacedStringInterner, NamespacedSym};
+pub use self::normalize::{
+    AsciiCaseFold, IdentityNormalizer, Normalizer, NormalizingStringInterner,
+};
+pub use self::os::{OsStrInterner, PathInterner};
+pub use self::perfect::PerfectInterner;
+pub use self::rc::{RcInterner, RcSym};
+pub use self::remap::SymbolRemap;
+pub use self::reserve::ReservedSymbols;
+#[cfg(feature = "serde_support")]
+pub use self::serde_impl::{SymbolSeed, SymbolWithInterner};
+pub use self::shared::SharedStringInterner;
+pub use self::snapshot::InternerSnapshot;
+pub use self::sorted::SortedStringInterner;
+pub use self::static_interner::{StaticIter, StaticStringInterner};
+pub use self::stats::InternerStats;
+pub use self::trace::{BulkOperation, Tracer};
+pub use self::wide::WideStringInterner;
+pub use self::writer::InternWriter;
+
+use crate::backend::{Backend, DefaultBackend};
+use crate::bloom::BloomFilter;
+use crate::metrics::MetricsHook;
+use crate::trace::{Span, TracerHook};
 use std::iter::FromIterator;
 use std::{
-    collections::{hash_map::RandomState, HashMap},
-    hash::{BuildHasher, Hash, Hasher},
+    borrow::Cow,
+    cmp::Ordering,
+    collections::{hash_map, HashMap, HashSet, TryReserveError},
+    convert::TryFrom,
+    fmt::{self, Write as _},
+    hash::{BuildHasher, BuildHasherDefault, Hash, Hasher},
     iter, marker,
-    num::NonZeroU32,
-    slice, u32, vec,
+    num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize},
+    ops,
+    sync::Arc,
 };
 
+/// Interns the string produced by the given format arguments into the given interner.
+///
+/// Returns the symbol of the formatted string, see `StringInterner::intern_fmt`.
+///
+/// ### Example
+///
+/// ```
+/// # use string_interner::{intern_fmt, DefaultStringInterner};
+/// let mut interner = DefaultStringInterner::new();
+/// let (module, name) = ("std", "vec");
+/// let sym = intern_fmt!(interner, "{}::{}", module, name);
+/// assert_eq!(interner.resolve(sym), Some("std::vec"));
+/// ```
+#[macro_export]
+macro_rules! intern_fmt {
+    ($interner:expr, $($arg:tt)*) => {
+        $interner.intern_fmt(::std::format_args!($($arg)*))
+    };
+}
+
+/// Defines `Sym` constants for a fixed set of strings.
+///
+/// Every constant is associated with its string in declaration order.
+/// Additionally the macro defines the constant `SYMBOL_STRINGS` holding all strings,
+/// the static `STATIC_INTERNER` which is a `StaticStringInterner` for them
+/// and the function `preloaded_interner` that creates an interner in which
+/// all constants resolve to their strings, see `StringInterner::with_preloaded`.
+///
+/// # Note
+///
+/// The macro should be invoked at most once per module.
+///
+/// ### Example
+///
+/// ```
+/// mod kw {
+///     string_interner::symbols! {
+///         FN: "fn",
+///         LET: "let",
+///     }
+/// }
+///
+/// let mut interner = kw::preloaded_interner();
+/// assert_eq!(interner.get_or_intern("let"), kw::LET);
+/// assert_eq!(interner.resolve(kw::FN), Some("fn"));
+/// assert_eq!(kw::STATIC_INTERNER.get("let"), Some(kw::LET));
+/// ```
+#[macro_export]
+macro_rules! symbols {
+    ($($name:ident: $string:literal),* $(,)?) => {
+        #[allow(non_camel_case_types, clippy::upper_case_acronyms, dead_code)]
+        enum __SymbolIndex {
+            $($name,)*
+        }
+
+        $(
+            #[allow(dead_code)]
+            pub const $name: $crate::Sym = $crate::Sym::from_u32(__SymbolIndex::$name as u32);
+        )*
+
+        /// The strings of all symbol constants in the order of their symbols.
+        #[allow(dead_code)]
+        pub const SYMBOL_STRINGS: &[&str] = &[$($string),*];
+
+        /// A read-only interner for the strings of all symbol constants.
+        #[allow(dead_code)]
+        pub static STATIC_INTERNER: $crate::StaticStringInterner<$crate::Sym> =
+            $crate::StaticStringInterner::new(SYMBOL_STRINGS, |string| match string {
+                $($string => ::std::option::Option::Some(__SymbolIndex::$name as usize),)*
+                _ => ::std::option::Option::None,
+            });
+
+        /// Creates an interner in which all symbol constants resolve to their strings.
+        #[allow(dead_code)]
+        pub fn preloaded_interner() -> $crate::DefaultStringInterner {
+            $crate::StringInterner::with_preloaded(SYMBOL_STRINGS)
+        }
+    };
+}
+
+/// Defines newtype symbol types backed by `Sym`.
+///
+/// The generated types implement `Symbol`, `Debug`, `Copy`, `Clone`, `PartialEq`, `Eq`,
+/// `PartialOrd`, `Ord` and `Hash` and have the same size as `Sym`, also within an `Option`.
+/// Use them to prevent symbols of different domains from being mixed up.
+///
+/// ### Example
+///
+/// ```
+/// # use string_interner::{newtype_symbol, StringInterner};
+/// newtype_symbol! {
+///     /// Identifies a source file.
+///     pub struct FileId;
+///     pub struct NameId;
+/// }
+///
+/// let mut files = StringInterner::<FileId>::new();
+/// let main = files.get_or_intern("main.rs");
+/// assert_eq!(files.resolve(main), Some("main.rs"));
+/// ```
+#[macro_export]
+macro_rules! newtype_symbol {
+    ($($(#[$attr:meta])* $vis:vis struct $name:ident;)*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            $vis struct $name($crate::Sym);
+
+            impl $crate::Symbol for $name {
+                #[inline]
+                fn from_usize(val: usize) -> Self {
+                    $name(<$crate::Sym as $crate::Symbol>::from_usize(val))
+                }
+
+                #[inline]
+                fn try_from_usize(val: usize) -> ::std::option::Option<Self> {
+                    <$crate::Sym as $crate::Symbol>::try_from_usize(val).map($name)
+                }
+
+                #[inline]
+                fn to_usize(self) -> usize {
+                    <$crate::Sym as $crate::Symbol>::to_usize(self.0)
+                }
+
+                #[inline]
+                fn with_generation(self, generation: u32) -> Self {
+                    $name(<$crate::Sym as $crate::Symbol>::with_generation(self.0, generation))
+                }
+
+                #[inline]
+                fn generation(self) -> ::std::option::Option<u32> {
+                    <$crate::Sym as $crate::Symbol>::generation(self.0)
+                }
+            }
+        )*
+    };
+}
+
 /// Types implementing this trait are able to act as symbols for string interners.
 ///
 /// Symbols are returned by `StringInterner::get_or_intern` and allow look-ups of the
@@ -88,8 +322,42 @@ pub trait Symbol: Copy + Ord + Eq {
     /// Implementations panic if the operation cannot succeed.
     fn from_usize(val: usize) -> Self;
 
+    /// Creates a symbol from a `usize` if it is representable by the symbol type,
+    /// otherwise returns `None`.
+    ///
+    /// # Note
+    ///
+    /// The default implementation forwards to `from_usize` and therefore panics
+    /// wherever `from_usize` does. All symbol types of this crate override it.
+    #[inline]
+    fn try_from_usize(val: usize) -> Option<Self> {
+        Some(Self::from_usize(val))
+    }
+
     /// Returns the `usize` representation of `self`.
     fn to_usize(self) -> usize;
+
+    /// Returns `self` tagged with the given generation of a `StringInterner`.
+    ///
+    /// # Note
+    ///
+    /// Interners only tag their symbols with the `debug-validate` crate feature.
+    /// The default implementation returns `self` unchanged so that the symbol is
+    /// never validated. `Sym` and `TaggedSym` override it.
+    #[inline]
+    fn with_generation(self, _generation: u32) -> Self {
+        self
+    }
+
+    /// Returns the generation `self` has been tagged with if any.
+    ///
+    /// # Note
+    ///
+    /// The default implementation returns `None`, see `with_generation`.
+    #[inline]
+    fn generation(self) -> Option<u32> {
+        None
+    }
 }
 
 /// Symbol type used by the `DefaultStringInterner`.
@@ -98,8 +366,87 @@ pub trait Symbol: Copy + Ord + Eq {
 ///
 /// This special symbol type has a memory footprint of 32 bits
 /// and allows for certain space optimizations such as using it within an option: `Option<Sym>`
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Sym(NonZeroU32);
+///
+/// With the `debug-validate` crate feature every `Sym` additionally carries the generation
+/// of the interner that handed it out, which doubles its size. Resolving it against another
+/// interner then panics, see `TaggedSym`. Comparisons and hashing only consider the index.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(
+    not(feature = "debug-validate"),
+    derive(PartialEq, Eq, PartialOrd, Ord, Hash)
+)]
+pub struct Sym {
+    value: NonZeroU32,
+    /// The generation of the interner that handed out the symbol or zero.
+    #[cfg(feature = "debug-validate")]
+    generation: u32,
+}
+
+#[cfg(feature = "debug-validate")]
+impl PartialEq for Sym {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+#[cfg(feature = "debug-validate")]
+impl Eq for Sym {}
+
+#[cfg(feature = "debug-validate")]
+impl PartialOrd for Sym {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "debug-validate")]
+impl Ord for Sym {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+#[cfg(feature = "debug-validate")]
+impl Hash for Sym {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl Sym {
+    /// Creates an untagged `Sym` from the given nonzero value.
+    #[inline]
+    const fn new(value: NonZeroU32) -> Self {
+        Sym {
+            value,
+            #[cfg(feature = "debug-validate")]
+            generation: 0,
+        }
+    }
+
+    /// Creates a `Sym` from the given index in constant contexts.
+    ///
+    /// This is equivalent to `Sym::from_usize` and used by the `symbols!` macro.
+    ///
+    /// # Panics
+    ///
+    /// If the given index is `u32::MAX`.
+    pub const fn from_u32(index: u32) -> Self {
+        match index.checked_add(1) {
+            Some(value) => match NonZeroU32::new(value) {
+                Some(value) => Sym::new(value),
+                None => unreachable!(),
+            },
+            None => {
+                panic!("Symbol value is too large and not supported by `string_interner::Sym` type")
+            }
+        }
+    }
+}
 
 impl Symbol for Sym {
     /// Creates a `Sym` from the given `usize`.
@@ -107,296 +454,1571 @@ impl Symbol for Sym {
     /// # Panics
     ///
     /// If the given `usize` is greater than `u32::MAX - 1`.
+    ///
+    /// This check is always performed, also in release builds.
     fn from_usize(val: usize) -> Self {
         assert!(
             val < u32::MAX as usize,
             "Symbol value {} is too large and not supported by `string_interner::Sym` type",
             val
         );
-        Sym(NonZeroU32::new((val + 1) as u32).unwrap_or_else(|| {
+        Sym::new(NonZeroU32::new((val + 1) as u32).unwrap_or_else(|| {
             unreachable!("Should never fail because `val + 1` is nonzero and `<= u32::MAX`")
         }))
     }
 
+    #[inline]
+    fn try_from_usize(val: usize) -> Option<Self> {
+        u32::try_from(val.checked_add(1)?)
+            .ok()
+            .and_then(NonZeroU32::new)
+            .map(Sym::new)
+    }
+
     fn to_usize(self) -> usize {
-        (self.0.get() as usize) - 1
+        (self.value.get() as usize) - 1
+    }
+
+    #[cfg(feature = "debug-validate")]
+    #[inline]
+    fn with_generation(self, generation: u32) -> Self {
+        Sym { generation, ..self }
+    }
+
+    #[cfg(feature = "debug-validate")]
+    #[inline]
+    fn generation(self) -> Option<u32> {
+        match self.generation {
+            0 => None,
+            generation => Some(generation),
+        }
     }
 }
 
-impl Symbol for usize {
+/// A symbol type with a memory footprint of 16 bits.
+///
+/// # Note
+///
+/// Use this for small symbol tables so that symbols embedded in packed data structures
+/// take only 2 bytes. Like `Sym` it allows for space optimizations such as `Option<SymU16>`.
+/// It supports at most `u16::MAX` distinct symbols.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SymU16(NonZeroU16);
+
+impl Symbol for SymU16 {
+    /// Creates a `SymU16` from the given `usize`.
+    ///
+    /// # Panics
+    ///
+    /// If the given `usize` is greater than `u16::MAX - 1`.
+    /// Use `StringInterner::try_get_or_intern` to handle this case gracefully.
+    #[inline]
     fn from_usize(val: usize) -> Self {
-        val
+        Self::try_from_usize(val).unwrap_or_else(|| {
+            panic!(
+                "Symbol value {} is too large and not supported by `string_interner::SymU16` type",
+                val
+            )
+        })
+    }
+
+    #[inline]
+    fn try_from_usize(val: usize) -> Option<Self> {
+        NonZeroU16::try_from_usize(val).map(SymU16)
     }
 
+    #[inline]
     fn to_usize(self) -> usize {
-        self
+        self.0.to_usize()
     }
 }
 
-/// Internal reference to `str` used only within the `StringInterner` itself
-/// to encapsulate the unsafe behaviour of interior references.
-#[derive(Debug, Copy, Clone, Eq)]
-struct InternalStrRef(*const str);
+/// A symbol type with a memory footprint of 64 bits.
+///
+/// # Note
+///
+/// Use this for huge interners that need to hold more than `u32::MAX - 1` distinct strings,
+/// which is the limit of `Sym`. Like `Sym` it allows for space optimizations such as
+/// `Option<SymU64>`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SymU64(NonZeroU64);
 
-impl InternalStrRef {
-    /// Creates an InternalStrRef from a str.
+impl Symbol for SymU64 {
+    /// Creates a `SymU64` from the given `usize`.
     ///
-    /// This just wraps the str internally.
-    fn from_str(val: &str) -> Self {
-        InternalStrRef(val as *const str)
+    /// # Panics
+    ///
+    /// If the given `usize` is greater than `u64::MAX - 1`.
+    #[inline]
+    fn from_usize(val: usize) -> Self {
+        Self::try_from_usize(val).unwrap_or_else(|| {
+            panic!(
+                "Symbol value {} is too large and not supported by `string_interner::SymU64` type",
+                val
+            )
+        })
+    }
+
+    #[inline]
+    fn try_from_usize(val: usize) -> Option<Self> {
+        NonZeroU64::try_from_usize(val).map(SymU64)
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        self.0.to_usize()
+    }
+}
+
+/// A symbol type with a memory footprint of 32 bits that uses `u32::MAX` as its niche.
+///
+/// # Note
+///
+/// The index is stored bitwise inverted within a `NonZeroU32` so that `Option<NonMaxSym>`
+/// has the same size as `NonMaxSym`. In contrast to `Sym`, converting from and to `usize`
+/// requires no addition or subtraction, only a single bitwise negation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct NonMaxSym(NonZeroU32);
+
+impl PartialOrd for NonMaxSym {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NonMaxSym {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
     }
+}
 
-    /// Reinterprets this InternalStrRef as a str.
+impl Symbol for NonMaxSym {
+    /// Creates a `NonMaxSym` from the given `usize`.
     ///
-    /// This is "safe" as long as this InternalStrRef only
-    /// refers to strs that outlive this instance or
-    /// the instance that owns this InternalStrRef.
-    /// This should hold true for `StringInterner`.
+    /// # Panics
     ///
-    /// Does not allocate memory!
-    fn as_str(&self) -> &str {
-        unsafe { &*self.0 }
+    /// If the given `usize` is greater than `u32::MAX - 1`.
+    #[inline]
+    fn from_usize(val: usize) -> Self {
+        Self::try_from_usize(val).unwrap_or_else(|| {
+            panic!(
+                "Symbol value {} is too large and not supported by `string_interner::NonMaxSym` type",
+                val
+            )
+        })
+    }
+
+    #[inline]
+    fn try_from_usize(val: usize) -> Option<Self> {
+        u32::try_from(val)
+            .ok()
+            .and_then(|val| NonZeroU32::new(!val))
+            .map(NonMaxSym)
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        !self.0.get() as usize
     }
 }
 
-impl<T> From<T> for InternalStrRef
-where
-    T: AsRef<str>,
-{
-    fn from(val: T) -> Self {
-        InternalStrRef::from_str(val.as_ref())
+impl Symbol for usize {
+    fn from_usize(val: usize) -> Self {
+        val
+    }
+
+    #[inline]
+    fn try_from_usize(val: usize) -> Option<Self> {
+        Some(val)
+    }
+
+    fn to_usize(self) -> usize {
+        self
     }
 }
 
-impl Hash for InternalStrRef {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.as_str().hash(state)
+/// Implements `Symbol` for unsigned integer types that store the index directly.
+macro_rules! impl_symbol_for_int {
+    ( $( $int:ty ),* ) => {
+        $(
+            impl Symbol for $int {
+                /// # Panics
+                ///
+                /// If the given `usize` does not fit into the integer type.
+                #[inline]
+                fn from_usize(val: usize) -> Self {
+                    Self::try_from_usize(val).unwrap_or_else(|| {
+                        panic!(
+                            "Symbol value {} is too large and not supported by `{}`",
+                            val,
+                            stringify!($int)
+                        )
+                    })
+                }
+
+                #[inline]
+                fn try_from_usize(val: usize) -> Option<Self> {
+                    <$int>::try_from(val).ok()
+                }
+
+                #[inline]
+                fn to_usize(self) -> usize {
+                    self as usize
+                }
+            }
+        )*
+    };
+}
+
+impl_symbol_for_int!(u8, u16, u32, u64);
+
+/// Implements `Symbol` for non-zero integer types that store the index plus one.
+macro_rules! impl_symbol_for_nonzero {
+    ( $( $nonzero:ty => $int:ty ),* ) => {
+        $(
+            impl Symbol for $nonzero {
+                /// # Panics
+                ///
+                /// If the given `usize` plus one does not fit into the integer type.
+                #[inline]
+                fn from_usize(val: usize) -> Self {
+                    Self::try_from_usize(val).unwrap_or_else(|| {
+                        panic!(
+                            "Symbol value {} is too large and not supported by `{}`",
+                            val,
+                            stringify!($nonzero)
+                        )
+                    })
+                }
+
+                #[inline]
+                fn try_from_usize(val: usize) -> Option<Self> {
+                    <$int>::try_from(val.checked_add(1)?)
+                        .ok()
+                        .and_then(<$nonzero>::new)
+                }
+
+                #[inline]
+                fn to_usize(self) -> usize {
+                    self.get() as usize - 1
+                }
+            }
+        )*
+    };
+}
+
+impl_symbol_for_nonzero!(
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroUsize => usize
+);
+
+/// A hasher that passes through precomputed `u64` hashes.
+///
+/// Used for the internal map of `StringInterner` which is keyed by string hashes
+/// that have already been computed with the user provided `BuildHasher`.
+#[derive(Debug, Default, Copy, Clone)]
+struct IdentityHasher(u64);
+
+impl Hasher for IdentityHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = self.0.rotate_left(8) ^ u64::from(byte);
+        }
+    }
+
+    #[inline]
+    fn write_u32(&mut self, key: u32) {
+        // Spreads the key over all bits since the map also probes by the upper bits.
+        self.0 = u64::from(key).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, hash: u64) {
+        self.0 = hash;
     }
 }
 
-impl PartialEq for InternalStrRef {
-    fn eq(&self, other: &InternalStrRef) -> bool {
-        self.as_str() == other.as_str()
+/// Maps the keys of precomputed string hashes to symbols.
+///
+/// The keys are truncated to 32 bits which keeps the entries small,
+/// e.g. 8 bytes for `Sym`. Strings whose keys collide are stored separately.
+type HashToSymbol<V> = HashMap<u32, V, BuildHasherDefault<IdentityHasher>>;
+
+/// Returns the key of the given string hash within a `HashToSymbol` map.
+#[inline]
+fn key_of(hash: u64) -> u32 {
+    (hash ^ (hash >> 32)) as u32
+}
+
+/// The signature of callbacks registered via `StringInterner::on_intern`.
+type InternCallback<S> = dyn Fn(S, &str) + Send + Sync;
+
+/// A callback registered via `StringInterner::on_intern`.
+struct InternHook<S>(Arc<InternCallback<S>>);
+
+impl<S> fmt::Debug for InternHook<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("InternHook")
+    }
+}
+
+impl<S> Clone for InternHook<S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        InternHook(Arc::clone(&self.0))
     }
 }
 
 /// `StringInterner` that uses `Sym` as its underlying symbol type.
 pub type DefaultStringInterner = StringInterner<Sym>;
 
+/// `StringInterner` that uses `Sym` as its underlying symbol type and the fast `FxBuildHasher`.
+pub type FxStringInterner = StringInterner<Sym, DefaultBackend<Sym>, FxBuildHasher>;
+
 /// Caches strings efficiently, with minimal memory footprint and associates them with unique symbols.
 /// These symbols allow constant time comparisons and look-ups to the underlying interned strings.
-#[derive(Debug, Eq)]
-pub struct StringInterner<S, H = RandomState>
+///
+/// The strings themselves are stored by the backend `B`, see the `backend` module.
+#[derive(Debug, Clone)]
+pub struct StringInterner<S, B = DefaultBackend<S>, H = DefaultHashBuilder>
 where
     S: Symbol,
+    B: Backend<S>,
     H: BuildHasher,
 {
-    map: HashMap<InternalStrRef, S, H>,
-    values: Vec<Box<str>>,
+    hash_builder: H,
+    /// Maps the hash of every interned string to its symbol.
+    map: HashToSymbol<S>,
+    /// Holds the symbols of all strings whose hashes collide with an earlier string.
+    collisions: HashToSymbol<Vec<S>>,
+    backend: B,
+    /// Reusable buffer for strings that are formatted before they are interned.
+    scratch: String,
+    /// The number of strings interned by `with_preloaded`.
+    preloaded: usize,
+    /// The number of removed strings whose symbols are tombstones in the backend.
+    removed: usize,
+    /// The total length in bytes of all interned strings.
+    bytes: usize,
+    /// The maximum total length in bytes enforced by `try_get_or_intern`.
+    max_bytes: Option<usize>,
+    /// The callbacks invoked for every newly interned string.
+    hooks: Vec<InternHook<S>>,
+    /// The optional recorder of look-ups and size changes, see `set_metrics`.
+    metrics: Option<MetricsHook>,
+    /// The optional receiver of spans and events, see `set_tracer`.
+    tracer: Option<TracerHook>,
+    /// The optional filter for fast negative look-ups.
+    bloom: Option<BloomFilter>,
+    /// The optional index of the ASCII lowercase forms of all strings,
+    /// see `get_or_intern_ignore_ascii_case`. Entries of removed strings may be stale.
+    ascii_folded: Option<HashToSymbol<Vec<S>>>,
+    /// The cached symbols of the lowercase forms of strings indexed by symbol,
+    /// see `get_or_intern_folded`.
+    folded: Vec<Option<S>>,
+    /// The random generation symbols are tagged with, see `TaggedSym`.
+    #[cfg(feature = "debug-validate")]
+    generation: u32,
 }
 
-impl<S, H> PartialEq for StringInterner<S, H>
+impl<S, B, H> PartialEq for StringInterner<S, B, H>
 where
     S: Symbol,
+    B: Backend<S>,
     H: BuildHasher,
 {
+    /// Two interners are equal if they associate the same symbols with the same strings.
     fn eq(&self, rhs: &Self) -> bool {
-        self.len() == rhs.len() && self.values == rhs.values
+        self.len() == rhs.len() && self.iter().eq(rhs.iter())
     }
 }
 
-impl Default for StringInterner<Sym, RandomState> {
-    #[inline]
-    fn default() -> Self {
-        StringInterner::new()
-    }
+impl<S, B, H> Eq for StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
 }
 
-// Should be manually cloned.
-// See <https://github.com/Robbepop/string-interner/issues/9>.
-impl<S, H> Clone for StringInterner<S, H>
+impl<S, B, H> PartialOrd for StringInterner<S, B, H>
 where
     S: Symbol,
-    H: Clone + BuildHasher,
+    B: Backend<S>,
+    H: BuildHasher,
 {
-    fn clone(&self) -> Self {
-        let values = self.values.clone();
-        let mut map = HashMap::with_capacity_and_hasher(values.len(), self.map.hasher().clone());
-        // Recreate `InternalStrRef` from the newly cloned `Box<str>`s.
-        // Use `extend()` to avoid `H: Default` trait bound required by `FromIterator for HashMap`.
-        map.extend(
-            values
-                .iter()
-                .enumerate()
-                .map(|(i, s)| (InternalStrRef::from_str(s), S::from_usize(i))),
-        );
-        Self { values, map }
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
     }
 }
 
-// About `Send` and `Sync` impls for `StringInterner`
-// --------------------------------------------------
-//
-// tl;dr: Automation of Send+Sync impl was prevented by `InternalStrRef`
-// being an unsafe abstraction and thus prevented Send+Sync default derivation.
-//
-// These implementations are safe due to the following reasons:
-//  - `InternalStrRef` cannot be used outside `StringInterner`.
-//  - Strings stored in `StringInterner` are not mutable.
-//  - Iterator invalidation while growing the underlying `Vec<Box<str>>` is prevented by
-//    using an additional indirection to store strings.
-unsafe impl<S, H> Send for StringInterner<S, H>
+/// Orders string interners lexicographically by their strings in the order of their symbols.
+///
+/// # Note
+///
+/// Interners with equal strings but different symbols, e.g. due to removed strings,
+/// are further ordered by their pairs of symbols and strings to stay consistent with `PartialEq`.
+///
+/// Comparing interners never allocates.
+impl<S, B, H> Ord for StringInterner<S, B, H>
 where
-    S: Symbol + Send,
+    S: Symbol,
+    B: Backend<S>,
     H: BuildHasher,
 {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.iter_values()
+            .cmp(rhs.iter_values())
+            .then_with(|| self.iter().cmp(rhs.iter()))
+    }
 }
-unsafe impl<S, H> Sync for StringInterner<S, H>
+
+impl<S, B, H> ops::Index<S> for StringInterner<S, B, H>
 where
-    S: Symbol + Sync,
+    S: Symbol,
+    B: Backend<S>,
     H: BuildHasher,
 {
+    type Output = str;
+
+    /// Returns the string associated with the given symbol.
+    ///
+    /// # Panics
+    ///
+    /// If the given symbol has no associated string, see `resolve` for a non-panicking variant.
+    #[inline]
+    fn index(&self, symbol: S) -> &str {
+        self.resolve(symbol).unwrap_or_else(|| {
+            panic!(
+                "Symbol with index {} has no associated string in this interner",
+                symbol.to_usize()
+            )
+        })
+    }
+}
+
+impl Default for StringInterner<Sym, DefaultBackend<Sym>, DefaultHashBuilder> {
+    #[inline]
+    fn default() -> Self {
+        StringInterner::new()
+    }
 }
 
-impl<S> StringInterner<S>
+impl<S, B> StringInterner<S, B>
 where
     S: Symbol,
+    B: Backend<S>,
 {
     /// Creates a new empty `StringInterner`.
     #[inline]
-    pub fn new() -> StringInterner<S, RandomState> {
-        StringInterner {
-            map: HashMap::new(),
-            values: Vec::new(),
-        }
+    pub fn new() -> Self {
+        StringInterner::with_hasher(DefaultHashBuilder::default())
     }
 
     /// Creates a new `StringInterner` with the given initial capacity.
     #[inline]
     pub fn with_capacity(cap: usize) -> Self {
-        StringInterner {
-            map: HashMap::with_capacity(cap),
-            values: Vec::with_capacity(cap),
-        }
+        StringInterner::with_capacity_and_hasher(cap, DefaultHashBuilder::default())
     }
 
-    /// Returns the number of elements the `StringInterner` can hold without reallocating.
+    /// Creates a new empty `StringInterner` whose strings may not exceed
+    /// the given total length in bytes, see `try_get_or_intern`.
     #[inline]
-    pub fn capacity(&self) -> usize {
-        std::cmp::min(self.map.capacity(), self.values.capacity())
+    pub fn with_max_bytes(limit: usize) -> Self {
+        let mut interner = StringInterner::new();
+        interner.max_bytes = Some(limit);
+        interner
     }
 
-    /// Reserves capacity for at least `additional` more elements to be interned into `self`.
+    /// Creates a new `StringInterner` from the given sorted and deduplicated strings.
     ///
-    /// The collection may reserve more space to avoid frequent allocations.
-    /// After calling `reserve`, capacity will be greater than or equal to `self.len() + additional`.
-    /// Does nothing if capacity is already sufficient.
-    #[inline]
-    pub fn reserve(&mut self, additional: usize) {
-        self.map.reserve(additional);
-        self.values.reserve(additional);
-    }
-}
-
-impl<S, H> StringInterner<S, H>
+    /// The strings are associated with symbols in the order of the slice.
+    ///
+    /// # Note
+    ///
+    /// This trusts the caller and skips the duplicate look-up for every string.
+    /// Passing unsorted or non-unique strings breaks the invariants of the interner.
+    ///
+    /// # Panics
+    ///
+    /// In debug mode if the given strings are not sorted or not unique.
+    pub fn from_sorted_unique(strings: &[&str]) -> Self {
+        debug_assert!(
+            strings.windows(2).all(|pair| pair[0] < pair[1]),
+            "strings passed to `StringInterner::from_sorted_unique` must be sorted and unique"
+        );
+        let mut interner = Self::with_capacity(strings.len());
+        for &s in strings {
+            let hash = interner.hash_of(s);
+            let new_id = interner.backend.intern(s);
+            interner.insert(hash, new_id);
+        }
+        interner
+    }
+
+    /// Creates a new `StringInterner` with the given strings preloaded.
+    ///
+    /// The preloaded strings are associated with the symbols of the indices `0..n`
+    /// in the order of the slice, see `is_preloaded`.
+    ///
+    /// # Panics
+    ///
+    /// If the given strings are not unique.
+    pub fn with_preloaded(strings: &[&str]) -> Self {
+        let mut interner = Self::with_capacity(strings.len());
+        for (n, s) in strings.iter().enumerate() {
+            assert_eq!(
+                interner.get_or_intern(s).to_usize(),
+                n,
+                "strings passed to `StringInterner::with_preloaded` must be unique"
+            );
+        }
+        interner.preloaded = strings.len();
+        interner
+    }
+}
+
+impl<S, B, H> StringInterner<S, B, H>
 where
     S: Symbol,
+    B: Backend<S>,
     H: BuildHasher,
 {
+    /// Returns true if the given symbol belongs to a string interned by `with_preloaded`.
+    ///
+    /// This is a mere comparison of the symbol with the number of preloaded strings.
+    #[inline]
+    pub fn is_preloaded(&self, symbol: S) -> bool {
+        symbol.to_usize() < self.preloaded
+    }
+
     /// Creates a new empty `StringInterner` with the given hasher.
     #[inline]
-    pub fn with_hasher(hash_builder: H) -> StringInterner<S, H> {
+    pub fn with_hasher(hash_builder: H) -> Self {
         StringInterner {
-            map: HashMap::with_hasher(hash_builder),
-            values: Vec::new(),
+            hash_builder,
+            map: HashMap::default(),
+            collisions: HashMap::default(),
+            backend: B::default(),
+            scratch: String::new(),
+            preloaded: 0,
+            removed: 0,
+            bytes: 0,
+            max_bytes: None,
+            hooks: Vec::new(),
+            metrics: None,
+            tracer: None,
+            bloom: None,
+            ascii_folded: None,
+            folded: Vec::new(),
+            #[cfg(feature = "debug-validate")]
+            generation: generation::next_generation(),
+        }
+    }
+
+    /// Creates a new empty `StringInterner` with the given initial capacity and the given hasher.
+    #[inline]
+    pub fn with_capacity_and_hasher(cap: usize, hash_builder: H) -> Self {
+        StringInterner {
+            hash_builder,
+            map: HashMap::with_capacity_and_hasher(cap, Default::default()),
+            collisions: HashMap::default(),
+            backend: B::with_capacity(cap),
+            scratch: String::new(),
+            preloaded: 0,
+            removed: 0,
+            bytes: 0,
+            max_bytes: None,
+            hooks: Vec::new(),
+            metrics: None,
+            tracer: None,
+            bloom: None,
+            ascii_folded: None,
+            folded: Vec::new(),
+            #[cfg(feature = "debug-validate")]
+            generation: generation::next_generation(),
+        }
+    }
+
+    /// Returns the number of elements the `StringInterner` can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        std::cmp::min(self.map.capacity(), self.backend.capacity())
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be interned into `self`.
+    ///
+    /// The collection may reserve more space to avoid frequent allocations.
+    /// After calling `reserve`, capacity will be greater than or equal to `self.len() + additional`.
+    /// Does nothing if capacity is already sufficient.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional);
+        self.backend.reserve(additional);
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements to be interned into `self`.
+    ///
+    /// Returns an error instead of aborting if an allocation fails, see `reserve`.
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.map.try_reserve(additional)?;
+        self.backend.try_reserve(additional)
+    }
+
+    /// Returns the hash of the given string.
+    #[inline]
+    fn hash_of(&self, val: &str) -> u64 {
+        self.hash_builder.hash_one(val)
+    }
+
+    /// Returns a reference to the hasher of the interner.
+    ///
+    /// Use it to precompute hashes for `get_hashed` and `get_or_intern_hashed`.
+    #[inline]
+    pub fn hasher(&self) -> &H {
+        &self.hash_builder
+    }
+
+    /// Returns the symbol associated with the given string with the given precomputed hash
+    /// if existent, otherwise returns `None`.
+    ///
+    /// # Note
+    ///
+    /// The hash must have been computed by `self.hasher().hash_one(val)`.
+    /// Passing any other hash makes the look-up fail.
+    ///
+    /// # Panics
+    ///
+    /// In debug mode if the hash does not match the given string.
+    #[inline]
+    pub fn get_hashed(&self, hash: u64, val: &str) -> Option<S> {
+        debug_assert_eq!(
+            hash,
+            self.hash_of(val),
+            "hash passed to `StringInterner::get_hashed` does not match the string"
+        );
+        self.lookup(hash, val)
+    }
+
+    /// Interns the given string with the given precomputed hash.
+    ///
+    /// Returns a symbol to access it within this interner.
+    ///
+    /// # Note
+    ///
+    /// The hash must have been computed by `self.hasher().hash_one(val)`.
+    /// Passing any other hash breaks the invariants of the interner.
+    ///
+    /// # Panics
+    ///
+    /// In debug mode if the hash does not match the given string.
+    #[inline]
+    pub fn get_or_intern_hashed(&mut self, hash: u64, val: &str) -> S {
+        debug_assert_eq!(
+            hash,
+            self.hash_of(val),
+            "hash passed to `StringInterner::get_or_intern_hashed` does not match the string"
+        );
+        match self.lookup(hash, val) {
+            Some(sym) => sym,
+            None => {
+                let new_id = self.backend.intern(val);
+                self.insert(hash, new_id)
+            }
+        }
+    }
+
+    /// Returns the symbol of the given string with the given hash if existent.
+    ///
+    /// Records the look-up if metrics are enabled.
+    #[inline]
+    fn lookup(&self, hash: u64, val: &str) -> Option<S> {
+        let sym = self.find(hash, val);
+        if let Some(metrics) = &self.metrics {
+            metrics.0.record_lookup(sym.is_some());
+        }
+        sym
+    }
+
+    /// Returns the symbol of the given string with the given hash if existent.
+    fn find(&self, hash: u64, val: &str) -> Option<S> {
+        if let Some(bloom) = &self.bloom {
+            if !bloom.may_contain(hash) {
+                return None;
+            }
+        }
+        let is_match = |sym: S| {
+            self.backend
+                .resolve(sym)
+                .is_some_and(|s| eq::str_eq(s, val))
+        };
+        let key = key_of(hash);
+        let sym = *self.map.get(&key)?;
+        if is_match(sym) {
+            return Some(self.tag(sym));
+        }
+        self.collisions
+            .get(&key)?
+            .iter()
+            .cloned()
+            .find(|&sym| is_match(sym))
+            .map(|sym| self.tag(sym))
+    }
+
+    /// Tags the given symbol with the generation of this interner.
+    ///
+    /// Does nothing without the `debug-validate` crate feature.
+    #[inline]
+    fn tag(&self, symbol: S) -> S {
+        #[cfg(feature = "debug-validate")]
+        {
+            symbol.with_generation(self.generation)
+        }
+        #[cfg(not(feature = "debug-validate"))]
+        {
+            symbol
+        }
+    }
+
+    /// Panics if the given symbol has been tagged by another interner.
+    ///
+    /// Does nothing without the `debug-validate` crate feature.
+    #[inline]
+    fn validate(&self, _symbol: S) {
+        #[cfg(feature = "debug-validate")]
+        {
+            if let Some(generation) = _symbol.generation() {
+                assert_eq!(
+                    generation,
+                    self.generation,
+                    "Symbol with index {} was created by another interner",
+                    _symbol.to_usize()
+                );
+            }
+        }
+    }
+
+    /// Interns the given value.
+    ///
+    /// Returns a symbol to access it within this interner.
+    ///
+    /// The contents of the string are copied only if it has not been interned before.
+    /// Use `get_or_intern_owned` to move an owned string into the interner instead.
+    ///
+    /// The string is hashed exactly once, the internal map is keyed by the computed hash.
+    #[inline]
+    pub fn get_or_intern<T>(&mut self, val: T) -> S
+    where
+        T: AsRef<str>,
+    {
+        let val = val.as_ref();
+        let hash = self.hash_of(val);
+        match self.lookup(hash, val) {
+            Some(sym) => sym,
+            None => {
+                let new_id = self.backend.intern(val);
+                self.insert(hash, new_id)
+            }
+        }
+    }
+
+    /// Interns the given value and returns its symbol together with the stored string.
+    ///
+    /// The returned string slice is the canonical copy owned by the interner,
+    /// see `get_or_intern`.
+    #[inline]
+    pub fn get_or_intern_resolved<T>(&mut self, val: T) -> (S, &str)
+    where
+        T: AsRef<str>,
+    {
+        let sym = self.get_or_intern(val);
+        let string = self.backend.resolve(sym).unwrap_or_else(|| {
+            unreachable!("Should never fail because the symbol has just been interned")
+        });
+        (sym, string)
+    }
+
+    /// Interns the given value and reports whether it has been newly interned.
+    ///
+    /// Returns a symbol to access it within this interner and `true` if the string
+    /// has not been interned before, see `get_or_intern`.
+    #[inline]
+    pub fn intern_entry<T>(&mut self, val: T) -> (S, bool)
+    where
+        T: AsRef<str>,
+    {
+        let val = val.as_ref();
+        let hash = self.hash_of(val);
+        match self.lookup(hash, val) {
+            Some(sym) => (sym, false),
+            None => {
+                let new_id = self.backend.intern(val);
+                (self.insert(hash, new_id), true)
+            }
+        }
+    }
+
+    /// Interns the given value unless this would exceed the byte budget of the interner.
+    ///
+    /// Returns a symbol to access it within this interner or an error if the total length
+    /// of all interned strings would exceed the limit given to `with_max_bytes`
+    /// or if the symbol type cannot represent any more symbols.
+    ///
+    /// # Note
+    ///
+    /// Only this method enforces the byte budget. All other interning methods ignore it.
+    #[inline]
+    pub fn try_get_or_intern<T>(&mut self, val: T) -> Result<S, InternerFull>
+    where
+        T: AsRef<str>,
+    {
+        let val = val.as_ref();
+        let hash = self.hash_of(val);
+        if let Some(sym) = self.lookup(hash, val) {
+            return Ok(sym);
+        }
+        if let Some(max_bytes) = self.max_bytes {
+            if val.len() > max_bytes.saturating_sub(self.bytes) {
+                return Err(InternerFull);
+            }
+        }
+        if S::try_from_usize(self.backend.len()).is_none() {
+            return Err(InternerFull);
+        }
+        let new_id = self.backend.intern(val);
+        Ok(self.insert(hash, new_id))
+    }
+
+    /// Returns the total length in bytes of all interned strings.
+    #[inline]
+    pub fn total_bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Returns the byte budget given to `with_max_bytes` if any.
+    #[inline]
+    pub fn max_bytes(&self) -> Option<usize> {
+        self.max_bytes
+    }
+
+    /// Interns the given owned string.
+    ///
+    /// Returns a symbol to access it within this interner.
+    ///
+    /// The string is moved into the interner if it has not been interned before.
+    #[inline]
+    pub fn get_or_intern_owned(&mut self, val: String) -> S {
+        self.get_or_intern_into(val)
+    }
+
+    /// Interns the given copy-on-write string.
+    ///
+    /// Returns a symbol to access it within this interner.
+    ///
+    /// Owned strings are moved into the interner while borrowed strings are copied,
+    /// in both cases only if the string has not been interned before.
+    #[inline]
+    pub fn get_or_intern_cow(&mut self, val: Cow<str>) -> S {
+        match val {
+            Cow::Borrowed(val) => self.get_or_intern(val),
+            Cow::Owned(val) => self.get_or_intern_owned(val),
+        }
+    }
+
+    /// Interns the given shared string.
+    ///
+    /// Returns a symbol to access it within this interner.
+    ///
+    /// Backends such as the `ArcBackend` keep sharing the given `Arc` instead of
+    /// copying its contents if the string has not been interned before.
+    #[inline]
+    pub fn get_or_intern_arc(&mut self, val: Arc<str>) -> S {
+        let hash = self.hash_of(&val);
+        match self.lookup(hash, &val) {
+            Some(sym) => sym,
+            None => {
+                let new_id = self.backend.intern_arc(val);
+                self.insert(hash, new_id)
+            }
         }
     }
 
-    /// Creates a new empty `StringInterner` with the given initial capacity and the given hasher.
+    /// Interns the string produced by the given format arguments.
+    ///
+    /// Returns a symbol to access it within this interner.
+    ///
+    /// The string is formatted into a reusable buffer of the interner, see `writer`,
+    /// and only copied if it has not been interned before.
+    /// The `intern_fmt!` macro provides a more convenient syntax.
+    pub fn intern_fmt(&mut self, args: fmt::Arguments) -> S {
+        let mut writer = self.writer();
+        writer
+            .write_fmt(args)
+            .expect("a formatting trait implementation returned an error");
+        writer.finish()
+    }
+
+    /// Interns the concatenation of the strings associated with the given symbols.
+    ///
+    /// Returns a symbol to access it within this interner or `None` if any of
+    /// the given symbols has no associated string.
+    #[inline]
+    pub fn intern_concat(&mut self, parts: &[S]) -> Option<S> {
+        self.intern_join(parts, "")
+    }
+
+    /// Interns the strings associated with the given symbols joined by the given separator.
+    ///
+    /// Returns a symbol to access it within this interner or `None` if any of
+    /// the given symbols has no associated string.
+    ///
+    /// The joined string is built in a reusable buffer of the interner
+    /// and only copied if it has not been interned before.
+    pub fn intern_join(&mut self, parts: &[S], separator: &str) -> Option<S> {
+        let mut scratch = std::mem::take(&mut self.scratch);
+        scratch.clear();
+        let mut complete = true;
+        for (n, &part) in parts.iter().enumerate() {
+            match self.resolve(part) {
+                Some(part) => {
+                    if n != 0 {
+                        scratch.push_str(separator);
+                    }
+                    scratch.push_str(part);
+                }
+                None => {
+                    complete = false;
+                    break;
+                }
+            }
+        }
+        let sym = if complete {
+            Some(self.get_or_intern(&scratch))
+        } else {
+            None
+        };
+        self.scratch = scratch;
+        sym
+    }
+
+    /// Interns the string representation of the given value.
+    ///
+    /// Returns a symbol to access it within this interner.
+    ///
+    /// Formatting happens into a reusable buffer, see `intern_fmt`.
+    #[inline]
+    pub fn intern_display<T>(&mut self, val: &T) -> S
+    where
+        T: fmt::Display + ?Sized,
+    {
+        self.intern_fmt(format_args!("{}", val))
+    }
+
+    /// Interns the given key and constructs its owned string with `make` only if it is new.
+    ///
+    /// Returns a symbol to access it within this interner.
+    ///
+    /// # Panics
+    ///
+    /// In debug mode if the string constructed by `make` is not equal to `key`.
+    #[inline]
+    pub fn get_or_intern_with<F>(&mut self, key: &str, make: F) -> S
+    where
+        F: FnOnce() -> String,
+    {
+        let hash = self.hash_of(key);
+        match self.lookup(hash, key) {
+            Some(sym) => sym,
+            None => {
+                let new_val = make();
+                debug_assert_eq!(
+                    new_val, key,
+                    "the string constructed by `get_or_intern_with` must be equal to its key"
+                );
+                self.intern(hash, new_val)
+            }
+        }
+    }
+
+    /// Interns the given value and converts it into an owned string only if it is new.
+    #[inline]
+    fn get_or_intern_into<T>(&mut self, val: T) -> S
+    where
+        T: Into<String> + AsRef<str>,
+    {
+        let hash = self.hash_of(val.as_ref());
+        match self.lookup(hash, val.as_ref()) {
+            Some(sym) => sym,
+            None => self.intern(hash, val.into()),
+        }
+    }
+
+    /// Returns an iterator that lazily interns all values of the given iterator.
+    ///
+    /// Each value is interned only once the returned iterator is advanced to it
+    /// and the iterator yields the associated symbols in input order.
+    #[inline]
+    pub fn intern_iter<'a, I, T>(&'a mut self, iter: I) -> impl Iterator<Item = S> + 'a
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: 'a,
+        T: AsRef<str>,
+    {
+        iter.into_iter().map(move |val| self.get_or_intern(val))
+    }
+
+    /// Interns all values of the given iterator.
+    ///
+    /// Returns the associated symbols in input order.
+    ///
+    /// Capacity for all values is reserved up front according to the size hint of the iterator.
+    pub fn get_or_intern_all<I, T>(&mut self, iter: I) -> Vec<S>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        let iter = iter.into_iter();
+        let (len, _) = iter.size_hint();
+        self.reserve(len);
+        let mut symbols = Vec::with_capacity(len);
+        symbols.extend(iter.map(|val| self.get_or_intern(val)));
+        symbols
+    }
+
+    /// Interns all given values and hashes and deduplicates them in parallel beforehand.
+    ///
+    /// Returns the associated symbols in input order.
+    ///
+    /// The values are hashed on all available cores using scoped threads. Afterwards
+    /// they are partitioned by their hashes and every partition is deduplicated on its
+    /// own thread. Only the first occurrence of every value is then looked up and
+    /// inserted on the calling thread without hashing it again.
+    /// Small batches are processed on the calling thread.
+    pub fn get_or_intern_par<T>(&mut self, values: &[T]) -> Vec<S>
+    where
+        T: AsRef<str> + Sync,
+        H: Sync,
+    {
+        let (hashes, firsts) = self.dedup_par(values);
+        self.reserve(values.len());
+        let mut symbols = Vec::with_capacity(values.len());
+        for (index, (val, hash)) in values.iter().zip(hashes).enumerate() {
+            let first = firsts[index];
+            let sym = if first < index {
+                symbols[first]
+            } else {
+                let val = val.as_ref();
+                match self.lookup(hash, val) {
+                    Some(sym) => sym,
+                    None => {
+                        let new_id = self.backend.intern(val);
+                        self.insert(hash, new_id)
+                    }
+                }
+            };
+            symbols.push(sym);
+        }
+        symbols
+    }
+
+    /// Returns the hashes of all given values and the index of the first occurrence
+    /// of every value, both computed on all available cores.
+    fn dedup_par<T>(&self, values: &[T]) -> (Vec<u64>, Vec<usize>)
+    where
+        T: AsRef<str> + Sync,
+        H: Sync,
+    {
+        /// The minimum number of values processed by a single thread.
+        const MIN_CHUNK_LEN: usize = 1 << 12;
+        let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk_len = std::cmp::max(MIN_CHUNK_LEN, values.len().div_ceil(threads));
+        let mut firsts = (0..values.len()).collect::<Vec<_>>();
+        if values.len() <= chunk_len {
+            let hashes = values
+                .iter()
+                .map(|val| self.hash_of(val.as_ref()))
+                .collect();
+            return (hashes, firsts);
+        }
+        let hash_builder = &self.hash_builder;
+        let hashes = std::thread::scope(|scope| {
+            let handles = values
+                .chunks(chunk_len)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|val| hash_builder.hash_one(val.as_ref()))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+            let mut hashes = Vec::with_capacity(values.len());
+            for handle in handles {
+                hashes.extend(
+                    handle
+                        .join()
+                        .unwrap_or_else(|err| std::panic::resume_unwind(err)),
+                );
+            }
+            hashes
+        });
+        // Equal values have equal hashes and thus end up within the same partition.
+        let partitions = values.len().div_ceil(chunk_len);
+        let mut members = vec![Vec::new(); partitions];
+        for (index, &hash) in hashes.iter().enumerate() {
+            members[(hash >> 32) as usize % partitions].push(index);
+        }
+        let hashes_ref = &hashes;
+        std::thread::scope(|scope| {
+            let handles = members
+                .iter()
+                .map(|members| {
+                    scope.spawn(move || {
+                        let mut seen = HashToSymbol::<usize>::default();
+                        members
+                            .iter()
+                            .filter_map(|&index| {
+                                let key = key_of(hashes_ref[index]);
+                                let first = *seen.entry(key).or_insert(index);
+                                // Values with colliding keys are treated as distinct
+                                // and deduplicated by the look-up on the calling thread.
+                                let duplicate = first != index
+                                    && values[first].as_ref() == values[index].as_ref();
+                                duplicate.then_some((index, first))
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+            for handle in handles {
+                let duplicates = handle
+                    .join()
+                    .unwrap_or_else(|err| std::panic::resume_unwind(err));
+                for (index, first) in duplicates {
+                    firsts[index] = first;
+                }
+            }
+        });
+        (hashes, firsts)
+    }
+
+    /// Interns the given static string.
+    ///
+    /// Returns a symbol to access it within this interner.
+    ///
+    /// Backends such as the `DefaultBackend` store a reference to the static string
+    /// instead of copying its contents.
+    #[inline]
+    pub fn get_or_intern_static(&mut self, val: &'static str) -> S {
+        let hash = self.hash_of(val);
+        match self.lookup(hash, val) {
+            Some(sym) => sym,
+            None => {
+                let new_id = self.backend.intern_static(val);
+                self.insert(hash, new_id)
+            }
+        }
+    }
+
+    /// Interns the given value with the given hash and ignores collissions.
+    ///
+    /// Returns a symbol to access it within this interner.
+    fn intern(&mut self, hash: u64, new_val: String) -> S {
+        let new_id = self.backend.intern_string(new_val);
+        self.insert(hash, new_id)
+    }
+
+    /// Enables a probabilistic filter that lets look-ups of strings that have never
+    /// been interned fail fast in most cases without probing the internal map.
+    ///
+    /// The filter uses about one byte per interned string and grows with the interner.
+    /// This benefits workloads dominated by look-ups of new strings.
+    pub fn enable_bloom_filter(&mut self) {
+        let mut bloom = BloomFilter::with_capacity(2 * self.backend.len());
+        for string in self.slots().flatten() {
+            bloom.insert(self.hash_of(string));
+        }
+        self.bloom = Some(bloom);
+    }
+
+    /// Disables the filter enabled by `enable_bloom_filter`.
+    #[inline]
+    pub fn disable_bloom_filter(&mut self) {
+        self.bloom = None;
+    }
+
+    /// Registers a callback that is invoked with the symbol and the contents
+    /// of every string that is newly interned from now on.
+    ///
+    /// Clones of the interner share the registered callbacks.
+    pub fn on_intern<F>(&mut self, f: F)
+    where
+        F: Fn(S, &str) + Send + Sync + 'static,
+    {
+        self.hooks.push(InternHook(Arc::new(f)));
+    }
+
+    /// Enables recording the metrics of this interner with the given recorder.
+    ///
+    /// The recorder is notified of every look-up by `get_or_intern`, `get` and related
+    /// methods and of every change of the number of interned strings or their total length.
+    /// The current size is recorded right away. Replaces any previously set recorder.
+    /// Clones of the interner share the recorder.
+    pub fn set_metrics(&mut self, metrics: Arc<dyn MetricsRecorder>) {
+        self.metrics = Some(MetricsHook(metrics));
+        self.record_size();
+    }
+
+    /// Disables recording the metrics enabled by `set_metrics`.
+    #[inline]
+    pub fn disable_metrics(&mut self) {
+        self.metrics = None;
+    }
+
+    /// Enables reporting the spans and events of this interner to the given tracer.
+    ///
+    /// The tracer is notified of bulk operations such as `merge`, `compact`, `gc` and
+    /// serialization and of newly interned strings whose hashes collide.
+    /// Replaces any previously set tracer. Clones of the interner share the tracer.
+    #[inline]
+    pub fn set_tracer(&mut self, tracer: Arc<dyn Tracer>) {
+        self.tracer = Some(TracerHook(tracer));
+    }
+
+    /// Disables reporting to the tracer set by `set_tracer`.
+    #[inline]
+    pub fn disable_tracer(&mut self) {
+        self.tracer = None;
+    }
+
+    /// Reports the start of the given bulk operation if a tracer is set.
+    ///
+    /// The end is reported when the returned span is dropped.
+    #[inline]
+    pub(crate) fn span(&self, operation: BulkOperation) -> Option<Span> {
+        self.tracer
+            .as_ref()
+            .map(|tracer| Span::enter(tracer, operation))
+    }
+
+    /// Records the current number of interned strings and their total length if metrics are enabled.
+    #[inline]
+    fn record_size(&self) {
+        if let Some(metrics) = &self.metrics {
+            metrics.0.record_size(self.len(), self.bytes);
+        }
+    }
+
+    /// Associates the given hash with the newly interned symbol.
+    fn insert(&mut self, hash: u64, new_id: S) -> S {
+        let new_val = self.backend.resolve(new_id).unwrap_or("");
+        self.bytes += new_val.len();
+        for hook in &self.hooks {
+            (hook.0)(new_id, new_val);
+        }
+        self.record_size();
+        if self.ascii_folded.is_some() {
+            let key = key_of(self.hash_ignore_ascii_case(new_val));
+            if let Some(index) = &mut self.ascii_folded {
+                index.entry(key).or_default().push(new_id);
+            }
+        }
+        if let Some(bloom) = &mut self.bloom {
+            bloom.insert(hash);
+        }
+        if self
+            .bloom
+            .as_ref()
+            .is_some_and(|bloom| self.len() > bloom.capacity())
+        {
+            self.enable_bloom_filter();
+        }
+        let key = key_of(hash);
+        match self.map.entry(key) {
+            hash_map::Entry::Vacant(entry) => {
+                entry.insert(new_id);
+            }
+            hash_map::Entry::Occupied(_) => {
+                if let Some(tracer) = &self.tracer {
+                    tracer
+                        .0
+                        .hash_collision(self.backend.resolve(new_id).unwrap_or(""));
+                }
+                self.collisions.entry(key).or_default().push(new_id)
+            }
+        }
+        self.tag(new_id)
+    }
+
+    /// Returns the string slice associated with the given symbol if available,
+    /// otherwise returns `None`.
+    ///
+    /// # Note
+    ///
+    /// The returned string slice borrows the interner, so it has to be dropped before
+    /// interning further strings. Convert interners with a `StableBackend` such as the
+    /// default backend into a `LocalStringInterner` whose resolved strings stay valid
+    /// across later calls to `get_or_intern`.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&str> {
+        self.validate(symbol);
+        self.backend.resolve(symbol)
+    }
+
+    /// Returns the string slice associated with the given dense index if available,
+    /// otherwise returns `None`.
+    ///
+    /// This is the same as `resolve` without requiring to construct a symbol first.
+    #[inline]
+    pub fn resolve_index(&self, index: usize) -> Option<&str> {
+        if index >= self.backend.len() {
+            return None;
+        }
+        self.resolve(S::from_usize(index))
+    }
+
+    /// Returns the string associated with the given symbol.
+    ///
+    /// # Note
+    ///
+    /// This does not check whether the given symbol has an associated string
+    /// for the given string interner instance.
+    ///
+    /// With the `checked_symbols` crate feature enabled this performs a bounds check
+    /// and panics for invalid symbols instead. This costs an additional branch
+    /// on every call but turns the undefined behaviour below into a panic.
+    ///
+    /// # Safety
+    ///
+    /// This will result in undefined behaviour if the given symbol
+    /// had no associated string for this interner instance.
+    #[inline]
+    pub unsafe fn resolve_unchecked(&self, symbol: S) -> &str {
+        #[cfg(feature = "checked_symbols")]
+        {
+            self.resolve(symbol).unwrap_or_else(|| {
+                panic!(
+                    "Symbol with index {} has no associated string in this interner",
+                    symbol.to_usize()
+                )
+            })
+        }
+        #[cfg(not(feature = "checked_symbols"))]
+        {
+            self.validate(symbol);
+            self.backend.resolve_unchecked(symbol)
+        }
+    }
+
+    /// Returns the symbol associated with the given string for this interner
+    /// if existent, otherwise returns `None`.
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        self.lookup(self.hash_of(val.as_ref()), val.as_ref())
+    }
+
+    /// Returns true if the given string has been interned into this interner.
+    #[inline]
+    pub fn contains<T>(&self, val: T) -> bool
+    where
+        T: AsRef<str>,
+    {
+        self.get(val).is_some()
+    }
+
+    /// Returns true if the given symbol has an associated string in this interner.
+    ///
+    /// This is false for symbols that have not been handed out yet and for symbols
+    /// of removed strings.
     #[inline]
-    pub fn with_capacity_and_hasher(cap: usize, hash_builder: H) -> StringInterner<S, H> {
-        StringInterner {
-            map: HashMap::with_hasher(hash_builder),
-            values: Vec::with_capacity(cap),
-        }
+    pub fn contains_symbol(&self, symbol: S) -> bool {
+        self.resolve(symbol).is_some()
     }
 
-    /// Interns the given value.
+    /// Removes the string associated with the given symbol and returns it if available.
     ///
-    /// Returns a symbol to access it within this interner.
+    /// The symbols of all other strings remain valid and the given symbol
+    /// is never handed out again by this interner.
+    pub fn remove(&mut self, symbol: S) -> Option<String> {
+        self.validate(symbol);
+        let string = self.backend.remove(symbol)?;
+        let hash = self.hash_of(&string);
+        self.unlink(hash, symbol);
+        self.removed += 1;
+        self.bytes -= string.len();
+        self.record_size();
+        Some(string)
+    }
+
+    /// Removes the given string from the interner and returns its former symbol if existent.
     ///
-    /// This either copies the contents of the string (e.g. for str)
-    /// or moves them into this interner (e.g. for String).
+    /// See `remove` for details.
     #[inline]
-    pub fn get_or_intern<T>(&mut self, val: T) -> S
+    pub fn remove_str<T>(&mut self, val: T) -> Option<S>
     where
-        T: Into<String> + AsRef<str>,
+        T: AsRef<str>,
     {
-        match self.map.get(&val.as_ref().into()) {
-            Some(&sym) => sym,
-            None => self.intern(val),
-        }
+        let symbol = self.get(val)?;
+        self.remove(symbol);
+        Some(symbol)
     }
 
-    /// Interns the given value and ignores collissions.
+    /// Retains only the strings for which the given predicate returns `true`.
     ///
-    /// Returns a symbol to access it within this interner.
-    fn intern<T>(&mut self, new_val: T) -> S
+    /// All other strings are removed, see `remove`.
+    pub fn retain<F>(&mut self, mut f: F)
     where
-        T: Into<String> + AsRef<str>,
+        F: FnMut(S, &str) -> bool,
     {
-        let new_id: S = self.make_symbol();
-        let new_boxed_val = new_val.into().into_boxed_str();
-        let new_ref: InternalStrRef = new_boxed_val.as_ref().into();
-        self.values.push(new_boxed_val);
-        self.map.insert(new_ref, new_id);
-        new_id
+        let removed = self
+            .iter()
+            .filter(|&(sym, s)| !f(sym, s))
+            .map(|(sym, _)| sym)
+            .collect::<Vec<_>>();
+        for sym in removed {
+            self.remove(sym);
+        }
     }
 
-    /// Creates a new symbol for the current state of the interner.
-    fn make_symbol(&self) -> S {
-        S::from_usize(self.len())
+    /// Removes the most recently interned string and returns it together with its symbol.
+    ///
+    /// Unlike `remove` this frees the symbol itself so that it is handed out
+    /// again by the next interning operation.
+    pub fn pop(&mut self) -> Option<(S, String)> {
+        let index = (0..self.backend.len())
+            .rev()
+            .find(|&index| self.backend.resolve(S::from_usize(index)).is_some())?;
+        let symbol = S::from_usize(index);
+        let string = self.remove(symbol)?;
+        self.truncate(index);
+        Some((self.tag(symbol), string))
     }
 
-    /// Returns the string slice associated with the given symbol if available,
-    /// otherwise returns `None`.
-    #[inline]
-    pub fn resolve(&self, symbol: S) -> Option<&str> {
-        self.values
-            .get(symbol.to_usize())
-            .map(|boxed_str| boxed_str.as_ref())
+    /// Removes all strings whose symbols have an index of at least `len`.
+    ///
+    /// Unlike `remove` this frees the symbols themselves so that they are handed out
+    /// again by subsequent interning operations. Does nothing if `len` is greater
+    /// than or equal to the number of symbols handed out.
+    pub fn truncate(&mut self, len: usize) {
+        let total = self.backend.len();
+        if len >= total {
+            return;
+        }
+        let mut live = 0;
+        for index in len..total {
+            let symbol = S::from_usize(index);
+            if let Some(string) = self.backend.resolve(symbol) {
+                let hash = self.hash_of(string);
+                self.bytes -= string.len();
+                self.unlink(hash, symbol);
+                live += 1;
+            }
+        }
+        self.removed -= total - len - live;
+        self.preloaded = std::cmp::min(self.preloaded, len);
+        self.truncate_folded(len);
+        self.backend.truncate(len);
+        self.record_size();
     }
 
-    /// Returns the string associated with the given symbol.
-    ///
-    /// # Note
-    ///
-    /// This does not check whether the given symbol has an associated string
-    /// for the given string interner instance.
+    /// Returns a checkpoint of the current state of the interner.
     ///
-    /// # Safety
-    ///
-    /// This will result in undefined behaviour if the given symbol
-    /// had no associated string for this interner instance.
+    /// Use `rollback` to discard all strings interned after the checkpoint was taken.
     #[inline]
-    pub unsafe fn resolve_unchecked(&self, symbol: S) -> &str {
-        self.values.get_unchecked(symbol.to_usize()).as_ref()
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            len: self.backend.len(),
+        }
     }
 
-    /// Returns the symbol associated with the given string for this interner
-    /// if existent, otherwise returns `None`.
+    /// Restores the interner to the state of the given checkpoint.
+    ///
+    /// Invalidates only the symbols created after the checkpoint was taken.
+    /// Strings removed after the checkpoint was taken are not restored.
     #[inline]
-    pub fn get<T>(&self, val: T) -> Option<S>
-    where
-        T: AsRef<str>,
-    {
-        self.map.get(&val.as_ref().into()).cloned()
+    pub fn rollback(&mut self, checkpoint: Checkpoint) {
+        self.truncate(checkpoint.len)
+    }
+
+    /// Removes all strings from the interner and returns an iterator over them.
+    ///
+    /// The interner keeps the capacity of its internal data structures for reuse.
+    /// All symbols of the drained strings are invalidated and are handed out again.
+    pub fn drain(&mut self) -> IntoIter<S, B> {
+        self.map.clear();
+        self.collisions.clear();
+        if let Some(bloom) = &mut self.bloom {
+            bloom.clear();
+        }
+        if let Some(index) = &mut self.ascii_folded {
+            index.clear();
+        }
+        self.folded.clear();
+        let remaining = self.len();
+        self.removed = 0;
+        self.preloaded = 0;
+        self.bytes = 0;
+        let capacity = self.backend.capacity();
+        let backend = std::mem::replace(&mut self.backend, B::with_capacity(capacity));
+        self.record_size();
+        IntoIter {
+            remaining,
+            #[cfg(feature = "debug-validate")]
+            generation: self.generation,
+            iter: backend.into_strings().enumerate(),
+            mark: marker::PhantomData,
+        }
+    }
+
+    /// Removes the association of the given hash with the given symbol.
+    fn unlink(&mut self, hash: u64, symbol: S) {
+        let key = key_of(hash);
+        if let hash_map::Entry::Occupied(mut entry) = self.collisions.entry(key) {
+            let colliding = entry.get_mut();
+            match colliding.iter().position(|&sym| sym == symbol) {
+                Some(pos) => {
+                    colliding.swap_remove(pos);
+                }
+                None => {
+                    let replacement = colliding.pop().expect("collision lists are never empty");
+                    self.map.insert(key, replacement);
+                }
+            }
+            if entry.get().is_empty() {
+                entry.remove();
+            }
+            return;
+        }
+        self.map.remove(&key);
     }
 
     /// Returns the number of uniquely interned strings within this interner.
     #[inline]
     pub fn len(&self) -> usize {
-        self.values.len()
+        self.backend.len() - self.removed
+    }
+
+    /// Returns an iterator over all symbol indices and their strings if they have not been removed.
+    #[inline]
+    pub(crate) fn slots(&self) -> impl Iterator<Item = Option<&str>> + '_ {
+        (0..self.backend.len()).map(move |index| self.backend.resolve(S::from_usize(index)))
     }
 
     /// Returns true if the string interner holds no elements.
@@ -407,132 +2029,451 @@ where
 
     /// Returns an iterator over the interned strings.
     #[inline]
-    pub fn iter(&self) -> Iter<S> {
+    pub fn iter(&self) -> Iter<'_, S, B> {
         Iter::new(self)
     }
 
+    /// Splits the interned strings into at most `parts` iterators over consecutive
+    /// ranges of symbols of roughly equal length.
+    ///
+    /// The iterators together yield every interned string exactly once and may be
+    /// sent to other threads in order to process the interned strings in parallel.
+    pub fn split_iter(&self, parts: usize) -> Vec<Iter<'_, S, B>> {
+        let len = self.backend.len();
+        let chunk_len = std::cmp::max(1, len.div_ceil(std::cmp::max(1, parts)));
+        (0..len)
+            .step_by(chunk_len)
+            .map(|start| Iter::within(self, start..start + chunk_len))
+            .collect()
+    }
+
+    /// Calls the given closure with every pair of symbol and interned string
+    /// on all available cores.
+    ///
+    /// The interned strings are split into consecutive ranges of symbols, see `split_iter`,
+    /// each of which is processed on its own scoped thread. The order in which the closure
+    /// is called is unspecified. Small interners are processed on the calling thread.
+    pub fn par_for_each<F>(&self, f: F)
+    where
+        F: Fn(S, &str) + Sync,
+        S: Send,
+        B: Sync,
+    {
+        /// The minimum number of strings processed by a single thread.
+        const MIN_CHUNK_LEN: usize = 1 << 12;
+        let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        self.for_each_split(
+            std::cmp::min(threads, self.backend.len() / MIN_CHUNK_LEN),
+            f,
+        )
+    }
+
+    /// Calls the given closure with every pair of symbol and interned string
+    /// on up to `parts` scoped threads.
+    fn for_each_split<F>(&self, parts: usize, f: F)
+    where
+        F: Fn(S, &str) + Sync,
+        S: Send,
+        B: Sync,
+    {
+        if parts <= 1 {
+            return self.iter().for_each(|(sym, s)| f(sym, s));
+        }
+        let f = &f;
+        std::thread::scope(|scope| {
+            let handles = self
+                .split_iter(parts)
+                .into_iter()
+                .map(|iter| scope.spawn(move || iter.for_each(|(sym, s)| f(sym, s))))
+                .collect::<Vec<_>>();
+            for handle in handles {
+                handle
+                    .join()
+                    .unwrap_or_else(|err| std::panic::resume_unwind(err));
+            }
+        });
+    }
+
+    /// Returns an iterator over the strings interned at or after the given symbol.
+    #[inline]
+    pub fn iter_from(&self, start: S) -> Iter<'_, S, B> {
+        Iter::starting_at(self, start.to_usize())
+    }
+
+    /// Returns an iterator over the strings interned after the given checkpoint was taken.
+    #[inline]
+    pub fn symbols_after(&self, checkpoint: Checkpoint) -> Iter<'_, S, B> {
+        Iter::starting_at(self, checkpoint.len)
+    }
+
     /// Returns an iterator over all intern indices and their associated strings.
     #[inline]
-    pub fn iter_values(&self) -> Values<S> {
+    pub fn iter_values(&self) -> Values<'_, S, B> {
         Values::new(self)
     }
 
+    /// Returns an iterator over the interned strings in lexicographic order.
+    ///
+    /// Every call sorts all strings anew, see `SortedStringInterner` for an interner
+    /// that maintains the order of its strings.
+    pub fn iter_sorted(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (S, &str)> + ExactSizeIterator + '_ {
+        let mut pairs = self.iter().collect::<Vec<_>>();
+        pairs.sort_unstable_by_key(|&(_, s)| s);
+        pairs.into_iter()
+    }
+
+    /// Returns an iterator over the symbols of all interned strings in insertion order.
+    #[inline]
+    pub fn symbols(&self) -> Symbols<'_, S, B> {
+        Symbols {
+            iter: Iter::new(self),
+        }
+    }
+
     /// Shrinks the capacity of the interner as much as possible.
     pub fn shrink_to_fit(&mut self) {
         self.map.shrink_to_fit();
-        self.values.shrink_to_fit();
+        self.collisions.shrink_to_fit();
+        self.backend.shrink_to_fit();
+    }
+
+    /// Shrinks the capacity of the interner with a lower bound.
+    ///
+    /// The capacity remains at least as large as both the length and the given value.
+    /// This releases most of the slack without forcing a regrow on the next burst of interning.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.map.shrink_to(min_capacity);
+        self.collisions.shrink_to_fit();
+        self.backend.shrink_to(min_capacity);
     }
 }
 
-impl<T, S> FromIterator<T> for StringInterner<S>
+impl<T, S, B, H> FromIterator<T> for StringInterner<S, B, H>
 where
     S: Symbol,
-    T: Into<String> + AsRef<str>,
+    B: Backend<S>,
+    H: BuildHasher + Default,
+    T: AsRef<str>,
 {
     fn from_iter<I>(iter: I) -> Self
     where
         I: IntoIterator<Item = T>,
     {
         let iter = iter.into_iter();
-        let mut interner = StringInterner::with_capacity(iter.size_hint().0);
+        let mut interner =
+            StringInterner::with_capacity_and_hasher(iter.size_hint().0, H::default());
         interner.extend(iter);
         interner
     }
 }
 
-impl<T, S> std::iter::Extend<T> for StringInterner<S>
+impl<T, S, B, H> std::iter::Extend<T> for StringInterner<S, B, H>
 where
     S: Symbol,
-    T: Into<String> + AsRef<str>,
+    B: Backend<S>,
+    H: BuildHasher,
+    T: AsRef<str>,
 {
     fn extend<I>(&mut self, iter: I)
     where
         I: IntoIterator<Item = T>,
     {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
         for s in iter {
             self.get_or_intern(s);
         }
     }
 }
 
+impl<S, B, H> From<Vec<String>> for StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher + Default,
+{
+    /// Creates an interner from the given strings which are moved into it.
+    ///
+    /// Duplicates are removed and symbols are assigned in order of first occurrence.
+    fn from(strings: Vec<String>) -> Self {
+        let mut interner = StringInterner::with_capacity_and_hasher(strings.len(), H::default());
+        for s in strings {
+            interner.get_or_intern_owned(s);
+        }
+        interner
+    }
+}
+
+impl<'a, S, B, H> From<&'a [&'a str]> for StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher + Default,
+{
+    /// Creates an interner from the given strings.
+    ///
+    /// Duplicates are removed and symbols are assigned in order of first occurrence.
+    #[inline]
+    fn from(strings: &'a [&'a str]) -> Self {
+        strings.iter().collect()
+    }
+}
+
+impl<S, B, H, SH> From<HashSet<String, SH>> for StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher + Default,
+{
+    /// Creates an interner from the given set of strings which are moved into it.
+    ///
+    /// Symbols are assigned in the iteration order of the set.
+    fn from(strings: HashSet<String, SH>) -> Self {
+        let mut interner = StringInterner::with_capacity_and_hasher(strings.len(), H::default());
+        for s in strings {
+            interner.get_or_intern_owned(s);
+        }
+        interner
+    }
+}
+
+/// A saved point in the history of a `StringInterner`.
+///
+/// Created by `StringInterner::checkpoint` and consumed by `StringInterner::rollback`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    len: usize,
+}
+
 /// Iterator over the pairs of associated symbols and interned strings for a `StringInterner`.
-pub struct Iter<'a, S> {
-    iter: iter::Enumerate<slice::Iter<'a, Box<str>>>,
+pub struct Iter<'a, S, B = DefaultBackend<S>> {
+    backend: &'a B,
+    range: ops::Range<usize>,
+    /// The number of strings that have not been yielded yet.
+    remaining: usize,
+    /// The generation yielded symbols are tagged with, see `TaggedSym`.
+    #[cfg(feature = "debug-validate")]
+    generation: u32,
     mark: marker::PhantomData<S>,
 }
 
-impl<'a, S> Iter<'a, S>
+impl<'a, S, B> Iter<'a, S, B>
 where
     S: Symbol + 'a,
+    B: Backend<S>,
 {
     /// Creates a new iterator for the given StringIterator over pairs of
     /// symbols and their associated interned string.
     #[inline]
-    fn new<H>(interner: &'a StringInterner<S, H>) -> Self
+    fn new<H>(interner: &'a StringInterner<S, B, H>) -> Self
+    where
+        H: BuildHasher,
+    {
+        Iter {
+            backend: &interner.backend,
+            range: 0..interner.backend.len(),
+            remaining: interner.len(),
+            #[cfg(feature = "debug-validate")]
+            generation: interner.generation,
+            mark: marker::PhantomData,
+        }
+    }
+
+    /// Creates a new iterator for the given StringIterator over pairs of
+    /// symbols with an index of at least `start` and their associated interned string.
+    #[inline]
+    fn starting_at<H>(interner: &'a StringInterner<S, B, H>, start: usize) -> Self
+    where
+        H: BuildHasher,
+    {
+        Iter::within(interner, start..interner.backend.len())
+    }
+
+    /// Creates a new iterator for the given StringIterator over pairs of
+    /// symbols with an index within `range` and their associated interned string.
+    fn within<H>(interner: &'a StringInterner<S, B, H>, range: ops::Range<usize>) -> Self
     where
         H: BuildHasher,
     {
+        let backend = &interner.backend;
+        let end = std::cmp::min(range.end, backend.len());
+        let range = std::cmp::min(range.start, end)..end;
+        let remaining = match interner.removed {
+            0 => range.len(),
+            _ => range
+                .clone()
+                .filter(|&index| backend.resolve(S::from_usize(index)).is_some())
+                .count(),
+        };
         Iter {
-            iter: interner.values.iter().enumerate(),
+            backend,
+            range,
+            remaining,
+            #[cfg(feature = "debug-validate")]
+            generation: interner.generation,
             mark: marker::PhantomData,
         }
     }
+
+    /// Tags the symbol of the given item with the generation of the interner.
+    ///
+    /// Does nothing without the `debug-validate` crate feature.
+    #[inline]
+    fn tag(&self, item: (S, &'a str)) -> (S, &'a str) {
+        #[cfg(feature = "debug-validate")]
+        {
+            (item.0.with_generation(self.generation), item.1)
+        }
+        #[cfg(not(feature = "debug-validate"))]
+        {
+            item
+        }
+    }
 }
 
-impl<'a, S> Iterator for Iter<'a, S>
+impl<'a, S, B> Iterator for Iter<'a, S, B>
 where
     S: Symbol + 'a,
+    B: Backend<S>,
 {
     type Item = (S, &'a str);
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter
-            .next()
-            .map(|(num, boxed_str)| (S::from_usize(num), boxed_str.as_ref()))
+        let backend = self.backend;
+        let item = self.range.by_ref().find_map(|index| {
+            let sym = S::from_usize(index);
+            backend.resolve(sym).map(|s| (sym, s))
+        })?;
+        self.remaining -= 1;
+        Some(self.tag(item))
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        (self.remaining, Some(self.remaining))
     }
 }
 
+impl<'a, S, B> DoubleEndedIterator for Iter<'a, S, B>
+where
+    S: Symbol + 'a,
+    B: Backend<S>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let backend = self.backend;
+        let item = self.range.by_ref().rev().find_map(|index| {
+            let sym = S::from_usize(index);
+            backend.resolve(sym).map(|s| (sym, s))
+        })?;
+        self.remaining -= 1;
+        Some(self.tag(item))
+    }
+}
+
+impl<'a, S, B> ExactSizeIterator for Iter<'a, S, B>
+where
+    S: Symbol + 'a,
+    B: Backend<S>,
+{
+}
+
+impl<'a, S, B> iter::FusedIterator for Iter<'a, S, B>
+where
+    S: Symbol + 'a,
+    B: Backend<S>,
+{
+}
+
 /// Iterator over the interned strings of a `StringInterner`.
-pub struct Values<'a, S>
+pub struct Values<'a, S, B = DefaultBackend<S>>
 where
     S: Symbol + 'a,
 {
-    iter: slice::Iter<'a, Box<str>>,
-    mark: marker::PhantomData<S>,
+    iter: Iter<'a, S, B>,
 }
 
-impl<'a, S> Values<'a, S>
+impl<'a, S, B> Values<'a, S, B>
 where
     S: Symbol + 'a,
+    B: Backend<S>,
 {
     /// Creates a new iterator for the given StringIterator over its interned strings.
     #[inline]
-    fn new<H>(interner: &'a StringInterner<S, H>) -> Self
+    fn new<H>(interner: &'a StringInterner<S, B, H>) -> Self
     where
         H: BuildHasher,
     {
         Values {
-            iter: interner.values.iter(),
-            mark: marker::PhantomData,
+            iter: Iter::new(interner),
         }
     }
 }
 
-impl<'a, S> Iterator for Values<'a, S>
+impl<'a, S, B> Iterator for Values<'a, S, B>
 where
     S: Symbol + 'a,
+    B: Backend<S>,
 {
     type Item = &'a str;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next().map(|boxed_str| boxed_str.as_ref())
+        self.iter.next().map(|(_, s)| s)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, S, B> DoubleEndedIterator for Values<'a, S, B>
+where
+    S: Symbol + 'a,
+    B: Backend<S>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(_, s)| s)
+    }
+}
+
+impl<'a, S, B> ExactSizeIterator for Values<'a, S, B>
+where
+    S: Symbol + 'a,
+    B: Backend<S>,
+{
+}
+
+impl<'a, S, B> iter::FusedIterator for Values<'a, S, B>
+where
+    S: Symbol + 'a,
+    B: Backend<S>,
+{
+}
+
+/// Iterator over the symbols of the interned strings of a `StringInterner`.
+pub struct Symbols<'a, S, B = DefaultBackend<S>>
+where
+    S: Symbol + 'a,
+{
+    iter: Iter<'a, S, B>,
+}
+
+impl<'a, S, B> Iterator for Symbols<'a, S, B>
+where
+    S: Symbol + 'a,
+    B: Backend<S>,
+{
+    type Item = S;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(sym, _)| sym)
     }
 
     #[inline]
@@ -541,47 +2482,158 @@ where
     }
 }
 
-impl<S, H> iter::IntoIterator for StringInterner<S, H>
+impl<'a, S, B> DoubleEndedIterator for Symbols<'a, S, B>
+where
+    S: Symbol + 'a,
+    B: Backend<S>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(sym, _)| sym)
+    }
+}
+
+impl<'a, S, B> ExactSizeIterator for Symbols<'a, S, B>
+where
+    S: Symbol + 'a,
+    B: Backend<S>,
+{
+}
+
+impl<'a, S, B> iter::FusedIterator for Symbols<'a, S, B>
+where
+    S: Symbol + 'a,
+    B: Backend<S>,
+{
+}
+
+impl<S, B, H> iter::IntoIterator for StringInterner<S, B, H>
 where
     S: Symbol,
+    B: Backend<S>,
     H: BuildHasher,
 {
     type Item = (S, String);
-    type IntoIter = IntoIter<S>;
+    type IntoIter = IntoIter<S, B>;
 
     fn into_iter(self) -> Self::IntoIter {
         IntoIter {
-            iter: self.values.into_iter().enumerate(),
+            remaining: self.len(),
+            #[cfg(feature = "debug-validate")]
+            generation: self.generation,
+            iter: self.backend.into_strings().enumerate(),
             mark: marker::PhantomData,
         }
     }
 }
 
+impl<'a, S, B, H> iter::IntoIterator for &'a StringInterner<S, B, H>
+where
+    S: Symbol + 'a,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    type Item = (S, &'a str);
+    type IntoIter = Iter<'a, S, B>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 /// Iterator over the pairs of associated symbol and strings.
 ///
 /// Consumes the `StringInterner` upon usage.
-pub struct IntoIter<S>
+pub struct IntoIter<S, B = DefaultBackend<S>>
 where
     S: Symbol,
+    B: Backend<S>,
 {
-    iter: iter::Enumerate<vec::IntoIter<Box<str>>>,
+    iter: iter::Enumerate<B::IntoStrings>,
+    /// The number of strings that have not been yielded yet.
+    remaining: usize,
+    /// The generation yielded symbols are tagged with, see `TaggedSym`.
+    #[cfg(feature = "debug-validate")]
+    generation: u32,
     mark: marker::PhantomData<S>,
 }
 
-impl<S> Iterator for IntoIter<S>
+impl<S, B> IntoIter<S, B>
+where
+    S: Symbol,
+    B: Backend<S>,
+{
+    /// Tags the symbol of the given item with the generation of the interner.
+    ///
+    /// Does nothing without the `debug-validate` crate feature.
+    #[inline]
+    fn tag(&self, item: (S, String)) -> (S, String) {
+        #[cfg(feature = "debug-validate")]
+        {
+            (item.0.with_generation(self.generation), item.1)
+        }
+        #[cfg(not(feature = "debug-validate"))]
+        {
+            item
+        }
+    }
+}
+
+impl<S, B> Iterator for IntoIter<S, B>
 where
     S: Symbol,
+    B: Backend<S>,
 {
     type Item = (S, String);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter
-            .next()
-            .map(|(num, boxed_str)| (S::from_usize(num), boxed_str.into_string()))
+        if self.remaining == 0 {
+            return None;
+        }
+        let item = self
+            .iter
+            .find_map(|(num, string)| string.map(|string| (S::from_usize(num), string)))?;
+        self.remaining -= 1;
+        Some(self.tag(item))
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<S, B> DoubleEndedIterator for IntoIter<S, B>
+where
+    S: Symbol,
+    B: Backend<S>,
+    B::IntoStrings: DoubleEndedIterator + ExactSizeIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let item = self
+            .iter
+            .by_ref()
+            .rev()
+            .find_map(|(num, string)| string.map(|string| (S::from_usize(num), string)))?;
+        self.remaining -= 1;
+        Some(self.tag(item))
     }
 }
+
+impl<S, B> ExactSizeIterator for IntoIter<S, B>
+where
+    S: Symbol,
+    B: Backend<S>,
+{
+}
+
+impl<S, B> iter::FusedIterator for IntoIter<S, B>
+where
+    S: Symbol,
+    B: Backend<S>,
+{
+}
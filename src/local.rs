@@ -0,0 +1,138 @@
+use crate::{
+    backend::{DefaultBackend, StableBackend},
+    DefaultHashBuilder, StringInterner, Symbol,
+};
+
+use std::{cell::RefCell, hash::BuildHasher};
+
+/// A single-threaded string interner that interns through shared references.
+///
+/// This allows data structures such as AST nodes to hold a plain `&LocalStringInterner`
+/// and still intern new strings.
+///
+/// # Note
+///
+/// Only backends whose strings never move while interning are supported, see `StableBackend`.
+/// Strings are never removed from a `LocalStringInterner`, so resolved strings stay valid
+/// for as long as the interner is borrowed, even across later calls to `get_or_intern`.
+#[derive(Debug)]
+pub struct LocalStringInterner<S, B = DefaultBackend<S>, H = DefaultHashBuilder>
+where
+    S: Symbol,
+    B: StableBackend<S>,
+    H: BuildHasher,
+{
+    inner: RefCell<StringInterner<S, B, H>>,
+}
+
+impl<S> Default for LocalStringInterner<S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn default() -> Self {
+        LocalStringInterner::new()
+    }
+}
+
+impl<S> LocalStringInterner<S>
+where
+    S: Symbol,
+{
+    /// Creates a new empty `LocalStringInterner`.
+    #[inline]
+    pub fn new() -> Self {
+        LocalStringInterner::with_hasher(DefaultHashBuilder::default())
+    }
+}
+
+impl<S, B, H> From<StringInterner<S, B, H>> for LocalStringInterner<S, B, H>
+where
+    S: Symbol,
+    B: StableBackend<S>,
+    H: BuildHasher,
+{
+    #[inline]
+    fn from(interner: StringInterner<S, B, H>) -> Self {
+        LocalStringInterner {
+            inner: RefCell::new(interner),
+        }
+    }
+}
+
+impl<S, B, H> LocalStringInterner<S, B, H>
+where
+    S: Symbol,
+    B: StableBackend<S>,
+    H: BuildHasher,
+{
+    /// Creates a new empty `LocalStringInterner` with the given hasher.
+    #[inline]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        LocalStringInterner::from(StringInterner::with_hasher(hash_builder))
+    }
+
+    /// Interns the given value.
+    ///
+    /// Returns a symbol to access it within this interner.
+    #[inline]
+    pub fn get_or_intern<T>(&self, val: T) -> S
+    where
+        T: AsRef<str>,
+    {
+        self.inner.borrow_mut().get_or_intern(val)
+    }
+
+    /// Interns the given owned string.
+    ///
+    /// Returns a symbol to access it within this interner.
+    #[inline]
+    pub fn get_or_intern_owned(&self, val: String) -> S {
+        self.inner.borrow_mut().get_or_intern_owned(val)
+    }
+
+    /// Returns the symbol associated with the given string for this interner
+    /// if existent, otherwise returns `None`.
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        self.inner.borrow().get(val)
+    }
+
+    /// Returns the string slice associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&str> {
+        self.inner.borrow().resolve(symbol).map(|s| {
+            // This is safe since the backend never moves interned strings while
+            // interning and strings are never removed while the interner is alive.
+            unsafe { &*(s as *const str) }
+        })
+    }
+
+    /// Returns the number of uniquely interned strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.borrow().len()
+    }
+
+    /// Returns true if the string interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be interned.
+    #[inline]
+    pub fn reserve(&self, additional: usize) {
+        self.inner.borrow_mut().reserve(additional)
+    }
+
+    /// Converts this interner back into a `StringInterner`.
+    #[inline]
+    pub fn into_inner(self) -> StringInterner<S, B, H> {
+        self.inner.into_inner()
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{backend::DefaultBackend, DefaultHashBuilder, StringInterner, Symbol};
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::BuildHasher,
+};
+
+/// A string interner holding at most a fixed number of strings.
+///
+/// Interning a new string into a full interner evicts the least recently used string
+/// and invalidates its symbol. Use `is_live` to check whether a symbol is still valid.
+///
+/// # Note
+///
+/// Symbols of evicted strings are never handed out again so that they cannot
+/// accidentally resolve to another string. Internally the storage of evicted strings
+/// is reclaimed once they make up half of it, so memory usage stays bounded
+/// by `max_entries` regardless of how many strings have been interned.
+#[derive(Debug, Clone)]
+pub struct LruStringInterner<S, H = DefaultHashBuilder>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Stores the strings under internal symbols that are renumbered on compaction.
+    inner: StringInterner<S, DefaultBackend<S>, H>,
+    /// The symbol and the last use of every internal symbol.
+    entries: Vec<(S, u64)>,
+    /// The internal symbols of all live symbols.
+    internal: HashMap<usize, S>,
+    /// The live internal symbols ordered by their last use.
+    recency: BTreeMap<u64, S>,
+    tick: u64,
+    /// The index of the next symbol handed out.
+    next: usize,
+    max_entries: usize,
+}
+
+impl<S> LruStringInterner<S>
+where
+    S: Symbol,
+{
+    /// Creates a new empty `LruStringInterner` holding at most `max_entries` strings.
+    ///
+    /// # Panics
+    ///
+    /// If `max_entries` is zero.
+    #[inline]
+    pub fn with_max_entries(max_entries: usize) -> Self {
+        LruStringInterner::with_max_entries_and_hasher(max_entries, DefaultHashBuilder::default())
+    }
+}
+
+impl<S, H> LruStringInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `LruStringInterner` holding at most `max_entries` strings
+    /// with the given hasher.
+    ///
+    /// # Panics
+    ///
+    /// If `max_entries` is zero.
+    pub fn with_max_entries_and_hasher(max_entries: usize, hash_builder: H) -> Self {
+        assert!(
+            max_entries > 0,
+            "an LRU interner must hold at least one string"
+        );
+        LruStringInterner {
+            inner: StringInterner::with_hasher(hash_builder),
+            entries: Vec::new(),
+            internal: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+            next: 0,
+            max_entries,
+        }
+    }
+
+    /// Interns the given value and marks it as most recently used.
+    ///
+    /// Evicts the least recently used string if the interner is full.
+    /// Returns a symbol to access it within this interner.
+    pub fn get_or_intern<T>(&mut self, val: T) -> S
+    where
+        T: AsRef<str>,
+    {
+        if let Some(symbol) = self.get(val.as_ref()) {
+            return symbol;
+        }
+        if self.inner.len() == self.max_entries {
+            let (_, evicted) = self
+                .recency
+                .pop_first()
+                .expect("a full interner has a least recently used string");
+            self.inner.remove(evicted);
+            let (symbol, _) = self.entries[evicted.to_usize()];
+            self.internal.remove(&symbol.to_usize());
+        }
+        if self.slots() >= 2 * self.max_entries {
+            self.compact();
+        }
+        let symbol = S::from_usize(self.next);
+        self.next += 1;
+        let internal = self.inner.get_or_intern(val);
+        self.entries.push((symbol, 0));
+        self.internal.insert(symbol.to_usize(), internal);
+        self.touch(internal);
+        symbol
+    }
+
+    /// Returns the symbol associated with the given string if existent and marks
+    /// it as most recently used, otherwise returns `None`.
+    pub fn get<T>(&mut self, val: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        let internal = self.inner.get(val)?;
+        let (symbol, tick) = self.entries[internal.to_usize()];
+        self.recency.remove(&tick);
+        self.touch(internal);
+        Some(symbol)
+    }
+
+    /// Marks the given live internal symbol as most recently used.
+    fn touch(&mut self, internal: S) {
+        self.tick += 1;
+        self.entries[internal.to_usize()].1 = self.tick;
+        self.recency.insert(self.tick, internal);
+    }
+
+    /// Returns the number of internal symbols including those of evicted strings.
+    #[inline]
+    pub(crate) fn slots(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Renumbers the internal symbols densely and drops the storage of evicted strings.
+    fn compact(&mut self) {
+        let remap = self.inner.compact();
+        let mut entries = vec![(S::from_usize(0), 0); self.inner.len()];
+        for (old, new) in remap.iter() {
+            entries[new.to_usize()] = self.entries[old.to_usize()];
+        }
+        self.entries = entries;
+        for internal in self.internal.values_mut().chain(self.recency.values_mut()) {
+            *internal = remap
+                .get(*internal)
+                .expect("live symbols are never evicted");
+        }
+    }
+
+    /// Returns the string slice associated with the given symbol if it has not been evicted,
+    /// otherwise returns `None`.
+    ///
+    /// This does not mark the string as used.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&str> {
+        let internal = *self.internal.get(&symbol.to_usize())?;
+        self.inner.resolve(internal)
+    }
+
+    /// Returns true if the given symbol is associated with a string that has not been evicted.
+    #[inline]
+    pub fn is_live(&self, symbol: S) -> bool {
+        self.resolve(symbol).is_some()
+    }
+
+    /// Returns the maximum number of strings held by this interner.
+    #[inline]
+    pub fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+
+    /// Returns the number of uniquely interned strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the string interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{backend::Backend, HashToSymbol, StringInterner, Symbol};
+
+use std::{collections::HashMap, hash::BuildHasher, mem};
+
+/// The heap memory used by a `StringInterner` as reported by `StringInterner::memory_usage`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MemoryUsage {
+    map: usize,
+    values: usize,
+    strings: usize,
+    auxiliary: usize,
+}
+
+impl MemoryUsage {
+    /// Returns the number of heap bytes used by the hash maps for look-ups.
+    ///
+    /// This is an estimate since the exact layout of the hash maps is not exposed.
+    #[inline]
+    pub fn map(&self) -> usize {
+        self.map
+    }
+
+    /// Returns the number of heap bytes used by the backend to index the strings.
+    #[inline]
+    pub fn values(&self) -> usize {
+        self.values
+    }
+
+    /// Returns the number of heap bytes used by the contents of the strings.
+    #[inline]
+    pub fn strings(&self) -> usize {
+        self.strings
+    }
+
+    /// Returns the number of heap bytes used by the Bloom filter, the cached
+    /// lowercase forms and the reusable formatting buffer.
+    #[inline]
+    pub fn auxiliary(&self) -> usize {
+        self.auxiliary
+    }
+
+    /// Returns the total number of heap bytes used by the interner.
+    #[inline]
+    pub fn total(&self) -> usize {
+        self.map + self.values + self.strings + self.auxiliary
+    }
+}
+
+/// Returns an estimate of the heap bytes used by the table of the given hash map.
+fn table_bytes<K, V, H>(map: &HashMap<K, V, H>) -> usize {
+    // Every slot additionally uses one control byte.
+    map.capacity() * (mem::size_of::<(K, V)>() + 1)
+}
+
+/// Returns an estimate of the heap bytes used by the given map of colliding symbols.
+fn colliding_bytes<S>(map: &HashToSymbol<Vec<S>>) -> usize {
+    let symbols = map
+        .values()
+        .map(|symbols| symbols.capacity() * mem::size_of::<S>())
+        .sum::<usize>();
+    table_bytes(map) + symbols
+}
+
+impl<S, B, H> StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Returns the heap memory used by the interner.
+    ///
+    /// The index for ASCII case-insensitive look-ups is counted towards the hash maps.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let ascii_folded = self.ascii_folded.as_ref().map_or(0, colliding_bytes);
+        let bloom = self.bloom.as_ref().map_or(0, |bloom| bloom.heap_bytes());
+        MemoryUsage {
+            map: table_bytes(&self.map) + colliding_bytes(&self.collisions) + ascii_folded,
+            values: self.backend.values_heap_bytes(),
+            strings: self.backend.strings_heap_bytes(),
+            auxiliary: bloom
+                + self.folded.capacity() * mem::size_of::<Option<S>>()
+                + self.scratch.capacity(),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+/// Receives the metrics of a `StringInterner`, see `StringInterner::set_metrics`.
+///
+/// The methods map onto the counters and gauges of metrics facades such as the
+/// `metrics` crate, so that interner growth can be watched in production dashboards.
+/// Use `AtomicMetrics` to simply accumulate the metrics in memory.
+pub trait MetricsRecorder: Send + Sync {
+    /// Called for every look-up of a string by `get_or_intern`, `get` and related methods
+    /// with whether the string had already been interned.
+    fn record_lookup(&self, hit: bool) {
+        let _ = hit;
+    }
+
+    /// Called with the number of interned strings and their total length in bytes
+    /// whenever either changes.
+    fn record_size(&self, len: usize, bytes: usize) {
+        let _ = (len, bytes);
+    }
+}
+
+/// A `MetricsRecorder` that accumulates all metrics in atomic counters.
+#[derive(Debug, Default)]
+pub struct AtomicMetrics {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    len: AtomicUsize,
+    bytes: AtomicUsize,
+}
+
+impl AtomicMetrics {
+    /// Creates new metrics with all counters set to zero.
+    #[inline]
+    pub fn new() -> Self {
+        AtomicMetrics::default()
+    }
+
+    /// Returns the number of look-ups of strings that had already been interned.
+    #[inline]
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of look-ups of strings that had not been interned.
+    #[inline]
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Returns the ratio of hits to all look-ups.
+    ///
+    /// Returns zero if no look-ups have been recorded.
+    #[inline]
+    pub fn hit_ratio(&self) -> f64 {
+        let hits = self.hits();
+        match hits + self.misses() {
+            0 => 0.0,
+            total => hits as f64 / total as f64,
+        }
+    }
+
+    /// Returns the most recently recorded number of interned strings.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    /// Returns true if the most recently recorded number of interned strings is zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the most recently recorded total length in bytes of all interned strings.
+    #[inline]
+    pub fn bytes(&self) -> usize {
+        self.bytes.load(Ordering::Relaxed)
+    }
+}
+
+impl MetricsRecorder for AtomicMetrics {
+    #[inline]
+    fn record_lookup(&self, hit: bool) {
+        match hit {
+            true => self.hits.fetch_add(1, Ordering::Relaxed),
+            false => self.misses.fetch_add(1, Ordering::Relaxed),
+        };
+    }
+
+    #[inline]
+    fn record_size(&self, len: usize, bytes: usize) {
+        self.len.store(len, Ordering::Relaxed);
+        self.bytes.store(bytes, Ordering::Relaxed);
+    }
+}
+
+/// A recorder registered via `StringInterner::set_metrics`.
+#[derive(Clone)]
+pub(crate) struct MetricsHook(pub Arc<dyn MetricsRecorder>);
+
+impl fmt::Debug for MetricsHook {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MetricsHook")
+    }
+}
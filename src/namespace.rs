@@ -0,0 +1,151 @@
+use crate::{backend::DefaultBackend, StringInterner, Sym, Symbol};
+
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker,
+};
+
+/// A symbol of type `S` that is distinct for every tag type `T`.
+///
+/// # Note
+///
+/// Interners of different namespaces, see `NamespacedStringInterner`, hand out
+/// symbols of different types so that the type system rejects resolving a symbol
+/// of one namespace with the interner of another. The tag is a zero-sized marker
+/// and `NamespacedSym<T, S>` has the same size as `S`.
+///
+/// ### Example
+///
+/// ```compile_fail
+/// # use string_interner::NamespacedStringInterner;
+/// enum Modules {}
+/// enum Fields {}
+///
+/// let mut modules = NamespacedStringInterner::<Modules>::new();
+/// let fields = NamespacedStringInterner::<Fields>::new();
+/// let std = modules.get_or_intern("std");
+/// fields.resolve(std);
+/// ```
+pub struct NamespacedSym<T, S = Sym> {
+    symbol: S,
+    tag: marker::PhantomData<fn() -> T>,
+}
+
+/// A `StringInterner` whose symbols are tagged with the namespace `T`.
+pub type NamespacedStringInterner<T, S = Sym> =
+    StringInterner<NamespacedSym<T, S>, DefaultBackend<NamespacedSym<T, S>>>;
+
+impl<T, S> NamespacedSym<T, S>
+where
+    S: Symbol,
+{
+    /// Returns the untagged symbol.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.symbol
+    }
+}
+
+impl<T, S> fmt::Debug for NamespacedSym<T, S>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("NamespacedSym").field(&self.symbol).finish()
+    }
+}
+
+impl<T, S> Copy for NamespacedSym<T, S> where S: Copy {}
+
+impl<T, S> Clone for NamespacedSym<T, S>
+where
+    S: Copy,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, S> PartialEq for NamespacedSym<T, S>
+where
+    S: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.symbol == other.symbol
+    }
+}
+
+impl<T, S> Eq for NamespacedSym<T, S> where S: Eq {}
+
+impl<T, S> PartialOrd for NamespacedSym<T, S>
+where
+    S: Ord,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, S> Ord for NamespacedSym<T, S>
+where
+    S: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.symbol.cmp(&other.symbol)
+    }
+}
+
+impl<T, S> Hash for NamespacedSym<T, S>
+where
+    S: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.symbol.hash(state)
+    }
+}
+
+impl<T, S> Symbol for NamespacedSym<T, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn from_usize(val: usize) -> Self {
+        NamespacedSym {
+            symbol: S::from_usize(val),
+            tag: marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    fn try_from_usize(val: usize) -> Option<Self> {
+        S::try_from_usize(val).map(|symbol| NamespacedSym {
+            symbol,
+            tag: marker::PhantomData,
+        })
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        self.symbol.to_usize()
+    }
+
+    #[inline]
+    fn with_generation(self, generation: u32) -> Self {
+        NamespacedSym {
+            symbol: self.symbol.with_generation(generation),
+            tag: marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    fn generation(self) -> Option<u32> {
+        self.symbol.generation()
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{
+    backend::{Backend, DefaultBackend},
+    DefaultHashBuilder, StringInterner, Symbol,
+};
+
+use std::{borrow::Cow, hash::BuildHasher};
+
+/// Types implementing this trait normalize strings before they are interned.
+///
+/// Strings with equal normal forms are associated with the same symbol
+/// by a `NormalizingStringInterner`.
+///
+/// # Note
+///
+/// This crate does not provide a Unicode normalizer such as NFC since that requires
+/// the Unicode character database. Implement this trait on top of a crate that
+/// provides one instead, for example `unicode-normalization`:
+///
+/// ```ignore
+/// use std::borrow::Cow;
+/// use string_interner::{Normalizer, NormalizingStringInterner, Sym};
+/// use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+///
+/// #[derive(Default)]
+/// struct Nfc;
+///
+/// impl Normalizer for Nfc {
+///     fn normalize<'a>(&self, val: &'a str) -> Cow<'a, str> {
+///         match is_nfc_quick(val.chars()) {
+///             IsNormalized::Yes => Cow::Borrowed(val),
+///             _ => Cow::Owned(val.nfc().collect()),
+///         }
+///     }
+/// }
+///
+/// let mut interner = NormalizingStringInterner::<Sym, Nfc>::default();
+/// let composed = interner.get_or_intern("\u{e9}");
+/// assert_eq!(interner.get_or_intern("e\u{301}"), composed);
+/// ```
+pub trait Normalizer {
+    /// Returns the normal form of the given string.
+    ///
+    /// Implementations should borrow the given string if it is already normalized.
+    fn normalize<'a>(&self, val: &'a str) -> Cow<'a, str>;
+}
+
+/// A normalizer that leaves all strings unchanged.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct IdentityNormalizer;
+
+impl Normalizer for IdentityNormalizer {
+    #[inline]
+    fn normalize<'a>(&self, val: &'a str) -> Cow<'a, str> {
+        Cow::Borrowed(val)
+    }
+}
+
+/// A normalizer that converts all ASCII letters to lowercase.
+///
+/// Non-ASCII characters are left unchanged.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct AsciiCaseFold;
+
+impl Normalizer for AsciiCaseFold {
+    #[inline]
+    fn normalize<'a>(&self, val: &'a str) -> Cow<'a, str> {
+        match val.bytes().any(|byte| byte.is_ascii_uppercase()) {
+            true => Cow::Owned(val.to_ascii_lowercase()),
+            false => Cow::Borrowed(val),
+        }
+    }
+}
+
+impl<F> Normalizer for F
+where
+    F: for<'a> Fn(&'a str) -> Cow<'a, str>,
+{
+    #[inline]
+    fn normalize<'a>(&self, val: &'a str) -> Cow<'a, str> {
+        self(val)
+    }
+}
+
+/// A string interner that normalizes all strings before interning or looking them up.
+///
+/// Only the normal forms are stored, so `resolve` returns the normal form of a string.
+///
+/// # Note
+///
+/// Strings that are already in normal form are neither copied nor allocated
+/// for look-ups if the normalizer borrows them.
+#[derive(Debug, Clone)]
+pub struct NormalizingStringInterner<
+    S,
+    N = IdentityNormalizer,
+    B = DefaultBackend<S>,
+    H = DefaultHashBuilder,
+> where
+    S: Symbol,
+    N: Normalizer,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    inner: StringInterner<S, B, H>,
+    normalizer: N,
+}
+
+impl<S, N, B> Default for NormalizingStringInterner<S, N, B>
+where
+    S: Symbol,
+    N: Normalizer + Default,
+    B: Backend<S>,
+{
+    #[inline]
+    fn default() -> Self {
+        NormalizingStringInterner::with_normalizer(N::default())
+    }
+}
+
+impl<S, N, B> NormalizingStringInterner<S, N, B>
+where
+    S: Symbol,
+    N: Normalizer,
+    B: Backend<S>,
+{
+    /// Creates a new empty `NormalizingStringInterner` with the given normalizer.
+    #[inline]
+    pub fn with_normalizer(normalizer: N) -> Self {
+        NormalizingStringInterner::with_normalizer_and_hasher(
+            normalizer,
+            DefaultHashBuilder::default(),
+        )
+    }
+}
+
+impl<S, N, B, H> NormalizingStringInterner<S, N, B, H>
+where
+    S: Symbol,
+    N: Normalizer,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Creates a new empty `NormalizingStringInterner` with the given normalizer and hasher.
+    #[inline]
+    pub fn with_normalizer_and_hasher(normalizer: N, hash_builder: H) -> Self {
+        NormalizingStringInterner {
+            inner: StringInterner::with_hasher(hash_builder),
+            normalizer,
+        }
+    }
+
+    /// Interns the normal form of the given value.
+    ///
+    /// Returns a symbol to access it within this interner.
+    #[inline]
+    pub fn get_or_intern<T>(&mut self, val: T) -> S
+    where
+        T: AsRef<str>,
+    {
+        match self.normalizer.normalize(val.as_ref()) {
+            Cow::Borrowed(val) => self.inner.get_or_intern(val),
+            Cow::Owned(val) => self.inner.get_or_intern_owned(val),
+        }
+    }
+
+    /// Returns the symbol associated with the normal form of the given string
+    /// if existent, otherwise returns `None`.
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        self.inner.get(self.normalizer.normalize(val.as_ref()))
+    }
+
+    /// Returns the normal form of the string associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&str> {
+        self.inner.resolve(symbol)
+    }
+
+    /// Returns the number of uniquely interned strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the string interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the normalizer of this interner.
+    #[inline]
+    pub fn normalizer(&self) -> &N {
+        &self.normalizer
+    }
+
+    /// Returns the underlying `StringInterner`.
+    #[inline]
+    pub fn as_interner(&self) -> &StringInterner<S, B, H> {
+        &self.inner
+    }
+
+    /// Converts this interner into its underlying `StringInterner` and discards the normalizer.
+    #[inline]
+    pub fn into_inner(self) -> StringInterner<S, B, H> {
+        self.inner
+    }
+}
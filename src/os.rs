@@ -0,0 +1,209 @@
+use crate::{slice::SliceInterner, DefaultHashBuilder, Symbol};
+
+use std::{ffi::OsStr, hash::BuildHasher, path::Path};
+
+/// Returns the `OsStr` of the given bytes.
+///
+/// # Safety
+///
+/// The bytes must have been returned by `OsStr::as_encoded_bytes` on this platform.
+#[inline]
+unsafe fn os_str_of(bytes: &[u8]) -> &OsStr {
+    OsStr::from_encoded_bytes_unchecked(bytes)
+}
+
+/// An interner for platform strings that are not necessarily valid UTF-8.
+///
+/// Strings are stored in their platform encoding without lossy conversions.
+/// All strings are stored contiguously within a single buffer.
+#[derive(Debug, Clone)]
+pub struct OsStrInterner<S, H = DefaultHashBuilder>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    inner: SliceInterner<u8, S, H>,
+}
+
+impl<S> Default for OsStrInterner<S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn default() -> Self {
+        OsStrInterner::new()
+    }
+}
+
+impl<S> OsStrInterner<S>
+where
+    S: Symbol,
+{
+    /// Creates a new empty `OsStrInterner`.
+    #[inline]
+    pub fn new() -> Self {
+        OsStrInterner::with_hasher(DefaultHashBuilder::default())
+    }
+}
+
+impl<S, H> OsStrInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `OsStrInterner` with the given hasher.
+    #[inline]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        OsStrInterner {
+            inner: SliceInterner::with_hasher(hash_builder),
+        }
+    }
+
+    /// Interns the given platform string.
+    ///
+    /// Returns a symbol to access it within this interner.
+    #[inline]
+    pub fn get_or_intern<T>(&mut self, val: T) -> S
+    where
+        T: AsRef<OsStr>,
+    {
+        self.inner.get_or_intern(val.as_ref().as_encoded_bytes())
+    }
+
+    /// Returns the symbol associated with the given platform string if existent,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<OsStr>,
+    {
+        self.inner.get(val.as_ref().as_encoded_bytes())
+    }
+
+    /// Returns the platform string associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&OsStr> {
+        self.inner.resolve(symbol).map(|bytes| {
+            // This is safe since every stored slice has been returned by `as_encoded_bytes`.
+            unsafe { os_str_of(bytes) }
+        })
+    }
+
+    /// Returns the number of uniquely interned strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the symbols and platform strings in insertion order.
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (S, &OsStr)> + ExactSizeIterator + '_ {
+        self.inner.iter().map(|(sym, bytes)| {
+            // This is safe since every stored slice has been returned by `as_encoded_bytes`.
+            (sym, unsafe { os_str_of(bytes) })
+        })
+    }
+}
+
+/// An interner for file system paths.
+///
+/// # Note
+///
+/// Paths are interned by their exact platform encoding, so paths such as `a/b` and `a//b`
+/// that compare equal as `Path` are associated with different symbols.
+#[derive(Debug, Clone)]
+pub struct PathInterner<S, H = DefaultHashBuilder>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    inner: OsStrInterner<S, H>,
+}
+
+impl<S> Default for PathInterner<S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn default() -> Self {
+        PathInterner::new()
+    }
+}
+
+impl<S> PathInterner<S>
+where
+    S: Symbol,
+{
+    /// Creates a new empty `PathInterner`.
+    #[inline]
+    pub fn new() -> Self {
+        PathInterner::with_hasher(DefaultHashBuilder::default())
+    }
+}
+
+impl<S, H> PathInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `PathInterner` with the given hasher.
+    #[inline]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        PathInterner {
+            inner: OsStrInterner::with_hasher(hash_builder),
+        }
+    }
+
+    /// Interns the given path.
+    ///
+    /// Returns a symbol to access it within this interner.
+    #[inline]
+    pub fn get_or_intern<T>(&mut self, val: T) -> S
+    where
+        T: AsRef<Path>,
+    {
+        self.inner.get_or_intern(val.as_ref())
+    }
+
+    /// Returns the symbol associated with the given path if existent,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<Path>,
+    {
+        self.inner.get(val.as_ref())
+    }
+
+    /// Returns the path associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&Path> {
+        self.inner.resolve(symbol).map(Path::new)
+    }
+
+    /// Returns the number of uniquely interned paths within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the symbols and paths in insertion order.
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (S, &Path)> + ExactSizeIterator + '_ {
+        self.inner.iter().map(|(sym, os)| (sym, Path::new(os)))
+    }
+}
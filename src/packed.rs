@@ -0,0 +1,115 @@
+use std::{iter, slice};
+
+/// The bit set in the end offsets of removed strings.
+///
+/// It is never part of a valid offset since buffers do not exceed `isize::MAX` bytes.
+pub(crate) const REMOVED: usize = !(usize::MAX >> 1);
+
+/// Strings stored contiguously within a single buffer and indexed by their symbols.
+///
+/// Removed strings keep their index so that the indices of all other strings
+/// remain valid, but they do not resolve and are skipped by iteration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PackedStrings {
+    buffer: Box<str>,
+    /// The end offset of every string, see `REMOVED`.
+    ends: Box<[usize]>,
+    /// The number of strings that have not been removed.
+    len: usize,
+}
+
+impl PackedStrings {
+    /// Packs the given strings, where `None` marks a removed string.
+    ///
+    /// `bytes` is the total length of all given strings.
+    pub fn new<'a, I>(slots: I, bytes: usize) -> Self
+    where
+        I: IntoIterator<Item = Option<&'a str>>,
+    {
+        let mut buffer = String::with_capacity(bytes);
+        let mut len = 0;
+        let ends = slots
+            .into_iter()
+            .map(|s| match s {
+                Some(s) => {
+                    buffer.push_str(s);
+                    len += 1;
+                    buffer.len()
+                }
+                None => buffer.len() | REMOVED,
+            })
+            .collect::<Vec<_>>()
+            .into_boxed_slice();
+        PackedStrings {
+            buffer: buffer.into_boxed_str(),
+            ends,
+            len,
+        }
+    }
+
+    /// Returns the string with the given index unless it does not exist or has been removed.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&str> {
+        let end = *self.ends.get(index)?;
+        if end & REMOVED != 0 {
+            return None;
+        }
+        let start = match index {
+            0 => 0,
+            n => self.ends[n - 1] & !REMOVED,
+        };
+        Some(&self.buffer[start..end])
+    }
+
+    /// Returns the number of strings that have not been removed.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns an iterator over the indices and strings that have not been removed.
+    #[inline]
+    pub fn iter(&self) -> PackedIter<'_> {
+        PackedIter {
+            buffer: &self.buffer,
+            ends: self.ends.iter().enumerate(),
+            start: 0,
+            remaining: self.len,
+        }
+    }
+}
+
+/// Iterator over the pairs of indices and strings of `PackedStrings`.
+#[derive(Debug, Clone)]
+pub(crate) struct PackedIter<'a> {
+    buffer: &'a str,
+    ends: iter::Enumerate<slice::Iter<'a, usize>>,
+    start: usize,
+    /// The number of strings that have not been yielded yet.
+    remaining: usize,
+}
+
+impl<'a> Iterator for PackedIter<'a> {
+    type Item = (usize, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, &end) in &mut self.ends {
+            let start = std::mem::replace(&mut self.start, end & !REMOVED);
+            if end & REMOVED == 0 {
+                self.remaining -= 1;
+                return Some((index, &self.buffer[start..end]));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for PackedIter<'a> {}
+
+impl<'a> iter::FusedIterator for PackedIter<'a> {}
@@ -0,0 +1,197 @@
+use crate::{backend::Backend, packed::PackedStrings, StringInterner, Symbol};
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    convert::TryFrom,
+    hash::{BuildHasher, Hash, Hasher},
+    marker,
+};
+
+/// The average number of strings per bucket of the perfect hash function.
+const BUCKET_SIZE: usize = 4;
+
+/// The minimum number of pilots tried per bucket before the construction starts over
+/// with another seed. Larger sets try proportionally more pilots.
+const MIN_PILOTS: u32 = 1 << 16;
+
+/// A read-only string interner with a minimal perfect hash function created by
+/// `StringInterner::freeze_perfect`.
+///
+/// Look-ups compute a single hash and compare against exactly one candidate string
+/// without any probing or collision handling.
+///
+/// All symbols of the original interner remain valid for the perfect interner.
+/// Symbols of removed strings keep their gaps and do not resolve.
+///
+/// # Note
+///
+/// Building the perfect hash function takes expected linear time in the number of strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerfectInterner<S>
+where
+    S: Symbol,
+{
+    hash: PerfectHash,
+    strings: PackedStrings,
+    mark: marker::PhantomData<S>,
+}
+
+/// A minimal perfect hash function mapping every interned string to a distinct slot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PerfectHash {
+    seed: u64,
+    /// The displacement of every bucket.
+    pilots: Box<[u32]>,
+    /// The index of the string of every slot.
+    slots: Box<[usize]>,
+}
+
+/// Returns the hash of the given string for the given seed.
+#[inline]
+fn hash_with_seed(seed: u64, val: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    seed.hash(&mut hasher);
+    val.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns the bucket of the given hash.
+#[inline]
+fn bucket_of(hash: u64, buckets: usize) -> usize {
+    ((hash >> 32) as usize) % buckets
+}
+
+/// Returns the slot of the given hash displaced by the given pilot.
+#[inline]
+fn slot_of(hash: u64, pilot: u32, len: usize) -> usize {
+    let displaced = hash ^ (pilot as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    (displaced
+        .rotate_left(17)
+        .wrapping_mul(0xBF58_476D_1CE4_E5B9)
+        % len as u64) as usize
+}
+
+/// Tries to find pilots for all buckets that map the hashes of the given pairs of
+/// symbol indices and strings for the given seed to distinct slots.
+fn build<'a, I>(seed: u64, strings: I) -> Option<PerfectHash>
+where
+    I: Iterator<Item = (usize, &'a str)>,
+{
+    let (indices, hashes): (Vec<_>, Vec<_>) = strings
+        .map(|(index, s)| (index, hash_with_seed(seed, s)))
+        .unzip();
+    let len = hashes.len();
+    let buckets = std::cmp::max(1, len / BUCKET_SIZE);
+    let mut members = vec![Vec::new(); buckets];
+    for (index, &hash) in hashes.iter().enumerate() {
+        members[bucket_of(hash, buckets)].push(index);
+    }
+    let mut order = (0..buckets).collect::<Vec<_>>();
+    order.sort_by_key(|&bucket| std::cmp::Reverse(members[bucket].len()));
+    let mut pilots = vec![0; buckets];
+    let mut slots = vec![usize::MAX; len];
+    let mut taken = Vec::new();
+    let max_pilot = u32::try_from(len.saturating_mul(16))
+        .unwrap_or(u32::MAX)
+        .max(MIN_PILOTS);
+    for bucket in order {
+        let members = &members[bucket];
+        if members.is_empty() {
+            break;
+        }
+        let pilot = (0..max_pilot).find(|&pilot| {
+            taken.clear();
+            members.iter().all(|&index| {
+                let slot = slot_of(hashes[index], pilot, len);
+                let free = slots[slot] == usize::MAX && !taken.contains(&slot);
+                taken.push(slot);
+                free
+            })
+        })?;
+        pilots[bucket] = pilot;
+        for &index in members {
+            slots[slot_of(hashes[index], pilot, len)] = index;
+        }
+    }
+    for slot in &mut slots {
+        *slot = indices[*slot];
+    }
+    Some(PerfectHash {
+        seed,
+        pilots: pilots.into_boxed_slice(),
+        slots: slots.into_boxed_slice(),
+    })
+}
+
+impl<S, B, H> StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Converts the interner into a read-only `PerfectInterner`.
+    ///
+    /// All symbols of this interner remain valid for the perfect interner.
+    ///
+    /// Symbols of removed strings do not resolve for the perfect interner either.
+    pub fn freeze_perfect(self) -> PerfectInterner<S> {
+        let strings = PackedStrings::new(self.slots(), self.iter_values().map(str::len).sum());
+        let hash = (0..)
+            .find_map(|seed| build(seed, strings.iter()))
+            .expect("a perfect hash function exists for unique strings");
+        PerfectInterner {
+            hash,
+            strings,
+            mark: marker::PhantomData,
+        }
+    }
+}
+
+impl<S> PerfectInterner<S>
+where
+    S: Symbol,
+{
+    /// Returns the symbol associated with the given string for this interner
+    /// if existent, otherwise returns `None`.
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        let val = val.as_ref();
+        if self.hash.slots.is_empty() {
+            return None;
+        }
+        let PerfectHash {
+            seed,
+            pilots,
+            slots,
+        } = &self.hash;
+        let hash = hash_with_seed(*seed, val);
+        let pilot = pilots[bucket_of(hash, pilots.len())];
+        let index = slots[slot_of(hash, pilot, slots.len())];
+        match self.strings.get(index) {
+            Some(candidate) if candidate == val => Some(S::from_usize(index)),
+            _ => None,
+        }
+    }
+
+    /// Returns the string slice associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&str> {
+        self.strings.get(symbol.to_usize())
+    }
+
+    /// Returns the number of uniquely interned strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns true if the string interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
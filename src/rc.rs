@@ -0,0 +1,302 @@
+use crate::{DefaultHashBuilder, Symbol};
+
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt,
+    hash::{BuildHasher, Hash, Hasher},
+    ops,
+    rc::Rc,
+};
+
+/// A single-threaded string interner that reclaims strings that are no longer referenced.
+///
+/// Interning returns an `RcSym` handle instead of a plain symbol. Every handle holds a
+/// reference to its string and once the last handle of a string is dropped the string
+/// is removed from the interner and its symbol is handed out again for new strings.
+///
+/// # Note
+///
+/// Plain symbols obtained via `RcSym::symbol` are only valid while a handle
+/// of their string is alive.
+pub struct RcInterner<S, H = DefaultHashBuilder>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    inner: RefCell<RcStorage<S, H>>,
+}
+
+/// The reference counted storage of an `RcInterner`.
+#[derive(Debug)]
+struct RcStorage<S, H> {
+    map: HashMap<Rc<str>, S, H>,
+    slots: Vec<Option<RcSlot>>,
+    /// The indices of the released slots that are reused first.
+    free: Vec<usize>,
+}
+
+/// A string of an `RcInterner` together with the number of its live handles.
+#[derive(Debug)]
+struct RcSlot {
+    string: Rc<str>,
+    count: usize,
+}
+
+impl<S, H> fmt::Debug for RcInterner<S, H>
+where
+    S: Symbol + fmt::Debug,
+    H: BuildHasher,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let inner = self.inner.borrow();
+        f.debug_struct("RcInterner")
+            .field("slots", &inner.slots)
+            .field("free", &inner.free)
+            .finish()
+    }
+}
+
+impl<S> Default for RcInterner<S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn default() -> Self {
+        RcInterner::new()
+    }
+}
+
+impl<S> RcInterner<S>
+where
+    S: Symbol,
+{
+    /// Creates a new empty `RcInterner`.
+    #[inline]
+    pub fn new() -> Self {
+        RcInterner::with_hasher(DefaultHashBuilder::default())
+    }
+}
+
+impl<S, H> RcInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `RcInterner` with the given hasher.
+    pub fn with_hasher(hash_builder: H) -> Self {
+        RcInterner {
+            inner: RefCell::new(RcStorage {
+                map: HashMap::with_hasher(hash_builder),
+                slots: Vec::new(),
+                free: Vec::new(),
+            }),
+        }
+    }
+
+    /// Interns the given value.
+    ///
+    /// Returns a handle that keeps the string interned while it is alive.
+    pub fn get_or_intern<T>(&self, val: T) -> RcSym<'_, S, H>
+    where
+        T: AsRef<str>,
+    {
+        if let Some(handle) = self.get(val.as_ref()) {
+            return handle;
+        }
+        let mut inner = self.inner.borrow_mut();
+        let string: Rc<str> = Rc::from(val.as_ref());
+        let slot = Some(RcSlot {
+            string: Rc::clone(&string),
+            count: 1,
+        });
+        let index = match inner.free.pop() {
+            Some(index) => {
+                inner.slots[index] = slot;
+                index
+            }
+            None => {
+                inner.slots.push(slot);
+                inner.slots.len() - 1
+            }
+        };
+        let symbol = S::from_usize(index);
+        inner.map.insert(string, symbol);
+        RcSym {
+            interner: self,
+            symbol,
+        }
+    }
+
+    /// Returns a new handle of the given string if it is interned, otherwise returns `None`.
+    pub fn get<T>(&self, val: T) -> Option<RcSym<'_, S, H>>
+    where
+        T: AsRef<str>,
+    {
+        let symbol = self.inner.borrow().map.get(val.as_ref()).cloned()?;
+        Some(self.acquire(symbol))
+    }
+
+    /// Returns the number of live strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.borrow().map.len()
+    }
+
+    /// Returns true if the string interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of live handles of the string associated with the given symbol.
+    #[inline]
+    pub fn ref_count(&self, symbol: S) -> usize {
+        match self.inner.borrow().slots.get(symbol.to_usize()) {
+            Some(Some(slot)) => slot.count,
+            _ => 0,
+        }
+    }
+
+    /// Creates a new handle for the given live symbol.
+    fn acquire(&self, symbol: S) -> RcSym<'_, S, H> {
+        let mut inner = self.inner.borrow_mut();
+        let slot = inner.slots[symbol.to_usize()]
+            .as_mut()
+            .expect("handles only exist for live symbols");
+        slot.count += 1;
+        RcSym {
+            interner: self,
+            symbol,
+        }
+    }
+
+    /// Releases a handle of the given symbol and reclaims its string if it was the last one.
+    fn release(&self, symbol: S) {
+        let mut inner = self.inner.borrow_mut();
+        let index = symbol.to_usize();
+        let slot = inner.slots[index]
+            .as_mut()
+            .expect("handles only exist for live symbols");
+        slot.count -= 1;
+        if slot.count == 0 {
+            let slot = inner.slots[index].take().expect("slot is live");
+            inner.map.remove(&slot.string);
+            inner.free.push(index);
+        }
+    }
+}
+
+/// A handle of a string interned by an `RcInterner`.
+///
+/// The string stays interned for as long as at least one of its handles is alive.
+/// Handles dereference to their string.
+pub struct RcSym<'a, S, H = DefaultHashBuilder>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    interner: &'a RcInterner<S, H>,
+    symbol: S,
+}
+
+impl<'a, S, H> RcSym<'a, S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Returns the symbol of this handle.
+    #[inline]
+    pub fn symbol(&self) -> S {
+        self.symbol
+    }
+
+    /// Returns the string of this handle.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        let inner = self.interner.inner.borrow();
+        let slot = inner.slots[self.symbol.to_usize()]
+            .as_ref()
+            .expect("handles only exist for live symbols");
+        // This is safe since the string is reference counted and not dropped
+        // or mutated while this handle is alive.
+        unsafe { &*(&*slot.string as *const str) }
+    }
+}
+
+impl<'a, S, H> Clone for RcSym<'a, S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        self.interner.acquire(self.symbol)
+    }
+}
+
+impl<'a, S, H> Drop for RcSym<'a, S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    #[inline]
+    fn drop(&mut self) {
+        self.interner.release(self.symbol)
+    }
+}
+
+impl<'a, S, H> ops::Deref for RcSym<'a, S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a, S, H> PartialEq for RcSym<'a, S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        std::ptr::eq(self.interner, rhs.interner) && self.symbol == rhs.symbol
+    }
+}
+
+impl<'a, S, H> Eq for RcSym<'a, S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+}
+
+impl<'a, S, H> Hash for RcSym<'a, S, H>
+where
+    S: Symbol + Hash,
+    H: BuildHasher,
+{
+    #[inline]
+    fn hash<T: Hasher>(&self, state: &mut T) {
+        self.symbol.hash(state)
+    }
+}
+
+impl<'a, S, H> fmt::Debug for RcSym<'a, S, H>
+where
+    S: Symbol + fmt::Debug,
+    H: BuildHasher,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("RcSym")
+            .field(&self.symbol)
+            .field(&self.as_str())
+            .finish()
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{backend::Backend, BulkOperation, StringInterner, Symbol};
+
+use std::{hash::BuildHasher, mem};
+
+/// A mapping from old to new symbols returned by operations that renumber symbols.
+///
+/// Callers use it to patch symbols stored in their own data structures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolRemap<S> {
+    map: Vec<Option<S>>,
+}
+
+impl<S> SymbolRemap<S>
+where
+    S: Symbol,
+{
+    /// Returns the new symbol of the given old symbol if it is still associated with a string,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn get(&self, old: S) -> Option<S> {
+        self.map.get(old.to_usize()).cloned().flatten()
+    }
+
+    /// Returns the number of old symbols covered by this remap.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if the remap covers no old symbols.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the pairs of old and new symbols.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (S, S)> + '_ {
+        self.map
+            .iter()
+            .enumerate()
+            .filter_map(|(index, &new)| Some((S::from_usize(index), new?)))
+    }
+}
+
+impl<S, B, H> StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Renumbers the symbols of all remaining strings densely in their current order.
+    ///
+    /// This closes the holes left behind by removed strings.
+    /// Returns a mapping from the old to the new symbols.
+    pub fn compact(&mut self) -> SymbolRemap<S> {
+        let _span = self.span(BulkOperation::Compact);
+        let len = self.len();
+        let backend = mem::replace(&mut self.backend, B::with_capacity(len));
+        let mut preloaded = 0;
+        let map = backend
+            .into_strings()
+            .enumerate()
+            .map(|(index, string)| {
+                let new = self.backend.intern_string(string?);
+                if index < self.preloaded {
+                    preloaded += 1;
+                }
+                Some(new)
+            })
+            .collect::<Vec<_>>();
+        let remap = SymbolRemap { map };
+        for sym in self.map.values_mut() {
+            *sym = remap.get(*sym).expect("mapped symbols are never removed");
+        }
+        for sym in self.collisions.values_mut().flatten() {
+            *sym = remap.get(*sym).expect("mapped symbols are never removed");
+        }
+        for syms in self
+            .ascii_folded
+            .iter_mut()
+            .flat_map(|index| index.values_mut())
+        {
+            syms.retain_mut(|sym| match remap.get(*sym) {
+                Some(new) => {
+                    *sym = new;
+                    true
+                }
+                None => false,
+            });
+        }
+        self.folded.clear();
+        self.preloaded = preloaded;
+        self.removed = 0;
+        remap
+    }
+
+    /// Interns all strings of `other` into this interner.
+    ///
+    /// Returns a mapping from the symbols of `other` to their symbols in this interner.
+    pub fn merge<B2, H2>(&mut self, other: &StringInterner<S, B2, H2>) -> SymbolRemap<S>
+    where
+        B2: Backend<S>,
+        H2: BuildHasher,
+    {
+        let _span = self.span(BulkOperation::Merge);
+        self.reserve(other.len());
+        let map = other
+            .slots()
+            .map(|string| string.map(|string| self.get_or_intern(string)))
+            .collect();
+        SymbolRemap { map }
+    }
+}
+
+impl<S, B, H> StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher + Clone,
+{
+    /// Moves all strings whose symbols have an index of at least the index of `at`
+    /// into a new interner, similar to `Vec::split_off`.
+    ///
+    /// The moved strings are associated with dense symbols starting at index zero
+    /// within the returned interner. Returns the new interner together with a mapping
+    /// from the moved symbols of this interner to their symbols in the new interner.
+    pub fn split_off(&mut self, at: S) -> (Self, SymbolRemap<S>) {
+        let at = at.to_usize();
+        let total = self.backend.len();
+        let mut other = StringInterner::with_capacity_and_hasher(
+            total.saturating_sub(at),
+            self.hash_builder.clone(),
+        );
+        let mut map = vec![None; total];
+        for (index, new) in map.iter_mut().enumerate().skip(at) {
+            if let Some(string) = self.remove(S::from_usize(index)) {
+                *new = Some(other.get_or_intern_owned(string));
+            }
+        }
+        self.truncate(at);
+        (other, SymbolRemap { map })
+    }
+}
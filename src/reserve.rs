@@ -0,0 +1,116 @@
+use crate::{backend::Backend, InternerFull, ReserveError, StringInterner, Symbol};
+
+use std::{collections::HashSet, hash::BuildHasher, sync::OnceLock};
+
+/// A block of consecutive symbols reserved by `StringInterner::reserve_symbols`.
+///
+/// The slots of the block can be filled concurrently through shared references,
+/// for example by scoped worker threads. Once all slots are filled `commit` interns
+/// their strings so that every string is associated with the symbol of its slot.
+///
+/// # Note
+///
+/// The block borrows the interner mutably, so no other strings can be interned
+/// in between and the reserved symbols are guaranteed to be assigned as announced.
+/// Dropping the block without committing it leaves the interner unchanged.
+#[derive(Debug)]
+pub struct ReservedSymbols<'a, S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    interner: &'a mut StringInterner<S, B, H>,
+    start: usize,
+    slots: Box<[OnceLock<String>]>,
+}
+
+impl<S, B, H> StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Reserves `n` consecutive symbols for strings that are filled in later.
+    ///
+    /// Returns an error if the symbol type cannot represent all reserved symbols.
+    pub fn reserve_symbols(
+        &mut self,
+        n: usize,
+    ) -> Result<ReservedSymbols<'_, S, B, H>, InternerFull> {
+        let start = self.backend.len();
+        if n != 0 && S::try_from_usize(start + n - 1).is_none() {
+            return Err(InternerFull);
+        }
+        self.reserve(n);
+        Ok(ReservedSymbols {
+            interner: self,
+            start,
+            slots: (0..n).map(|_| OnceLock::new()).collect(),
+        })
+    }
+}
+
+impl<'a, S, B, H> ReservedSymbols<'a, S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Returns the number of reserved symbols.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns true if no symbols have been reserved.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the symbol reserved for the slot at the given offset if within the block,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn symbol(&self, offset: usize) -> Option<S> {
+        if offset >= self.len() {
+            return None;
+        }
+        Some(S::from_usize(self.start + offset))
+    }
+
+    /// Fills the slot at the given offset with the given string.
+    ///
+    /// Returns the symbol the string is going to be associated with.
+    pub fn fill<T>(&self, offset: usize, val: T) -> Result<S, ReserveError>
+    where
+        T: Into<String>,
+    {
+        let symbol = self
+            .symbol(offset)
+            .ok_or(ReserveError::OutOfRange { offset })?;
+        self.slots[offset]
+            .set(val.into())
+            .map_err(|_| ReserveError::AlreadyFilled { offset })?;
+        Ok(symbol)
+    }
+
+    /// Interns the strings of all slots with their reserved symbols.
+    ///
+    /// Returns an error and leaves the interner unchanged if any slot is unfilled
+    /// or if any string is not unique.
+    pub fn commit(self) -> Result<(), ReserveError> {
+        let mut seen = HashSet::with_capacity(self.len());
+        for (offset, slot) in self.slots.iter().enumerate() {
+            let val = slot.get().ok_or(ReserveError::Unfilled { offset })?;
+            if !seen.insert(val.as_str()) || self.interner.contains(val) {
+                return Err(ReserveError::Duplicate { offset });
+            }
+        }
+        for slot in self.slots.into_vec() {
+            let val = slot.into_inner().unwrap_or_default();
+            self.interner.get_or_intern_owned(val);
+        }
+        Ok(())
+    }
+}
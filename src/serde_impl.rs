@@ -4,32 +4,53 @@ use std::default::Default;
 
 use std::fmt;
 
-use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
-use serde::ser::{Serialize, SerializeSeq, Serializer};
+use serde::de::{self, Deserialize, DeserializeSeed, Deserializer, SeqAccess, Visitor};
+use serde::ser::{self, Serialize, SerializeSeq, Serializer};
 
-impl<Sym, H> Serialize for StringInterner<Sym, H>
+impl<Sym, B, H> Serialize for StringInterner<Sym, B, H>
 where
     Sym: Symbol,
+    B: Backend<Sym>,
     H: BuildHasher,
 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut seq = serializer.serialize_seq(Some(self.len()))?;
-        for s in self.iter_values() {
-            seq.serialize_element(s)?
+        let _span = self.span(BulkOperation::Serialize);
+        // Removed strings are serialized as `None` so that all symbols keep their index.
+        let mut seq = serializer.serialize_seq(Some(self.backend.len()))?;
+        for s in self.slots() {
+            seq.serialize_element(&s)?
         }
         seq.end()
     }
 }
 
-impl<'de, Sym, H> Deserialize<'de> for StringInterner<Sym, H>
+impl<Sym, B, H> StringInterner<Sym, B, H>
 where
     Sym: Symbol,
+    B: Backend<Sym>,
+    H: BuildHasher,
+{
+    /// Hands out the next symbol for a string that is removed right away.
+    ///
+    /// Restores the tombstone of a string that had been removed before serialization.
+    fn push_removed(&mut self) -> Sym {
+        let symbol = self.backend.intern("");
+        self.backend.remove(symbol);
+        self.removed += 1;
+        symbol
+    }
+}
+
+impl<'de, Sym, B, H> Deserialize<'de> for StringInterner<Sym, B, H>
+where
+    Sym: Symbol,
+    B: Backend<Sym>,
     H: BuildHasher + Default,
 {
-    fn deserialize<D>(deserializer: D) -> Result<StringInterner<Sym, H>, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<StringInterner<Sym, B, H>, D::Error>
     where
         D: Deserializer<'de>,
     {
@@ -37,17 +58,19 @@ where
     }
 }
 
-struct StringInternerVisitor<Sym, H>
+struct StringInternerVisitor<Sym, B, H>
 where
     Sym: Symbol,
+    B: Backend<Sym>,
     H: BuildHasher,
 {
-    mark: marker::PhantomData<(Sym, H)>,
+    mark: marker::PhantomData<(Sym, B, H)>,
 }
 
-impl<Sym, H> Default for StringInternerVisitor<Sym, H>
+impl<Sym, B, H> Default for StringInternerVisitor<Sym, B, H>
 where
     Sym: Symbol,
+    B: Backend<Sym>,
     H: BuildHasher,
 {
     fn default() -> Self {
@@ -57,26 +80,152 @@ where
     }
 }
 
-impl<'de, Sym, H> Visitor<'de> for StringInternerVisitor<Sym, H>
+impl<'de, Sym, B, H> Visitor<'de> for StringInternerVisitor<Sym, B, H>
 where
     Sym: Symbol,
+    B: Backend<Sym>,
     H: BuildHasher + Default,
 {
-    type Value = StringInterner<Sym, H>;
+    type Value = StringInterner<Sym, B, H>;
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str("Expected a contiguous sequence of strings.")
+        formatter.write_str("Expected a contiguous sequence of optional strings.")
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
-        let mut interner: StringInterner<Sym, H> =
+        let mut interner: StringInterner<Sym, B, H> =
             StringInterner::with_capacity_and_hasher(seq.size_hint().unwrap_or(0), H::default());
-        while let Some(s) = seq.next_element::<Box<str>>()? {
-            interner.get_or_intern(s);
+        while let Some(s) = seq.next_element::<Option<String>>()? {
+            match s {
+                Some(s) => interner.get_or_intern_owned(s),
+                None => interner.push_removed(),
+            };
         }
         Ok(interner)
     }
 }
+
+/// Serializes a symbol as its associated string within the given interner.
+///
+/// Use this to emit self-describing output for data structures holding symbols.
+/// The matching deserialization is provided by `SymbolSeed`.
+///
+/// # Errors
+///
+/// Serialization fails if the symbol has no associated string in the interner.
+#[derive(Debug)]
+pub struct SymbolWithInterner<'a, Sym, B = DefaultBackend<Sym>, H = DefaultHashBuilder>
+where
+    Sym: Symbol,
+    B: Backend<Sym>,
+    H: BuildHasher,
+{
+    symbol: Sym,
+    interner: &'a StringInterner<Sym, B, H>,
+}
+
+impl<'a, Sym, B, H> SymbolWithInterner<'a, Sym, B, H>
+where
+    Sym: Symbol,
+    B: Backend<Sym>,
+    H: BuildHasher,
+{
+    /// Creates a serializable view of the given symbol resolved by the given interner.
+    #[inline]
+    pub fn new(symbol: Sym, interner: &'a StringInterner<Sym, B, H>) -> Self {
+        SymbolWithInterner { symbol, interner }
+    }
+}
+
+impl<'a, Sym, B, H> Serialize for SymbolWithInterner<'a, Sym, B, H>
+where
+    Sym: Symbol,
+    B: Backend<Sym>,
+    H: BuildHasher,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.interner.resolve(self.symbol) {
+            Some(s) => serializer.serialize_str(s),
+            None => Err(ser::Error::custom(format!(
+                "symbol with index {} has no associated string in the interner",
+                self.symbol.to_usize()
+            ))),
+        }
+    }
+}
+
+/// Deserializes a string and interns it into the given interner.
+///
+/// This is a `DeserializeSeed` that yields the symbol of the deserialized string
+/// and is the counterpart of `SymbolWithInterner`.
+#[derive(Debug)]
+pub struct SymbolSeed<'a, Sym, B = DefaultBackend<Sym>, H = DefaultHashBuilder>
+where
+    Sym: Symbol,
+    B: Backend<Sym>,
+    H: BuildHasher,
+{
+    interner: &'a mut StringInterner<Sym, B, H>,
+}
+
+impl<'a, Sym, B, H> SymbolSeed<'a, Sym, B, H>
+where
+    Sym: Symbol,
+    B: Backend<Sym>,
+    H: BuildHasher,
+{
+    /// Creates a seed that interns deserialized strings into the given interner.
+    #[inline]
+    pub fn new(interner: &'a mut StringInterner<Sym, B, H>) -> Self {
+        SymbolSeed { interner }
+    }
+}
+
+impl<'a, 'de, Sym, B, H> DeserializeSeed<'de> for SymbolSeed<'a, Sym, B, H>
+where
+    Sym: Symbol,
+    B: Backend<Sym>,
+    H: BuildHasher,
+{
+    type Value = Sym;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'a, 'de, Sym, B, H> Visitor<'de> for SymbolSeed<'a, Sym, B, H>
+where
+    Sym: Symbol,
+    B: Backend<Sym>,
+    H: BuildHasher,
+{
+    type Value = Sym;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("Expected a string.")
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(self.interner.get_or_intern(s))
+    }
+
+    fn visit_string<E>(self, s: String) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(self.interner.get_or_intern_owned(s))
+    }
+}
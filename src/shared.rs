@@ -0,0 +1,173 @@
+use crate::{DefaultHashBuilder, Symbol};
+
+use std::{collections::HashMap, hash::BuildHasher, sync::Arc};
+
+/// A copy-on-write string interner whose clones share their string storage.
+///
+/// Cloning a `SharedStringInterner` is `O(1)` since it only increments a reference count.
+/// The interned strings are stored behind `Arc<str>` so that clones keep sharing
+/// the strings they have in common even after they diverge.
+///
+/// # Sharing semantics
+///
+/// - Clones share all of their internal state until one of them interns a new string.
+/// - Upon interning a new string a clone splits off its own symbol table. This copies
+///   the internal indices but not the strings themselves, so all strings interned before
+///   the split stay shared between the clones.
+/// - Look-ups and resolution never split the storage.
+///
+/// # Thread-safety
+///
+/// The shared storage is reference counted atomically and never mutated in place.
+/// Therefore a `SharedStringInterner` is `Send` and `Sync` if its symbol type and hasher are,
+/// and clones can be handed over to other threads freely.
+#[derive(Debug)]
+pub struct SharedStringInterner<S, H = DefaultHashBuilder>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    inner: Arc<SharedStorage<S, H>>,
+}
+
+/// The actual storage of a `SharedStringInterner` that is shared between clones.
+#[derive(Debug, Clone)]
+struct SharedStorage<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    map: HashMap<Arc<str>, S, H>,
+    values: Vec<Arc<str>>,
+}
+
+impl<S, H> Clone for SharedStringInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        SharedStringInterner {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<S, H> PartialEq for SharedStringInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    fn eq(&self, rhs: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &rhs.inner) || self.inner.values == rhs.inner.values
+    }
+}
+
+impl<S, H> Eq for SharedStringInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+}
+
+impl<S> Default for SharedStringInterner<S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn default() -> Self {
+        SharedStringInterner::new()
+    }
+}
+
+impl<S> SharedStringInterner<S>
+where
+    S: Symbol,
+{
+    /// Creates a new empty `SharedStringInterner`.
+    #[inline]
+    pub fn new() -> Self {
+        SharedStringInterner::with_hasher(DefaultHashBuilder::default())
+    }
+}
+
+impl<S, H> SharedStringInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `SharedStringInterner` with the given hasher.
+    #[inline]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        SharedStringInterner {
+            inner: Arc::new(SharedStorage {
+                map: HashMap::with_hasher(hash_builder),
+                values: Vec::new(),
+            }),
+        }
+    }
+
+    /// Returns the symbol associated with the given string for this interner
+    /// if existent, otherwise returns `None`.
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        self.inner.map.get(val.as_ref()).cloned()
+    }
+
+    /// Returns the string slice associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&str> {
+        self.inner.values.get(symbol.to_usize()).map(|s| s.as_ref())
+    }
+
+    /// Returns the number of uniquely interned strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.values.len()
+    }
+
+    /// Returns true if the string interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if `self` and `other` still share all of their storage.
+    #[inline]
+    pub fn is_shared_with(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl<S, H> SharedStringInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Clone,
+{
+    /// Interns the given value.
+    ///
+    /// Returns a symbol to access it within this interner.
+    ///
+    /// If the value has not yet been interned and the storage of this interner
+    /// is shared with a clone the symbol table is split off first.
+    /// Strings interned before the split remain shared.
+    pub fn get_or_intern<T>(&mut self, val: T) -> S
+    where
+        T: AsRef<str>,
+    {
+        if let Some(sym) = self.get(val.as_ref()) {
+            return sym;
+        }
+        let storage = Arc::make_mut(&mut self.inner);
+        let sym = S::from_usize(storage.values.len());
+        let new_val: Arc<str> = Arc::from(val.as_ref());
+        storage.values.push(Arc::clone(&new_val));
+        storage.map.insert(new_val, sym);
+        sym
+    }
+}
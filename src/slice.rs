@@ -0,0 +1,106 @@
+use crate::{key_of, HashToSymbol, Symbol};
+
+use std::{
+    collections::hash_map::Entry,
+    hash::{BuildHasher, Hash},
+};
+
+/// The storage shared by the interners of slices other than `str`.
+///
+/// All slices are stored contiguously within a single buffer.
+#[derive(Debug, Clone)]
+pub(crate) struct SliceInterner<T, S, H> {
+    hash_builder: H,
+    /// Maps the hash of every interned slice to its symbol, see `StringInterner`.
+    map: HashToSymbol<S>,
+    collisions: HashToSymbol<Vec<S>>,
+    buffer: Vec<T>,
+    ends: Vec<usize>,
+}
+
+impl<T, S, H> SliceInterner<T, S, H>
+where
+    T: Copy + Eq + Hash,
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `SliceInterner` with the given hasher.
+    #[inline]
+    pub(crate) fn with_hasher(hash_builder: H) -> Self {
+        SliceInterner {
+            hash_builder,
+            map: HashToSymbol::default(),
+            collisions: HashToSymbol::default(),
+            buffer: Vec::new(),
+            ends: Vec::new(),
+        }
+    }
+
+    /// Interns the given slice and returns its symbol.
+    pub(crate) fn get_or_intern(&mut self, val: &[T]) -> S {
+        let hash = self.hash_builder.hash_one(val);
+        if let Some(sym) = self.lookup(hash, val) {
+            return sym;
+        }
+        let sym = S::from_usize(self.ends.len());
+        self.buffer.extend_from_slice(val);
+        self.ends.push(self.buffer.len());
+        let key = key_of(hash);
+        match self.map.entry(key) {
+            Entry::Vacant(entry) => {
+                entry.insert(sym);
+            }
+            Entry::Occupied(_) => self.collisions.entry(key).or_default().push(sym),
+        }
+        sym
+    }
+
+    /// Returns the symbol of the given slice if it has been interned.
+    #[inline]
+    pub(crate) fn get(&self, val: &[T]) -> Option<S> {
+        self.lookup(self.hash_builder.hash_one(val), val)
+    }
+
+    /// Returns the symbol of the given slice with the given hash if existent.
+    fn lookup(&self, hash: u64, val: &[T]) -> Option<S> {
+        let is_match = |sym: S| self.resolve(sym) == Some(val);
+        let key = key_of(hash);
+        let sym = *self.map.get(&key)?;
+        if is_match(sym) {
+            return Some(sym);
+        }
+        self.collisions
+            .get(&key)?
+            .iter()
+            .cloned()
+            .find(|&sym| is_match(sym))
+    }
+
+    /// Returns the slice associated with the given symbol if available.
+    #[inline]
+    pub(crate) fn resolve(&self, symbol: S) -> Option<&[T]> {
+        let index = symbol.to_usize();
+        let end = *self.ends.get(index)?;
+        let start = match index {
+            0 => 0,
+            n => self.ends[n - 1],
+        };
+        Some(&self.buffer[start..end])
+    }
+
+    /// Returns the number of interned slices.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns an iterator over the symbols and slices in insertion order.
+    pub(crate) fn iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (S, &[T])> + ExactSizeIterator + '_ {
+        (0..self.len()).map(move |index| {
+            let sym = S::from_usize(index);
+            (sym, self.resolve(sym).unwrap_or(&[]))
+        })
+    }
+}
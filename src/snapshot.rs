@@ -0,0 +1,67 @@
+use crate::{backend::Backend, packed::PackedStrings, StringInterner, Symbol};
+
+use std::{hash::BuildHasher, marker, sync::Arc};
+
+/// An immutable view of the strings of a `StringInterner` at some point in time.
+///
+/// Snapshots are created by `StringInterner::snapshot` and are meant to be shared
+/// with other threads via the returned `Arc` in order to resolve symbols while the
+/// original interner keeps on interning new strings.
+///
+/// # Note
+///
+/// A snapshot only supports resolution of symbols. It does not contain a hash map
+/// and stores all strings contiguously within a single buffer.
+/// Symbols created after the snapshot was taken or whose strings have been removed
+/// before it was taken do not resolve for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InternerSnapshot<S>
+where
+    S: Symbol,
+{
+    strings: PackedStrings,
+    mark: marker::PhantomData<S>,
+}
+
+impl<S, B, H> StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Returns an immutable and cheaply cloneable snapshot of the interned strings.
+    ///
+    /// Creating the snapshot copies all strings once into a single buffer.
+    /// Afterwards the snapshot can be shared between threads without further copies.
+    pub fn snapshot(&self) -> Arc<InternerSnapshot<S>> {
+        let bytes = self.iter_values().map(str::len).sum();
+        Arc::new(InternerSnapshot {
+            strings: PackedStrings::new(self.slots(), bytes),
+            mark: marker::PhantomData,
+        })
+    }
+}
+
+impl<S> InternerSnapshot<S>
+where
+    S: Symbol,
+{
+    /// Returns the string slice associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&str> {
+        self.strings.get(symbol.to_usize())
+    }
+
+    /// Returns the number of strings within this snapshot.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns true if the snapshot holds no strings.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
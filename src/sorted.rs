@@ -0,0 +1,188 @@
+use crate::{
+    backend::{Backend, DefaultBackend},
+    DefaultHashBuilder, StringInterner, Symbol,
+};
+
+use std::{cmp::Ordering, hash::BuildHasher};
+
+/// A string interner that maintains the lexicographic order of its strings.
+///
+/// Every symbol has a rank, its position among all interned strings in string order.
+/// Comparing and sorting symbols by rank never resolves their strings, see `sort_symbols`.
+///
+/// # Note
+///
+/// Symbols are still handed out in insertion order, only their ranks follow string order.
+/// Ranks of existing symbols shift when a smaller string is interned later.
+/// Interning a new string costs linear time to update the ranks, use `extend`
+/// to intern many strings at once with a single sort.
+#[derive(Debug, Clone)]
+pub struct SortedStringInterner<S, B = DefaultBackend<S>, H = DefaultHashBuilder>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    inner: StringInterner<S, B, H>,
+    /// All symbols in the order of their strings.
+    sorted: Vec<S>,
+    /// The rank of every symbol indexed by symbol.
+    ranks: Vec<usize>,
+}
+
+impl<S, B> Default for SortedStringInterner<S, B>
+where
+    S: Symbol,
+    B: Backend<S>,
+{
+    #[inline]
+    fn default() -> Self {
+        SortedStringInterner::new()
+    }
+}
+
+impl<S, B> SortedStringInterner<S, B>
+where
+    S: Symbol,
+    B: Backend<S>,
+{
+    /// Creates a new empty `SortedStringInterner`.
+    #[inline]
+    pub fn new() -> Self {
+        SortedStringInterner::with_hasher(DefaultHashBuilder::default())
+    }
+}
+
+impl<S, B, H> SortedStringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Creates a new empty `SortedStringInterner` with the given hasher.
+    #[inline]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        SortedStringInterner {
+            inner: StringInterner::with_hasher(hash_builder),
+            sorted: Vec::new(),
+            ranks: Vec::new(),
+        }
+    }
+
+    /// Interns the given value.
+    ///
+    /// Returns a symbol to access it within this interner.
+    pub fn get_or_intern<T>(&mut self, val: T) -> S
+    where
+        T: AsRef<str>,
+    {
+        let len = self.inner.len();
+        let symbol = self.inner.get_or_intern(val);
+        if self.inner.len() != len {
+            let inner = &self.inner;
+            let new_val = inner.resolve(symbol);
+            let rank = self
+                .sorted
+                .partition_point(|&sym| inner.resolve(sym) < new_val);
+            self.sorted.insert(rank, symbol);
+            for sym in &self.sorted[rank + 1..] {
+                self.ranks[sym.to_usize()] += 1;
+            }
+            self.ranks.push(rank);
+        }
+        symbol
+    }
+
+    /// Interns all values of the given iterator and restores the order once.
+    pub fn extend<I, T>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        for val in iter {
+            self.inner.get_or_intern(val);
+        }
+        let inner = &self.inner;
+        self.sorted = (0..inner.len()).map(S::from_usize).collect();
+        self.sorted.sort_unstable_by_key(|&sym| inner.resolve(sym));
+        self.ranks.resize(inner.len(), 0);
+        for (rank, sym) in self.sorted.iter().enumerate() {
+            self.ranks[sym.to_usize()] = rank;
+        }
+    }
+
+    /// Returns the symbol associated with the given string for this interner
+    /// if existent, otherwise returns `None`.
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        self.inner.get(val)
+    }
+
+    /// Returns the string slice associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&str> {
+        self.inner.resolve(symbol)
+    }
+
+    /// Returns the position of the string of the given symbol among all interned
+    /// strings in lexicographic order if available, otherwise returns `None`.
+    #[inline]
+    pub fn rank(&self, symbol: S) -> Option<usize> {
+        self.ranks.get(symbol.to_usize()).cloned()
+    }
+
+    /// Compares the strings of the given symbols without resolving them.
+    ///
+    /// # Panics
+    ///
+    /// If any of the given symbols has no associated string.
+    #[inline]
+    pub fn cmp_symbols(&self, a: S, b: S) -> Ordering {
+        self.ranks[a.to_usize()].cmp(&self.ranks[b.to_usize()])
+    }
+
+    /// Sorts the given symbols by their strings without resolving them.
+    ///
+    /// # Panics
+    ///
+    /// If any of the given symbols has no associated string.
+    #[inline]
+    pub fn sort_symbols(&self, symbols: &mut [S]) {
+        symbols.sort_unstable_by_key(|sym| self.ranks[sym.to_usize()])
+    }
+
+    /// Returns an iterator over the symbols and strings in lexicographic order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (S, &str)> + ExactSizeIterator + '_ {
+        self.sorted
+            .iter()
+            .map(move |&sym| (sym, self.inner.resolve(sym).unwrap_or("")))
+    }
+
+    /// Returns the number of uniquely interned strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the string interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the underlying `StringInterner`.
+    #[inline]
+    pub fn as_interner(&self) -> &StringInterner<S, B, H> {
+        &self.inner
+    }
+
+    /// Converts this interner into its underlying `StringInterner` and discards the ranks.
+    #[inline]
+    pub fn into_inner(self) -> StringInterner<S, B, H> {
+        self.inner
+    }
+}
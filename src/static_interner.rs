@@ -0,0 +1,109 @@
+use crate::Symbol;
+
+use std::{fmt, iter, marker, slice};
+
+/// A read-only string interner for a fixed set of strings embedded in the binary.
+///
+/// Usually defined by the `symbols!` macro which generates a `match` based look-up
+/// that the compiler turns into a static decision tree.
+///
+/// # Note
+///
+/// A `StaticStringInterner` can be created in constant contexts, never allocates
+/// and can be stored in a `static`.
+pub struct StaticStringInterner<S> {
+    strings: &'static [&'static str],
+    index_of: fn(&str) -> Option<usize>,
+    mark: marker::PhantomData<fn() -> S>,
+}
+
+impl<S> fmt::Debug for StaticStringInterner<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StaticStringInterner")
+            .field("strings", &self.strings)
+            .finish()
+    }
+}
+
+impl<S> StaticStringInterner<S>
+where
+    S: Symbol,
+{
+    /// Creates a new `StaticStringInterner` from the given strings and look-up function.
+    ///
+    /// The look-up function must return the index of the given string within `strings`
+    /// if existent, otherwise `None`.
+    pub const fn new(
+        strings: &'static [&'static str],
+        index_of: fn(&str) -> Option<usize>,
+    ) -> Self {
+        StaticStringInterner {
+            strings,
+            index_of,
+            mark: marker::PhantomData,
+        }
+    }
+
+    /// Returns the symbol associated with the given string for this interner
+    /// if existent, otherwise returns `None`.
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        (self.index_of)(val.as_ref()).map(S::from_usize)
+    }
+
+    /// Returns the string slice associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&'static str> {
+        self.strings.get(symbol.to_usize()).cloned()
+    }
+
+    /// Returns the number of strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns true if the string interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Returns an iterator over the pairs of symbols and strings of this interner.
+    #[inline]
+    pub fn iter(&self) -> StaticIter<S> {
+        StaticIter {
+            iter: self.strings.iter().enumerate(),
+            mark: marker::PhantomData,
+        }
+    }
+}
+
+/// Iterator over the pairs of associated symbols and strings for a `StaticStringInterner`.
+pub struct StaticIter<S> {
+    iter: iter::Enumerate<slice::Iter<'static, &'static str>>,
+    mark: marker::PhantomData<fn() -> S>,
+}
+
+impl<S> Iterator for StaticIter<S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'static str);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(num, &string)| (S::from_usize(num), string))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{backend::Backend, StringInterner, Symbol};
+
+use std::hash::BuildHasher;
+
+/// A summary of the contents of a `StringInterner` as reported by `StringInterner::stats`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct InternerStats {
+    len: usize,
+    total_bytes: usize,
+    max_len: usize,
+    load_factor: f64,
+}
+
+impl InternerStats {
+    /// Returns the number of interned strings.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no strings are interned.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the total length in bytes of all interned strings.
+    #[inline]
+    pub fn total_bytes(&self) -> usize {
+        self.total_bytes
+    }
+
+    /// Returns the average length in bytes of the interned strings.
+    ///
+    /// Returns zero if no strings are interned.
+    #[inline]
+    pub fn average_len(&self) -> f64 {
+        match self.len {
+            0 => 0.0,
+            len => self.total_bytes as f64 / len as f64,
+        }
+    }
+
+    /// Returns the length in bytes of the longest interned string.
+    #[inline]
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Returns the ratio of occupied to available slots of the hash map for look-ups.
+    ///
+    /// Returns zero if the hash map has not allocated yet.
+    #[inline]
+    pub fn load_factor(&self) -> f64 {
+        self.load_factor
+    }
+}
+
+impl<S, B, H> StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Returns a summary of the contents of the interner.
+    ///
+    /// This iterates over all interned strings.
+    pub fn stats(&self) -> InternerStats {
+        let load_factor = match self.map.capacity() {
+            0 => 0.0,
+            capacity => self.map.len() as f64 / capacity as f64,
+        };
+        InternerStats {
+            len: self.len(),
+            total_bytes: self.total_bytes(),
+            max_len: self.iter_values().map(str::len).max().unwrap_or(0),
+            load_factor,
+        }
+    }
+}
@@ -1,4 +1,32 @@
-use crate::{DefaultStringInterner, InternalStrRef, StringInterner, Sym, Symbol};
+use crate::{
+    AppendOnlyStringInterner, ArenaStringInterner, ConcurrentStringInterner,
+    CountingStringInterner, DefaultStringInterner, FixedStringInterner, FxHasher, FxStringInterner,
+    InternerFull, LocalStringInterner, LruStringInterner, NonMaxSym, RcInterner,
+    SharedStringInterner, StaticStringInterner, StringArena, StringInterner, Sym, SymU16, SymU64,
+    Symbol,
+};
+
+/// A `BuildHasher` that maps all strings to the same hash.
+#[derive(Default, Clone)]
+struct CollidingState;
+
+impl std::hash::BuildHasher for CollidingState {
+    type Hasher = CollidingHasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        CollidingHasher
+    }
+}
+
+struct CollidingHasher;
+
+impl std::hash::Hasher for CollidingHasher {
+    fn finish(&self) -> u64 {
+        42
+    }
+
+    fn write(&mut self, _bytes: &[u8]) {}
+}
 
 mod sym {
     use super::*;
@@ -8,456 +36,4236 @@ mod sym {
         use std::mem;
         assert_eq!(mem::size_of::<Sym>(), mem::size_of::<Option<Sym>>());
     }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    #[should_panic]
+    fn from_usize_overflow() {
+        Sym::from_usize(u32::MAX as usize);
+    }
+
+    #[test]
+    fn from_usize_max() {
+        let max = u32::MAX as usize - 1;
+        assert_eq!(Sym::from_usize(max).to_usize(), max);
+    }
 }
 
-mod internal_str_ref {
+mod try_from_usize {
     use super::*;
+    use std::num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
 
     #[test]
-    fn size_of() {
-        use std::mem;
-        assert_eq!(mem::size_of::<InternalStrRef>(), mem::size_of::<&str>());
+    fn sym() {
+        assert_eq!(Sym::try_from_usize(0), Some(Sym::from_usize(0)));
+        assert_eq!(
+            Sym::try_from_usize(u32::MAX as usize - 1).map(Symbol::to_usize),
+            Some(u32::MAX as usize - 1)
+        );
+        assert_eq!(Sym::try_from_usize(u32::MAX as usize), None);
+        assert_eq!(Sym::try_from_usize(usize::MAX), None);
+    }
+
+    #[test]
+    fn integers() {
+        assert_eq!(u8::try_from_usize(255), Some(255));
+        assert_eq!(u8::try_from_usize(256), None);
+        assert_eq!(u16::try_from_usize(65_536), None);
+        assert_eq!(u32::try_from_usize(7).map(Symbol::to_usize), Some(7));
+        assert_eq!(u64::try_from_usize(usize::MAX), Some(usize::MAX as u64));
+        assert_eq!(usize::try_from_usize(usize::MAX), Some(usize::MAX));
     }
 
     #[test]
-    fn eq() {
-        // same origin (aka pointer to str)
-        let s = "bar";
-        assert_eq!(InternalStrRef::from_str(s), InternalStrRef::from_str(s));
-        // different origins (aka pointers)
+    fn non_zero_integers() {
+        assert_eq!(NonZeroU8::try_from_usize(0).map(NonZeroU8::get), Some(1));
         assert_eq!(
-            InternalStrRef::from_str("foo"),
-            InternalStrRef::from_str("foo")
+            NonZeroU8::try_from_usize(254).map(Symbol::to_usize),
+            Some(254)
         );
+        assert_eq!(NonZeroU8::try_from_usize(255), None);
+        assert_eq!(NonZeroU16::try_from_usize(65_535), None);
+        assert_eq!(NonZeroU32::try_from_usize(5).map(Symbol::to_usize), Some(5));
+        assert_eq!(NonZeroU64::try_from_usize(usize::MAX), None);
+        assert_eq!(NonZeroUsize::try_from_usize(usize::MAX), None);
     }
 
     #[test]
-    fn ne() {
-        assert_ne!(
-            InternalStrRef::from_str("foo"),
-            InternalStrRef::from_str("bar")
-        )
+    #[should_panic]
+    fn from_usize_overflow() {
+        u8::from_usize(256);
     }
 
     #[test]
-    fn hash_same_as_str() {
-        use std::{collections::hash_map::DefaultHasher, hash::Hash};
-        let (s0, s1) = ("foo", "bar");
-        let (r0, r1) = (InternalStrRef::from_str(s0), InternalStrRef::from_str(s1));
-        let mut sip = DefaultHasher::new();
-        assert_eq!(r0.hash(&mut sip), s0.hash(&mut sip));
-        assert_eq!(r1.hash(&mut sip), s1.hash(&mut sip));
+    fn try_get_or_intern_exhausted() {
+        let mut interner = StringInterner::<NonZeroU8>::new();
+        for n in 0..255 {
+            assert!(interner.try_get_or_intern(n.to_string()).is_ok());
+        }
+        assert_eq!(interner.try_get_or_intern("0").map(Symbol::to_usize), Ok(0));
+        assert_eq!(interner.try_get_or_intern("new"), Err(InternerFull));
+        assert_eq!(interner.len(), 255);
     }
 }
 
-mod len {
+mod sym_u16 {
     use super::*;
 
     #[test]
-    fn new_len() {
-        assert_eq!(DefaultStringInterner::new().len(), 0)
+    fn same_size_as_optional() {
+        use std::mem;
+        assert_eq!(mem::size_of::<SymU16>(), 2);
+        assert_eq!(mem::size_of::<Option<SymU16>>(), 2);
     }
 
     #[test]
-    fn len_after_intern() {
-        let mut interner = DefaultStringInterner::new();
-        interner.get_or_intern("foo");
-        assert_eq!(interner.len(), 1)
+    fn roundtrip() {
+        let max = u16::MAX as usize - 1;
+        assert_eq!(SymU16::from_usize(0).to_usize(), 0);
+        assert_eq!(SymU16::from_usize(max).to_usize(), max);
+        assert_eq!(SymU16::try_from_usize(max + 1), None);
     }
 
     #[test]
-    fn len_after_same() {
-        let mut interner = DefaultStringInterner::new();
-        interner.get_or_intern("foo");
-        interner.get_or_intern("foo");
-        assert_eq!(interner.len(), 1)
+    #[should_panic]
+    fn from_usize_overflow() {
+        SymU16::from_usize(u16::MAX as usize);
     }
 
     #[test]
-    fn len_after_diff() {
-        let mut interner = DefaultStringInterner::new();
-        interner.get_or_intern("foo");
-        interner.get_or_intern("bar");
-        assert_eq!(interner.len(), 2)
+    fn interner() {
+        let mut interner = StringInterner::<SymU16>::new();
+        let foo = interner.get_or_intern("foo");
+        assert_eq!(interner.resolve(foo), Some("foo"));
+        assert_eq!(interner.get("foo"), Some(foo));
+    }
+
+    #[test]
+    fn try_get_or_intern_full() {
+        let mut interner = StringInterner::<SymU16>::new();
+        for i in 0..u16::MAX as usize {
+            assert!(interner.try_get_or_intern(i.to_string()).is_ok());
+        }
+        assert_eq!(interner.try_get_or_intern("overflow"), Err(InternerFull));
+        assert!(interner.try_get_or_intern("0").is_ok());
     }
 }
 
-mod is_empty {
+mod sym_u64 {
     use super::*;
 
     #[test]
-    fn new() {
-        assert_eq!(DefaultStringInterner::new().is_empty(), true)
+    fn same_size_as_optional() {
+        use std::mem;
+        assert_eq!(mem::size_of::<SymU64>(), 8);
+        assert_eq!(mem::size_of::<Option<SymU64>>(), 8);
     }
 
     #[test]
-    fn not_empty() {
-        let mut interner = DefaultStringInterner::with_capacity(1);
-        interner.get_or_intern("foo");
-        assert_eq!(interner.is_empty(), false)
+    #[cfg(target_pointer_width = "64")]
+    fn beyond_u32() {
+        let val = u32::MAX as usize + 1;
+        assert_eq!(SymU64::from_usize(val).to_usize(), val);
+        assert!(Sym::try_from_usize(val).is_none());
+        assert_eq!(SymU64::try_from_usize(usize::MAX), None);
+    }
+
+    #[test]
+    fn interner() {
+        let mut interner = StringInterner::<SymU64>::new();
+        let foo = interner.get_or_intern("foo");
+        let bar = interner.get_or_intern("bar");
+        assert_eq!(bar.to_usize(), 1);
+        assert_eq!(interner.resolve(foo), Some("foo"));
+        assert_eq!(interner.get("bar"), Some(bar));
     }
 }
 
-mod get_or_intern {
+mod non_max_sym {
     use super::*;
 
     #[test]
-    fn simple() {
-        assert_eq!(
-            DefaultStringInterner::new().get_or_intern("foo"),
-            Sym::from_usize(0)
-        )
+    fn same_size_as_optional() {
+        use std::mem;
+        assert_eq!(mem::size_of::<NonMaxSym>(), 4);
+        assert_eq!(mem::size_of::<Option<NonMaxSym>>(), 4);
     }
 
     #[test]
-    fn empty_string() {
-        assert_eq!(
-            DefaultStringInterner::new().get_or_intern(""),
-            Sym::from_usize(0)
-        )
+    fn roundtrip() {
+        let max = u32::MAX as usize - 1;
+        assert_eq!(NonMaxSym::from_usize(0).to_usize(), 0);
+        assert_eq!(NonMaxSym::from_usize(max).to_usize(), max);
+        assert_eq!(NonMaxSym::try_from_usize(max + 1), None);
+        assert!(NonMaxSym::from_usize(0) < NonMaxSym::from_usize(1));
     }
 
     #[test]
-    fn same_twice() {
-        let mut interner = DefaultStringInterner::new();
-        let fst = interner.get_or_intern("foo");
-        let snd = interner.get_or_intern("foo");
-        assert_eq!(fst, snd);
+    fn interner() {
+        let mut interner = StringInterner::<NonMaxSym>::new();
+        let foo = interner.get_or_intern("foo");
+        let bar = interner.get_or_intern("bar");
+        assert_eq!(bar.to_usize(), 1);
+        assert_eq!(interner.resolve(foo), Some("foo"));
+        assert_eq!(interner.get("bar"), Some(bar));
     }
+}
 
-    #[test]
-    fn two_different() {
-        let mut interner = DefaultStringInterner::new();
-        let fst = interner.get_or_intern("foo");
-        let snd = interner.get_or_intern("bar");
-        assert_ne!(fst, snd);
+mod newtype_symbol {
+    use super::*;
+
+    crate::newtype_symbol! {
+        struct FileId;
+        /// Documented symbol.
+        pub(crate) struct NameId;
     }
 
     #[test]
-    fn act_same() {
-        let mut interner1 = DefaultStringInterner::new();
-        let mut interner2 = DefaultStringInterner::new();
-        let sym1 = interner1.get_or_intern("foo");
-        let sym2 = interner2.get_or_intern("foo");
-        assert_eq!(sym1, sym2);
+    fn same_size_as_sym() {
+        use std::mem;
+        assert_eq!(mem::size_of::<FileId>(), mem::size_of::<Sym>());
+        assert_eq!(mem::size_of::<Option<NameId>>(), mem::size_of::<Sym>());
     }
 
     #[test]
-    fn intern_string() {
-        assert_eq!(
-            DefaultStringInterner::new().get_or_intern(String::from("foo")),
-            Sym::from_usize(0)
-        )
+    fn interner() {
+        let mut files = StringInterner::<FileId>::new();
+        let mut names = StringInterner::<NameId>::new();
+        let main = files.get_or_intern("main.rs");
+        let foo = names.get_or_intern("foo");
+        assert_eq!(files.resolve(main), Some("main.rs"));
+        assert_eq!(names.resolve(foo), Some("foo"));
+        assert_eq!(main.to_usize(), foo.to_usize());
+        assert_eq!(FileId::try_from_usize(u32::MAX as usize), None);
     }
 }
 
-mod default {
+mod namespaced {
     use super::*;
+    use crate::{NamespacedStringInterner, NamespacedSym};
+
+    enum Modules {}
+    enum Fields {}
 
     #[test]
-    fn same_as_empty() {
-        assert_eq!(StringInterner::default(), DefaultStringInterner::new())
+    fn same_size_as_sym() {
+        use std::mem;
+        assert_eq!(
+            mem::size_of::<NamespacedSym<Modules>>(),
+            mem::size_of::<Sym>()
+        );
+        assert_eq!(
+            mem::size_of::<Option<NamespacedSym<Fields>>>(),
+            mem::size_of::<Sym>()
+        );
+    }
+
+    #[test]
+    fn separate_namespaces() {
+        let mut modules = NamespacedStringInterner::<Modules>::new();
+        let mut fields = NamespacedStringInterner::<Fields>::new();
+        let std = modules.get_or_intern("std");
+        let len = fields.get_or_intern("len");
+        assert_eq!(modules.resolve(std), Some("std"));
+        assert_eq!(fields.resolve(len), Some("len"));
+        assert_eq!(std.into_inner(), len.into_inner());
+        assert_eq!(modules.get("std"), Some(std));
     }
 }
 
-mod capacity {
-    use super::*;
+mod content_addressed {
+    use crate::{ContentStringInterner, ContentSym};
 
     #[test]
-    fn new() {
-        assert_eq!(DefaultStringInterner::new().capacity(), 0)
+    fn stable_symbols() {
+        assert_eq!(
+            ContentSym::of("").to_u128(),
+            0x6c62_272e_07bb_0142_62b8_2175_6295_c58d
+        );
+        assert_eq!(
+            ContentSym::of("a").to_u128(),
+            0xd228_cb69_6f1a_8caf_7891_2b70_4e4a_8964
+        );
+        const FOO: ContentSym = ContentSym::of("foo");
+        let mut a = ContentStringInterner::new();
+        let mut b = ContentStringInterner::new();
+        b.get_or_intern("bar").unwrap();
+        assert_eq!(a.get_or_intern("foo"), Ok(FOO));
+        assert_eq!(b.get_or_intern("foo"), Ok(FOO));
+        assert_eq!(ContentSym::from_u128(FOO.to_u128()), FOO);
     }
 
     #[test]
-    fn with_capacity() {
-        assert_eq!(DefaultStringInterner::with_capacity(42).capacity(), 42)
+    fn get_and_resolve() {
+        let mut interner = ContentStringInterner::new();
+        let foo = interner.get_or_intern("foo").unwrap();
+        assert_eq!(interner.get_or_intern("foo"), Ok(foo));
+        assert_eq!(interner.get("foo"), Some(foo));
+        assert_eq!(interner.get("bar"), None);
+        assert_eq!(interner.resolve(foo), Some("foo"));
+        assert_eq!(interner.resolve(ContentSym::of("bar")), None);
+        assert_eq!(interner.len(), 1);
+        assert_eq!(interner.iter().collect::<Vec<_>>(), vec![(foo, "foo")]);
     }
+}
+
+mod sorted {
+    use super::*;
+    use crate::SortedStringInterner;
 
     #[test]
-    fn with_capacity_len_0() {
-        assert_eq!(DefaultStringInterner::with_capacity(5).len(), 0)
+    fn ranks_follow_string_order() {
+        let mut interner = SortedStringInterner::<Sym>::new();
+        let c = interner.get_or_intern("c");
+        let a = interner.get_or_intern("a");
+        let b = interner.get_or_intern("b");
+        assert_eq!(interner.get_or_intern("a"), a);
+        assert_eq!(interner.rank(a), Some(0));
+        assert_eq!(interner.rank(b), Some(1));
+        assert_eq!(interner.rank(c), Some(2));
+        assert_eq!(interner.cmp_symbols(c, a), std::cmp::Ordering::Greater);
+        let mut symbols = vec![c, b, a, c];
+        interner.sort_symbols(&mut symbols);
+        assert_eq!(symbols, vec![a, b, c, c]);
+        assert_eq!(
+            interner.iter().map(|(_, s)| s).collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
     }
 
     #[test]
-    fn reserve() {
-        let mut interner = DefaultStringInterner::new();
-        assert_eq!(interner.capacity(), 0);
-        interner.reserve(1337);
-        assert_eq!(interner.capacity(), 1337);
+    fn extend() {
+        let mut interner = SortedStringInterner::<Sym>::new();
+        let d = interner.get_or_intern("d");
+        interner.extend(vec!["b", "e", "a", "b"]);
+        let c = interner.get_or_intern("c");
+        assert_eq!(interner.len(), 5);
+        assert_eq!(interner.rank(d), Some(3));
+        assert_eq!(interner.rank(c), Some(2));
+        assert_eq!(
+            interner.iter().map(|(_, s)| s).collect::<Vec<_>>(),
+            vec!["a", "b", "c", "d", "e"]
+        );
     }
+}
 
-    #[test]
-    fn with_capacity_eq_reserve() {
-        let interner1 = DefaultStringInterner::with_capacity(42);
-        let mut interner2 = DefaultStringInterner::new();
-        assert_ne!(interner1.capacity(), interner2.capacity());
-        interner2.reserve(42);
-        assert_eq!(interner1.capacity(), interner2.capacity());
+mod into_iter_ref {
+    use super::*;
+
+    fn count_chars<'a, I>(iter: I) -> usize
+    where
+        I: IntoIterator<Item = (Sym, &'a str)>,
+    {
+        iter.into_iter().map(|(_, s)| s.len()).sum()
     }
 
     #[test]
-    fn empty_shrink_to_fit() {
-        let mut interner = DefaultStringInterner::with_capacity(100);
-        assert_eq!(interner.capacity(), 100);
-        interner.shrink_to_fit();
-        assert_eq!(interner.capacity(), 0);
+    fn for_loop() {
+        let mut interner = DefaultStringInterner::new();
+        let foo = interner.get_or_intern("foo");
+        let bar = interner.get_or_intern("bar");
+        let mut pairs = Vec::new();
+        for (sym, s) in &interner {
+            pairs.push((sym, s));
+        }
+        assert_eq!(pairs, vec![(foo, "foo"), (bar, "bar")]);
+        assert_eq!(count_chars(&interner), 6);
     }
+}
+
+mod reserve_symbols {
+    use super::*;
+    use crate::ReserveError;
 
     #[test]
-    fn full_shrink_to_fit() {
-        let mut interner = DefaultStringInterner::with_capacity(1);
-        interner.get_or_intern("foo");
-        assert_eq!(interner.capacity(), 1);
-        interner.shrink_to_fit();
-        assert_eq!(interner.capacity(), 1);
+    fn fill_in_parallel() {
+        let mut interner = DefaultStringInterner::new();
+        interner.get_or_intern("first");
+        let block = interner.reserve_symbols(8).unwrap();
+        let symbols = std::thread::scope(|scope| {
+            let block = &block;
+            let handles = (0..block.len())
+                .rev()
+                .map(|n| scope.spawn(move || block.fill(n, n.to_string()).unwrap()))
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(symbols[0], block.symbol(7).unwrap());
+        block.commit().unwrap();
+        for n in 0..8 {
+            assert_eq!(interner.get(n.to_string()), Some(Sym::from_usize(n + 1)));
+        }
+        assert_eq!(interner.len(), 9);
     }
 
     #[test]
-    fn partial_shrink_to_fit() {
-        let mut interner = DefaultStringInterner::with_capacity(3);
+    fn validation() {
+        let mut interner = DefaultStringInterner::new();
         interner.get_or_intern("foo");
-        interner.get_or_intern("bar");
-        assert_eq!(interner.capacity(), 3);
-        interner.shrink_to_fit();
-        assert_eq!(interner.capacity(), 2);
+        let block = interner.reserve_symbols(3).unwrap();
+        assert_eq!(
+            block.fill(3, "x"),
+            Err(ReserveError::OutOfRange { offset: 3 })
+        );
+        assert_eq!(block.fill(0, "a"), Ok(Sym::from_usize(1)));
+        assert_eq!(
+            block.fill(0, "b"),
+            Err(ReserveError::AlreadyFilled { offset: 0 })
+        );
+        block.fill(2, "a").unwrap();
+        assert_eq!(block.commit(), Err(ReserveError::Unfilled { offset: 1 }));
+        let block = interner.reserve_symbols(2).unwrap();
+        block.fill(0, "a").unwrap();
+        block.fill(1, "foo").unwrap();
+        assert_eq!(block.commit(), Err(ReserveError::Duplicate { offset: 1 }));
+        assert_eq!(interner.len(), 1);
     }
 }
 
-mod resolve {
+mod get_or_intern_par {
     use super::*;
 
     #[test]
-    fn simple() {
+    fn small_batch() {
         let mut interner = DefaultStringInterner::new();
-        let sym = interner.get_or_intern("foo");
-        assert_eq!(interner.resolve(sym), Some("foo"));
+        let foo = interner.get_or_intern("foo");
+        let symbols = interner.get_or_intern_par(&["bar", "foo", "bar"]);
+        assert_eq!(symbols[1], foo);
+        assert_eq!(symbols[0], symbols[2]);
+        assert_eq!(interner.len(), 2);
     }
 
     #[test]
-    fn not_found() {
-        let interner = DefaultStringInterner::new();
-        assert_eq!(interner.resolve(Sym::from_usize(0)), None);
+    fn large_batch() {
+        let values = (0..50_000)
+            .map(|n| (n % 20_000).to_string())
+            .collect::<Vec<_>>();
+        let mut interner = DefaultStringInterner::new();
+        let symbols = interner.get_or_intern_par(&values);
+        assert_eq!(interner.len(), 20_000);
+        for (val, sym) in values.iter().zip(symbols) {
+            assert_eq!(interner.resolve(sym), Some(val.as_str()));
+            assert_eq!(interner.get(val), Some(sym));
+        }
     }
 
     #[test]
-    fn unchecked() {
-        let mut interner = DefaultStringInterner::new();
-        let sym = interner.get_or_intern("foo");
-        assert_eq!(unsafe { interner.resolve_unchecked(sym) }, "foo");
+    fn colliding_hashes() {
+        let values = (0..10_000)
+            .map(|n| (n % 1_000).to_string())
+            .collect::<Vec<_>>();
+        let mut interner =
+            StringInterner::<Sym, crate::backend::DefaultBackend<Sym>, _>::with_hasher(
+                CollidingState,
+            );
+        let symbols = interner.get_or_intern_par(&values);
+        assert_eq!(interner.len(), 1_000);
+        for (val, sym) in values.iter().zip(symbols) {
+            assert_eq!(interner.resolve(sym), Some(val.as_str()));
+        }
     }
 }
 
-mod get {
+mod par_iter {
     use super::*;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    };
 
     #[test]
-    fn simple() {
-        let mut interner = DefaultStringInterner::new();
-        let sym = interner.get_or_intern("foo");
-        assert_eq!(interner.get("foo"), Some(sym));
+    fn split_iter() {
+        let mut interner = (0..10)
+            .map(|n| n.to_string())
+            .collect::<DefaultStringInterner>();
+        interner.remove_str("3");
+        let parts = interner.split_iter(3);
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts.iter().map(ExactSizeIterator::len).sum::<usize>(), 9);
+        let joined = parts.into_iter().flatten().collect::<Vec<_>>();
+        assert_eq!(joined, interner.iter().collect::<Vec<_>>());
+        assert_eq!(interner.split_iter(20).len(), 10);
+        assert_eq!(interner.split_iter(0).len(), 1);
+        assert!(DefaultStringInterner::new().split_iter(4).is_empty());
     }
 
-    #[test]
-    fn not_founds() {
-        let interner = DefaultStringInterner::new();
-        assert_eq!(interner.get("foo"), None);
+    fn for_each_split(parts: usize) {
+        let mut interner = (0..50_000)
+            .map(|n| n.to_string())
+            .collect::<DefaultStringInterner>();
+        interner.remove_str("42");
+        let visited = Mutex::new(vec![false; 50_000]);
+        let bytes = AtomicUsize::new(0);
+        let f = |sym: Sym, s: &str| {
+            assert_eq!(s, sym.to_usize().to_string());
+            visited.lock().unwrap()[sym.to_usize()] = true;
+            bytes.fetch_add(s.len(), Ordering::Relaxed);
+        };
+        match parts {
+            0 => interner.par_for_each(f),
+            n => interner.for_each_split(n, f),
+        }
+        let visited = visited.into_inner().unwrap();
+        assert_eq!(visited.iter().filter(|&&v| v).count(), 49_999);
+        assert!(!visited[42]);
+        assert_eq!(
+            bytes.into_inner(),
+            interner.iter().map(|(_, s)| s.len()).sum::<usize>()
+        );
     }
 
     #[test]
-    fn simple_strings() {
-        let mut interner = DefaultStringInterner::new();
-        let sym = interner.get_or_intern("foo");
-        assert_eq!(interner.get(String::from("foo")), Some(sym));
+    fn par_for_each() {
+        for_each_split(0);
+        for_each_split(1);
+        for_each_split(4);
     }
 }
 
-mod iter {
+mod iter_sorted {
     use super::*;
 
     #[test]
-    fn empty() {
-        assert_eq!(DefaultStringInterner::new().iter().next(), None)
+    fn string_order() {
+        let mut interner = DefaultStringInterner::new();
+        let c = interner.get_or_intern("c");
+        let a = interner.get_or_intern("a");
+        let b = interner.get_or_intern("b");
+        assert_eq!(
+            interner.iter_sorted().collect::<Vec<_>>(),
+            vec![(a, "a"), (b, "b"), (c, "c")]
+        );
+        assert_eq!(interner.iter_sorted().next_back(), Some((c, "c")));
     }
+}
+
+mod symbols {
+    use super::*;
 
     #[test]
-    fn simple() {
-        let interner: DefaultStringInterner =
-            vec!["foo", "bar", "baz", "foo"].into_iter().collect();
-        let mut iter = interner.iter();
-        assert_eq!(iter.next(), Some((Sym::from_usize(0), "foo")));
-        assert_eq!(iter.next(), Some((Sym::from_usize(1), "bar")));
-        assert_eq!(iter.next(), Some((Sym::from_usize(2), "baz")));
-        assert_eq!(iter.next(), None);
+    fn insertion_order() {
+        let mut interner = DefaultStringInterner::new();
+        let a = interner.get_or_intern("a");
+        let b = interner.get_or_intern("b");
+        let c = interner.get_or_intern("c");
+        interner.remove(b);
+        let symbols = interner.symbols();
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(symbols.collect::<Vec<_>>(), vec![a, c]);
+        assert_eq!(interner.symbols().rev().collect::<Vec<_>>(), vec![c, a]);
     }
 }
 
-mod iter_values {
+mod iterator_traits {
     use super::*;
+    use crate::backend::{Backend, BucketBackend, BufferBackend, DefaultBackend};
 
-    #[test]
-    fn empty() {
-        assert_eq!(DefaultStringInterner::new().iter_values().next(), None)
+    fn check<B>()
+    where
+        B: Backend<Sym>,
+        B::IntoStrings: DoubleEndedIterator + ExactSizeIterator,
+    {
+        let mut interner = StringInterner::<Sym, B>::new();
+        for s in &["a", "b", "c", "d"] {
+            interner.get_or_intern(s);
+        }
+        interner.remove_str("c");
+        let mut iter = interner.iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back().map(|(_, s)| s), Some("d"));
+        assert_eq!(iter.next().map(|(_, s)| s), Some("a"));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next_back().map(|(_, s)| s), Some("b"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(
+            interner.iter_values().rev().collect::<Vec<_>>(),
+            vec!["d", "b", "a"]
+        );
+        let mut into_iter = interner.into_iter();
+        assert_eq!(into_iter.len(), 3);
+        assert_eq!(
+            into_iter.next_back(),
+            Some((Sym::from_usize(3), "d".to_owned()))
+        );
+        assert_eq!(into_iter.next(), Some((Sym::from_usize(0), "a".to_owned())));
+        assert_eq!(into_iter.len(), 1);
+        assert_eq!(
+            into_iter.next_back(),
+            Some((Sym::from_usize(1), "b".to_owned()))
+        );
+        assert_eq!(into_iter.next(), None);
+        assert_eq!(into_iter.next(), None);
     }
 
     #[test]
-    fn simple() {
-        let interner: DefaultStringInterner =
-            vec!["foo", "bar", "baz", "foo"].into_iter().collect();
-        let mut iter = interner.iter_values();
-        assert_eq!(iter.next(), Some("foo"));
-        assert_eq!(iter.next(), Some("bar"));
-        assert_eq!(iter.next(), Some("baz"));
-        assert_eq!(iter.next(), None);
+    fn all_backends() {
+        check::<DefaultBackend<Sym>>();
+        check::<BucketBackend<Sym>>();
+        check::<BufferBackend<Sym>>();
     }
 }
 
-mod into_iter {
+mod len {
     use super::*;
 
     #[test]
-    fn empty() {
-        assert_eq!(DefaultStringInterner::new().into_iter().next(), None)
+    fn new_len() {
+        assert_eq!(DefaultStringInterner::new().len(), 0)
     }
 
     #[test]
-    fn simple() {
-        let interner: DefaultStringInterner =
-            vec!["foo", "bar", "baz", "foo"].into_iter().collect();
-        let mut iter = interner.into_iter();
-        assert_eq!(iter.next(), Some((Sym::from_usize(0), String::from("foo"))));
-        assert_eq!(iter.next(), Some((Sym::from_usize(1), String::from("bar"))));
-        assert_eq!(iter.next(), Some((Sym::from_usize(2), String::from("baz"))));
-        assert_eq!(iter.next(), None);
+    fn len_after_intern() {
+        let mut interner = DefaultStringInterner::new();
+        interner.get_or_intern("foo");
+        assert_eq!(interner.len(), 1)
+    }
+
+    #[test]
+    fn len_after_same() {
+        let mut interner = DefaultStringInterner::new();
+        interner.get_or_intern("foo");
+        interner.get_or_intern("foo");
+        assert_eq!(interner.len(), 1)
+    }
+
+    #[test]
+    fn len_after_diff() {
+        let mut interner = DefaultStringInterner::new();
+        interner.get_or_intern("foo");
+        interner.get_or_intern("bar");
+        assert_eq!(interner.len(), 2)
     }
 }
 
-mod from_iterator {
+mod is_empty {
     use super::*;
 
     #[test]
-    fn empty() {
+    fn new() {
+        assert!(DefaultStringInterner::new().is_empty())
+    }
+
+    #[test]
+    fn not_empty() {
+        let mut interner = DefaultStringInterner::with_capacity(1);
+        interner.get_or_intern("foo");
+        assert!(!interner.is_empty())
+    }
+}
+
+mod get_or_intern {
+    use super::*;
+
+    #[test]
+    fn simple() {
         assert_eq!(
-            DefaultStringInterner::new(),
-            Vec::<&str>::new()
-                .into_iter()
-                .collect::<DefaultStringInterner>()
+            DefaultStringInterner::new().get_or_intern("foo"),
+            Sym::from_usize(0)
         )
     }
 
     #[test]
-    fn simple() {
+    fn empty_string() {
         assert_eq!(
-            vec!["foo", "bar"]
-                .into_iter()
-                .collect::<DefaultStringInterner>(),
-            {
-                let mut interner = DefaultStringInterner::new();
-                interner.get_or_intern("foo");
-                interner.get_or_intern("bar");
-                interner
-            }
-        );
+            DefaultStringInterner::new().get_or_intern(""),
+            Sym::from_usize(0)
+        )
     }
 
     #[test]
-    fn multiple_same() {
+    fn same_twice() {
+        let mut interner = DefaultStringInterner::new();
+        let fst = interner.get_or_intern("foo");
+        let snd = interner.get_or_intern("foo");
+        assert_eq!(fst, snd);
+    }
+
+    #[test]
+    fn two_different() {
+        let mut interner = DefaultStringInterner::new();
+        let fst = interner.get_or_intern("foo");
+        let snd = interner.get_or_intern("bar");
+        assert_ne!(fst, snd);
+    }
+
+    #[test]
+    fn act_same() {
+        let mut interner1 = DefaultStringInterner::new();
+        let mut interner2 = DefaultStringInterner::new();
+        let sym1 = interner1.get_or_intern("foo");
+        let sym2 = interner2.get_or_intern("foo");
+        assert_eq!(sym1, sym2);
+    }
+
+    #[test]
+    fn intern_string() {
         assert_eq!(
-            vec!["foo", "foo"]
-                .into_iter()
-                .collect::<DefaultStringInterner>(),
-            {
-                let mut interner = DefaultStringInterner::new();
-                interner.get_or_intern("foo");
-                interner
-            }
-        );
+            DefaultStringInterner::new().get_or_intern(String::from("foo")),
+            Sym::from_usize(0)
+        )
     }
 }
 
-mod extend {
+mod intern_iter {
     use super::*;
 
     #[test]
     fn empty() {
         let mut interner = DefaultStringInterner::new();
-        interner.extend(Vec::<&str>::new());
-        assert_eq!(interner, DefaultStringInterner::new(),);
+        assert_eq!(interner.intern_iter(Vec::<&str>::new()).next(), None);
+        assert!(interner.is_empty());
     }
 
     #[test]
-    fn simple() {
+    fn lazy() {
+        let mut interner = DefaultStringInterner::new();
+        {
+            let mut iter = interner.intern_iter(vec!["foo", "bar"]);
+            assert_eq!(iter.next(), Some(Sym::from_usize(0)));
+        }
+        assert_eq!(interner.len(), 1);
+    }
+
+    #[test]
+    fn order_and_dedup() {
+        let mut interner = DefaultStringInterner::new();
+        let syms = interner
+            .intern_iter(vec!["foo", "bar", "foo", "baz", "bar"])
+            .collect::<Vec<_>>();
         assert_eq!(
-            {
-                let mut interner = DefaultStringInterner::new();
-                interner.extend(vec!["foo", "bar"]);
-                interner
-            },
-            {
-                let mut interner = DefaultStringInterner::new();
-                interner.get_or_intern("foo");
-                interner.get_or_intern("bar");
-                interner
-            }
+            syms,
+            vec![
+                Sym::from_usize(0),
+                Sym::from_usize(1),
+                Sym::from_usize(0),
+                Sym::from_usize(2),
+                Sym::from_usize(1),
+            ]
         );
+        assert_eq!(interner.len(), 3);
+        assert_eq!(interner.resolve(syms[3]), Some("baz"));
     }
+}
+
+mod from_sorted_unique {
+    use super::*;
 
     #[test]
-    fn multiple_same() {
+    fn simple() {
+        let interner = DefaultStringInterner::from_sorted_unique(&["bar", "baz", "foo"]);
+        assert_eq!(interner.len(), 3);
+        assert_eq!(interner.resolve(Sym::from_usize(0)), Some("bar"));
+        assert_eq!(interner.get("baz"), Some(Sym::from_usize(1)));
+        assert_eq!(interner.get("foo"), Some(Sym::from_usize(2)));
         assert_eq!(
-            {
-                let mut interner = DefaultStringInterner::new();
-                interner.extend(vec!["foo", "foo"]);
-                interner
-            },
-            {
-                let mut interner = DefaultStringInterner::new();
-                interner.get_or_intern("foo");
-                interner.get_or_intern("foo");
-                interner
-            }
+            interner,
+            vec!["bar", "baz", "foo"]
+                .into_iter()
+                .collect::<DefaultStringInterner>()
         );
     }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn duplicate() {
+        DefaultStringInterner::from_sorted_unique(&["bar", "foo", "foo"]);
+    }
 }
 
-// See <https://github.com/Robbepop/string-interner/issues/9>.
-mod clone_and_drop {
+mod default {
     use super::*;
 
-    fn clone_and_drop() -> (DefaultStringInterner, Sym) {
-        let mut old = DefaultStringInterner::new();
-        let foo = old.get_or_intern("foo");
+    #[test]
+    fn same_as_empty() {
+        assert_eq!(StringInterner::default(), DefaultStringInterner::new())
+    }
+}
 
-        // Return newly created (cloned) interner, and drop the original `old` itself.
-        (old.clone(), foo)
+mod ord {
+    use super::*;
+    use std::cmp::Ordering;
+
+    fn interner(strings: &[&str]) -> DefaultStringInterner {
+        strings.iter().cloned().collect()
     }
 
     #[test]
-    fn no_use_after_free() {
-        let (mut new, foo) = clone_and_drop();
-
-        // This assert may fail if there are use after free bug.
-        // See <https://github.com/Robbepop/string-interner/issues/9> for detail.
+    fn equal() {
         assert_eq!(
-            new.get_or_intern("foo"),
-            foo,
-            "`foo` should represent the string \"foo\" so they should be equal"
+            interner(&["foo", "bar"]).cmp(&interner(&["foo", "bar"])),
+            Ordering::Equal
+        );
+        assert_eq!(
+            DefaultStringInterner::new().cmp(&DefaultStringInterner::new()),
+            Ordering::Equal
         );
     }
 
     #[test]
-    // Test for new (non-`derive`) `Clone` impl.
-    fn clone() {
-        let mut old = DefaultStringInterner::new();
-        let strings = &["foo", "bar", "baz", "qux", "quux", "corge"];
-        let syms = strings
-            .iter()
-            .map(|&s| old.get_or_intern(s))
-            .collect::<Vec<_>>();
+    fn lexicographic() {
+        assert!(interner(&["bar"]) < interner(&["foo"]));
+        assert!(interner(&["baz", "foo"]) < interner(&["foo", "bar"]));
+        assert!(interner(&["foo"]) > interner(&["bar", "foo"]));
+        assert!(interner(&["bar", "foo"]) < interner(&["foo", "bar"]));
+        assert!(DefaultStringInterner::new() < interner(&["foo"]));
+    }
 
-        let mut new = old.clone();
-        for (&s, &sym) in strings.iter().zip(&syms) {
-            assert_eq!(new.resolve(sym), Some(s));
-            assert_eq!(new.get_or_intern(s), sym);
-        }
+    #[test]
+    fn prefix() {
+        assert!(interner(&["bar"]) < interner(&["bar", "foo"]));
+        assert!(interner(&["foo", "bar"]) > interner(&["bar"]));
+    }
+
+    #[test]
+    fn consistent_with_eq() {
+        let (a, b) = (interner(&["foo", "bar"]), interner(&["bar", "foo"]));
+        assert_ne!(a, b);
+        assert_ne!(a.cmp(&b), Ordering::Equal);
+        assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+    }
+
+    #[test]
+    fn symbols_matter() {
+        let mut a = interner(&["removed", "foo"]);
+        let mut b = interner(&["foo", "removed"]);
+        a.remove_str("removed");
+        b.remove_str("removed");
+        assert!(a.iter_values().eq(b.iter_values()));
+        assert_ne!(a, b);
+        assert_ne!(a.cmp(&b), Ordering::Equal);
+        assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+    }
+
+    #[test]
+    fn btree_set() {
+        use std::collections::BTreeSet;
+        let set = vec![
+            interner(&["foo"]),
+            interner(&["bar", "baz"]),
+            interner(&["foo"]),
+        ]
+        .into_iter()
+        .collect::<BTreeSet<_>>();
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.iter().next(), Some(&interner(&["bar", "baz"])));
+    }
+}
+
+mod capacity {
+    use super::*;
+
+    #[test]
+    fn new() {
+        assert_eq!(DefaultStringInterner::new().capacity(), 0)
+    }
+
+    #[test]
+    fn with_capacity() {
+        assert_eq!(DefaultStringInterner::with_capacity(42).capacity(), 42)
+    }
+
+    #[test]
+    fn with_capacity_len_0() {
+        assert_eq!(DefaultStringInterner::with_capacity(5).len(), 0)
+    }
+
+    #[test]
+    fn reserve() {
+        let mut interner = DefaultStringInterner::new();
+        assert_eq!(interner.capacity(), 0);
+        interner.reserve(1337);
+        assert_eq!(interner.capacity(), 1337);
+    }
+
+    #[test]
+    fn reserve_in_use() {
+        let mut interner = vec!["foo", "bar", "baz"]
+            .into_iter()
+            .collect::<DefaultStringInterner>();
+        interner.reserve(100);
+        assert!(interner.capacity() >= interner.len() + 100);
+        assert_eq!(interner.get("bar"), Some(Sym::from_usize(1)));
+    }
+
+    #[test]
+    fn with_capacity_eq_reserve() {
+        let interner1 = DefaultStringInterner::with_capacity(42);
+        let mut interner2 = DefaultStringInterner::new();
+        assert_ne!(interner1.capacity(), interner2.capacity());
+        interner2.reserve(42);
+        assert_eq!(interner1.capacity(), interner2.capacity());
+    }
+
+    #[test]
+    fn shrink_to() {
+        let mut interner = DefaultStringInterner::with_capacity(100);
+        interner.get_or_intern("foo");
+        interner.shrink_to(10);
+        assert!(interner.capacity() >= 10);
+        assert!(interner.capacity() < 100);
+        interner.shrink_to(0);
+        assert!(interner.capacity() >= 1);
+        assert_eq!(interner.get("foo"), Some(Sym::from_usize(0)));
+    }
+
+    #[test]
+    fn empty_shrink_to_fit() {
+        let mut interner = DefaultStringInterner::with_capacity(100);
+        assert_eq!(interner.capacity(), 100);
+        interner.shrink_to_fit();
+        assert_eq!(interner.capacity(), 0);
+    }
+
+    #[test]
+    fn full_shrink_to_fit() {
+        let mut interner = DefaultStringInterner::with_capacity(1);
+        interner.get_or_intern("foo");
+        assert_eq!(interner.capacity(), 1);
+        interner.shrink_to_fit();
+        assert_eq!(interner.capacity(), 1);
+    }
+
+    #[test]
+    fn partial_shrink_to_fit() {
+        let mut interner = DefaultStringInterner::with_capacity(3);
+        interner.get_or_intern("foo");
+        interner.get_or_intern("bar");
+        assert_eq!(interner.capacity(), 3);
+        interner.shrink_to_fit();
+        assert_eq!(interner.capacity(), 2);
+    }
+}
+
+mod resolve {
+    use super::*;
+
+    #[test]
+    fn simple() {
+        let mut interner = DefaultStringInterner::new();
+        let sym = interner.get_or_intern("foo");
+        assert_eq!(interner.resolve(sym), Some("foo"));
+    }
+
+    #[test]
+    fn not_found() {
+        let interner = DefaultStringInterner::new();
+        assert_eq!(interner.resolve(Sym::from_usize(0)), None);
+    }
+
+    #[test]
+    fn unchecked() {
+        let mut interner = DefaultStringInterner::new();
+        let sym = interner.get_or_intern("foo");
+        assert_eq!(unsafe { interner.resolve_unchecked(sym) }, "foo");
+    }
+
+    #[test]
+    #[cfg(feature = "checked_symbols")]
+    #[should_panic]
+    fn unchecked_invalid_symbol() {
+        let interner = DefaultStringInterner::new();
+        unsafe { interner.resolve_unchecked(Sym::from_usize(0)) };
+    }
+}
+
+#[cfg(feature = "debug-validate")]
+mod debug_validate {
+    use super::*;
+    use crate::TaggedSym;
+
+    #[test]
+    fn same_interner() {
+        let mut interner = StringInterner::<TaggedSym>::new();
+        let foo = interner.get_or_intern("foo");
+        assert_eq!(interner.get("foo"), Some(foo));
+        assert_eq!(interner.resolve(foo), Some("foo"));
+        assert_eq!(interner.clone().resolve(foo), Some("foo"));
+        let (sym, _) = interner.iter().next().unwrap();
+        assert_eq!(sym, foo);
+        assert_eq!(interner.resolve(sym), Some("foo"));
+    }
+
+    #[test]
+    #[should_panic(expected = "created by another interner")]
+    fn other_interner() {
+        let mut a = StringInterner::<TaggedSym>::new();
+        let mut b = StringInterner::<TaggedSym>::new();
+        let foo = a.get_or_intern("foo");
+        b.get_or_intern("bar");
+        b.resolve(foo);
+    }
+
+    #[test]
+    #[should_panic(expected = "created by another interner")]
+    fn iterated_symbols() {
+        let mut a = StringInterner::<TaggedSym>::new();
+        let mut b = StringInterner::<TaggedSym>::new();
+        a.get_or_intern("foo");
+        b.get_or_intern("bar");
+        let sym = a.symbols().next().unwrap();
+        assert_eq!(sym.generation(), a.iter().next().unwrap().0.generation());
+        assert!(sym.generation().is_some());
+        let (owned, _) = a.clone().into_iter().next().unwrap();
+        assert_eq!(owned.generation(), sym.generation());
+        b.resolve(sym);
+    }
+
+    #[test]
+    #[should_panic(expected = "created by another interner")]
+    fn default_symbols() {
+        let mut a = DefaultStringInterner::new();
+        let mut b = DefaultStringInterner::new();
+        let foo = a.get_or_intern("foo");
+        assert!(foo.generation().is_some());
+        assert_eq!(a.resolve(foo), Some("foo"));
+        b.get_or_intern("bar");
+        b.resolve(foo);
+    }
+}
+
+mod ignore_ascii_case {
+    use super::*;
+
+    #[test]
+    fn canonical_spelling() {
+        let mut interner = DefaultStringInterner::new();
+        let content_type = interner.get_or_intern("Content-Type");
+        assert_eq!(
+            interner.get_ignore_ascii_case("content-type"),
+            Some(content_type)
+        );
+        assert_eq!(
+            interner.get_or_intern_ignore_ascii_case("CONTENT-TYPE"),
+            content_type
+        );
+        let accept = interner.get_or_intern_ignore_ascii_case("accept");
+        assert_eq!(interner.get_or_intern_ignore_ascii_case("Accept"), accept);
+        assert_eq!(interner.get_ignore_ascii_case("ACCEPT"), Some(accept));
+        assert_eq!(interner.get_ignore_ascii_case("Host"), None);
+        assert_eq!(interner.resolve(accept), Some("accept"));
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn long_and_non_ascii() {
+        let mut interner = DefaultStringInterner::new();
+        let long = "ä".repeat(40) + &"x".repeat(100);
+        let sym = interner.get_or_intern_ignore_ascii_case(&long);
+        assert_eq!(
+            interner.get_ignore_ascii_case(long.to_ascii_uppercase()),
+            Some(sym)
+        );
+        assert_eq!(interner.get_ignore_ascii_case(long.to_uppercase()), None);
+    }
+
+    #[test]
+    fn after_removal_and_compaction() {
+        let mut interner = DefaultStringInterner::new();
+        let foo = interner.get_or_intern_ignore_ascii_case("foo");
+        let bar = interner.get_or_intern("BAR");
+        interner.remove(foo);
+        assert_eq!(interner.get_ignore_ascii_case("FOO"), None);
+        let remap = interner.compact();
+        let bar = remap.get(bar).unwrap();
+        assert_eq!(interner.get_or_intern_ignore_ascii_case("bar"), bar);
+        interner.drain();
+        assert_eq!(interner.get_ignore_ascii_case("bar"), None);
+        let baz = interner.get_or_intern("baz");
+        assert_eq!(interner.get_ignore_ascii_case("BAZ"), Some(baz));
+    }
+}
+
+mod bytes {
+    use super::*;
+    use crate::ByteInterner;
+
+    #[test]
+    fn binary_tokens() {
+        let mut interner = ByteInterner::<Sym>::new();
+        let invalid = interner.get_or_intern([0xFF, 0xFE, 0x00]);
+        let text = interner.get_or_intern("foo");
+        assert_eq!(interner.get_or_intern(vec![0xFF, 0xFE, 0x00]), invalid);
+        assert_eq!(interner.get(b"foo"), Some(text));
+        assert_eq!(interner.get([0xFF]), None);
+        assert_eq!(interner.resolve(invalid), Some(&[0xFF, 0xFE, 0x00][..]));
+        assert_eq!(interner.resolve(text), Some(&b"foo"[..]));
+        assert_eq!(interner.len(), 2);
+        assert_eq!(
+            interner
+                .iter()
+                .rev()
+                .map(|(sym, _)| sym)
+                .collect::<Vec<_>>(),
+            vec![text, invalid]
+        );
+    }
+}
+
+mod os {
+    use super::*;
+    use crate::{OsStrInterner, PathInterner};
+    use std::{ffi::OsStr, path::Path};
+
+    #[test]
+    fn os_str() {
+        let mut interner = OsStrInterner::<Sym>::new();
+        let foo = interner.get_or_intern("foo");
+        assert_eq!(interner.get_or_intern(OsStr::new("foo")), foo);
+        assert_eq!(interner.get(String::from("foo")), Some(foo));
+        assert_eq!(interner.resolve(foo), Some(OsStr::new("foo")));
+        assert_eq!(interner.get("bar"), None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn non_utf8() {
+        use std::os::unix::ffi::OsStrExt;
+        let mut interner = OsStrInterner::<Sym>::new();
+        let invalid = OsStr::from_bytes(&[0x66, 0xFF]);
+        let sym = interner.get_or_intern(invalid);
+        assert_eq!(interner.resolve(sym), Some(invalid));
+        assert_ne!(
+            interner.get_or_intern(invalid.to_string_lossy().as_ref()),
+            sym
+        );
+    }
+
+    #[test]
+    fn paths() {
+        let mut interner = PathInterner::<Sym>::new();
+        let main = interner.get_or_intern("src/main.rs");
+        let lib = interner.get_or_intern(Path::new("src").join("lib.rs"));
+        assert_eq!(interner.get_or_intern(Path::new("src/main.rs")), main);
+        assert_eq!(interner.resolve(main), Some(Path::new("src/main.rs")));
+        assert_eq!(interner.get(Path::new("src").join("lib.rs")), Some(lib));
+        assert_eq!(
+            interner.iter().collect::<Vec<_>>(),
+            vec![
+                (main, Path::new("src/main.rs")),
+                (lib, Path::new("src").join("lib.rs").as_path())
+            ]
+        );
+    }
+}
+
+mod wide {
+    use super::*;
+    use crate::WideStringInterner;
+
+    #[test]
+    fn utf16_strings() {
+        let mut interner = WideStringInterner::<Sym>::new();
+        let wide = "Grüße".encode_utf16().collect::<Vec<u16>>();
+        let sym = interner.get_or_intern(&wide);
+        assert_eq!(interner.get_or_intern_str("Grüße"), sym);
+        assert_eq!(interner.get(&wide), Some(sym));
+        assert_eq!(interner.get_str("Grüße"), Some(sym));
+        assert_eq!(interner.resolve(sym), Some(&wide[..]));
+        assert_eq!(interner.resolve_lossy(sym), Some("Grüße".to_owned()));
+        assert_eq!(interner.get_str("Gruesse"), None);
+        assert_eq!(interner.len(), 1);
+    }
+
+    #[test]
+    fn unpaired_surrogates() {
+        let mut interner = WideStringInterner::<Sym>::new();
+        let a = interner.get_or_intern([0xD800]);
+        let b = interner.get_or_intern([0xDC00]);
+        let empty = interner.get_or_intern([]);
+        assert_ne!(a, b);
+        assert_eq!(interner.resolve(b), Some(&[0xDC00][..]));
+        assert_eq!(interner.resolve_lossy(a), Some("\u{FFFD}".to_owned()));
+        assert_eq!(interner.resolve(empty), Some(&[][..]));
+        assert_eq!(
+            interner.iter().map(|(sym, _)| sym).collect::<Vec<_>>(),
+            vec![a, b, empty]
+        );
+    }
+}
+
+mod folded {
+    use super::*;
+
+    #[test]
+    fn companion_symbols() {
+        let mut interner = DefaultStringInterner::new();
+        let (street, folded) = interner.get_or_intern_folded("Straße");
+        assert_ne!(street, folded);
+        assert_eq!(interner.resolve(folded), Some("straße"));
+        assert_eq!(interner.get_or_intern_folded("Straße"), (street, folded));
+        assert_eq!(interner.get_or_intern_folded("straße"), (folded, folded));
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn titlecase() {
+        let mut interner = DefaultStringInterner::new();
+        let (dz, folded) = interner.get_or_intern_folded("ǅ");
+        assert_ne!(dz, folded);
+        assert_eq!(interner.resolve(folded), Some("ǆ"));
+    }
+
+    #[test]
+    fn invalidated_by_removal() {
+        let mut interner = DefaultStringInterner::new();
+        let (foo, folded) = interner.get_or_intern_folded("FOO");
+        interner.remove(folded);
+        let (sym, refolded) = interner.get_or_intern_folded("FOO");
+        assert_eq!(sym, foo);
+        assert_ne!(refolded, folded);
+        assert_eq!(interner.resolve(refolded), Some("foo"));
+        interner.truncate(1);
+        interner.get_or_intern("bar");
+        let (_, folded) = interner.get_or_intern_folded("FOO");
+        assert_eq!(interner.resolve(folded), Some("foo"));
+    }
+}
+
+mod normalizing {
+    use super::*;
+    use crate::{AsciiCaseFold, NormalizingStringInterner};
+    use std::borrow::Cow;
+
+    #[test]
+    fn identity() {
+        let mut interner = NormalizingStringInterner::<Sym>::default();
+        let foo = interner.get_or_intern("Foo");
+        assert_ne!(interner.get_or_intern("foo"), foo);
+        assert_eq!(interner.get("Foo"), Some(foo));
+    }
+
+    #[test]
+    fn ascii_case_fold() {
+        let mut interner = NormalizingStringInterner::<Sym, AsciiCaseFold>::default();
+        let select = interner.get_or_intern("SELECT");
+        assert_eq!(interner.get_or_intern("select"), select);
+        assert_eq!(interner.get("Select"), Some(select));
+        assert_eq!(interner.resolve(select), Some("select"));
+        assert_eq!(interner.len(), 1);
+    }
+
+    #[test]
+    fn function() {
+        fn trim(val: &str) -> Cow<'_, str> {
+            Cow::Borrowed(val.trim())
+        }
+        let mut interner = NormalizingStringInterner::<Sym, _>::with_normalizer(trim);
+        let foo = interner.get_or_intern(" foo ");
+        assert_eq!(interner.get("foo"), Some(foo));
+        assert_eq!(interner.resolve(foo), Some("foo"));
+    }
+}
+
+mod entry {
+    use super::*;
+    use crate::Entry;
+
+    #[test]
+    fn occupied_and_vacant() {
+        let mut interner = DefaultStringInterner::new();
+        let foo = interner.get_or_intern("foo");
+        match interner.entry("foo") {
+            Entry::Occupied(entry) => {
+                assert_eq!(entry.key(), "foo");
+                assert_eq!(entry.symbol(), foo);
+                assert_eq!(entry.get(), "foo");
+            }
+            Entry::Vacant(_) => panic!("expected an occupied entry"),
+        }
+        let bar = match interner.entry(String::from("bar")) {
+            Entry::Occupied(_) => panic!("expected a vacant entry"),
+            Entry::Vacant(entry) => {
+                assert_eq!(entry.key(), "bar");
+                entry.insert_owned(String::from("bar"))
+            }
+        };
+        assert_eq!(interner.resolve(bar), Some("bar"));
+        assert_eq!(interner.entry("bar").symbol(), Some(bar));
+        assert_eq!(interner.entry("baz").symbol(), None);
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn or_intern() {
+        let mut interner = DefaultStringInterner::new();
+        let foo = interner.entry("foo").or_intern();
+        assert_eq!(interner.entry("foo").or_intern(), foo);
+        let bar = interner.entry("bar").or_intern_with(|| "bar".to_owned());
+        assert_eq!(interner.entry("bar").or_intern_with(|| unreachable!()), bar);
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn remove_occupied() {
+        let mut interner = DefaultStringInterner::new();
+        interner.get_or_intern("foo");
+        if let Entry::Occupied(entry) = interner.entry("foo") {
+            assert_eq!(entry.remove(), "foo");
+        }
+        assert_eq!(interner.get("foo"), None);
+    }
+}
+
+mod get_or_intern_resolved {
+    use super::*;
+
+    #[test]
+    fn canonical_string() {
+        let mut interner = DefaultStringInterner::new();
+        let (foo, stored) = interner.get_or_intern_resolved(String::from("foo"));
+        assert_eq!(stored, "foo");
+        let ptr = stored.as_ptr();
+        let (sym, stored) = interner.get_or_intern_resolved("foo");
+        assert_eq!(sym, foo);
+        assert_eq!(stored.as_ptr(), ptr);
+        assert_eq!(interner.resolve(foo).map(str::as_ptr), Some(ptr));
+    }
+}
+
+mod intern_entry {
+    use super::*;
+
+    #[test]
+    fn reports_insertion() {
+        let mut interner = DefaultStringInterner::new();
+        let (foo, inserted) = interner.intern_entry("foo");
+        assert!(inserted);
+        assert_eq!(interner.intern_entry("foo"), (foo, false));
+        assert_eq!(interner.intern_entry(String::from("foo")), (foo, false));
+        let (bar, inserted) = interner.intern_entry("bar");
+        assert!(inserted);
+        assert_ne!(foo, bar);
+        assert_eq!(interner.len(), 2);
+    }
+}
+
+mod contains {
+    use super::*;
+
+    #[test]
+    fn strings_and_symbols() {
+        let mut interner = DefaultStringInterner::new();
+        let foo = interner.get_or_intern("foo");
+        let bar = interner.get_or_intern("bar");
+        interner.remove(bar);
+        assert!(interner.contains("foo"));
+        assert!(!interner.contains("bar"));
+        assert!(!interner.contains("baz"));
+        assert!(interner.contains_symbol(foo));
+        assert!(!interner.contains_symbol(bar));
+        assert!(!interner.contains_symbol(Sym::from_usize(2)));
+    }
+}
+
+mod index {
+    use super::*;
+
+    #[test]
+    fn valid_symbol() {
+        let mut interner = DefaultStringInterner::new();
+        let foo = interner.get_or_intern("foo");
+        assert_eq!(&interner[foo], "foo");
+    }
+
+    #[test]
+    #[should_panic(expected = "Symbol with index 1 has no associated string")]
+    fn invalid_symbol() {
+        let mut interner = DefaultStringInterner::new();
+        interner.get_or_intern("foo");
+        let _ = &interner[Sym::from_usize(1)];
+    }
+}
+
+mod resolve_index {
+    use super::*;
+
+    #[test]
+    fn in_range() {
+        let mut interner = DefaultStringInterner::new();
+        interner.get_or_intern("foo");
+        interner.get_or_intern("bar");
+        assert_eq!(interner.resolve_index(0), Some("foo"));
+        assert_eq!(interner.resolve_index(1), Some("bar"));
+    }
+
+    #[test]
+    fn out_of_range() {
+        let mut interner = DefaultStringInterner::new();
+        assert_eq!(interner.resolve_index(0), None);
+        interner.get_or_intern("foo");
+        assert_eq!(interner.resolve_index(1), None);
+        assert_eq!(interner.resolve_index(usize::MAX), None);
+    }
+
+    #[test]
+    fn usize_symbols() {
+        let mut interner = StringInterner::<usize>::new();
+        let sym = interner.get_or_intern("foo");
+        assert_eq!(interner.resolve_index(sym), interner.resolve(sym));
+    }
+}
+
+mod get {
+    use super::*;
+
+    #[test]
+    fn simple() {
+        let mut interner = DefaultStringInterner::new();
+        let sym = interner.get_or_intern("foo");
+        assert_eq!(interner.get("foo"), Some(sym));
+    }
+
+    #[test]
+    fn not_founds() {
+        let interner = DefaultStringInterner::new();
+        assert_eq!(interner.get("foo"), None);
+    }
+
+    #[test]
+    fn simple_strings() {
+        let mut interner = DefaultStringInterner::new();
+        let sym = interner.get_or_intern("foo");
+        assert_eq!(interner.get(String::from("foo")), Some(sym));
+    }
+}
+
+mod iter {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(DefaultStringInterner::new().iter().next(), None)
+    }
+
+    #[test]
+    fn simple() {
+        let interner: DefaultStringInterner =
+            vec!["foo", "bar", "baz", "foo"].into_iter().collect();
+        let mut iter = interner.iter();
+        assert_eq!(iter.next(), Some((Sym::from_usize(0), "foo")));
+        assert_eq!(iter.next(), Some((Sym::from_usize(1), "bar")));
+        assert_eq!(iter.next(), Some((Sym::from_usize(2), "baz")));
+        assert_eq!(iter.next(), None);
+    }
+}
+
+mod iter_values {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(DefaultStringInterner::new().iter_values().next(), None)
+    }
+
+    #[test]
+    fn simple() {
+        let interner: DefaultStringInterner =
+            vec!["foo", "bar", "baz", "foo"].into_iter().collect();
+        let mut iter = interner.iter_values();
+        assert_eq!(iter.next(), Some("foo"));
+        assert_eq!(iter.next(), Some("bar"));
+        assert_eq!(iter.next(), Some("baz"));
+        assert_eq!(iter.next(), None);
+    }
+}
+
+mod into_iter {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(DefaultStringInterner::new().into_iter().next(), None)
+    }
+
+    #[test]
+    fn simple() {
+        let interner: DefaultStringInterner =
+            vec!["foo", "bar", "baz", "foo"].into_iter().collect();
+        let mut iter = interner.into_iter();
+        assert_eq!(iter.next(), Some((Sym::from_usize(0), String::from("foo"))));
+        assert_eq!(iter.next(), Some((Sym::from_usize(1), String::from("bar"))));
+        assert_eq!(iter.next(), Some((Sym::from_usize(2), String::from("baz"))));
+        assert_eq!(iter.next(), None);
+    }
+}
+
+mod from_iterator {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            DefaultStringInterner::new(),
+            Vec::<&str>::new()
+                .into_iter()
+                .collect::<DefaultStringInterner>()
+        )
+    }
+
+    #[test]
+    fn simple() {
+        assert_eq!(
+            vec!["foo", "bar"]
+                .into_iter()
+                .collect::<DefaultStringInterner>(),
+            {
+                let mut interner = DefaultStringInterner::new();
+                interner.get_or_intern("foo");
+                interner.get_or_intern("bar");
+                interner
+            }
+        );
+    }
+
+    #[test]
+    fn multiple_same() {
+        assert_eq!(
+            vec!["foo", "foo"]
+                .into_iter()
+                .collect::<DefaultStringInterner>(),
+            {
+                let mut interner = DefaultStringInterner::new();
+                interner.get_or_intern("foo");
+                interner
+            }
+        );
+    }
+}
+
+mod extend {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let mut interner = DefaultStringInterner::new();
+        interner.extend(Vec::<&str>::new());
+        assert_eq!(interner, DefaultStringInterner::new(),);
+    }
+
+    #[test]
+    fn simple() {
+        assert_eq!(
+            {
+                let mut interner = DefaultStringInterner::new();
+                interner.extend(vec!["foo", "bar"]);
+                interner
+            },
+            {
+                let mut interner = DefaultStringInterner::new();
+                interner.get_or_intern("foo");
+                interner.get_or_intern("bar");
+                interner
+            }
+        );
+    }
+
+    #[test]
+    fn multiple_same() {
+        assert_eq!(
+            {
+                let mut interner = DefaultStringInterner::new();
+                interner.extend(vec!["foo", "foo"]);
+                interner
+            },
+            {
+                let mut interner = DefaultStringInterner::new();
+                interner.get_or_intern("foo");
+                interner.get_or_intern("foo");
+                interner
+            }
+        );
+    }
+
+    #[test]
+    fn as_ref_items() {
+        let words = ["foo", "bar", "foo"];
+        let mut interner = DefaultStringInterner::new();
+        interner.extend(words.iter());
+        interner.extend(vec![Box::<str>::from("baz")]);
+        assert_eq!(
+            interner.iter_values().collect::<Vec<_>>(),
+            vec!["foo", "bar", "baz"]
+        );
+        let collected = words.iter().collect::<DefaultStringInterner>();
+        assert_eq!(collected.len(), 2);
+    }
+}
+
+// See <https://github.com/Robbepop/string-interner/issues/9>.
+mod clone_and_drop {
+    use super::*;
+
+    fn clone_and_drop() -> (DefaultStringInterner, Sym) {
+        let mut old = DefaultStringInterner::new();
+        let foo = old.get_or_intern("foo");
+
+        // Return newly created (cloned) interner, and drop the original `old` itself.
+        (old.clone(), foo)
+    }
+
+    #[test]
+    fn no_use_after_free() {
+        let (mut new, foo) = clone_and_drop();
+
+        // This assert may fail if there are use after free bug.
+        // See <https://github.com/Robbepop/string-interner/issues/9> for detail.
+        assert_eq!(
+            new.get_or_intern("foo"),
+            foo,
+            "`foo` should represent the string \"foo\" so they should be equal"
+        );
+    }
+
+    #[test]
+    // Test for new (non-`derive`) `Clone` impl.
+    fn clone() {
+        let mut old = DefaultStringInterner::new();
+        let strings = &["foo", "bar", "baz", "qux", "quux", "corge"];
+        let syms = strings
+            .iter()
+            .map(|&s| old.get_or_intern(s))
+            .collect::<Vec<_>>();
+
+        let mut new = old.clone();
+        for (&s, &sym) in strings.iter().zip(&syms) {
+            assert_eq!(new.resolve(sym), Some(s));
+            assert_eq!(new.get_or_intern(s), sym);
+        }
+    }
+
+    fn clone_is_independent<B>()
+    where
+        B: crate::backend::Backend<Sym> + Clone,
+    {
+        let mut old = StringInterner::<Sym, B, _>::with_hasher(CollidingState);
+        let foo = old.get_or_intern("foo");
+        let bar = old.get_or_intern("bar");
+        let mut new = old.clone();
+        old.remove(foo);
+        old.get_or_intern("baz");
+        drop(old);
+        assert_eq!(new.get("foo"), Some(foo));
+        assert_eq!(new.get("bar"), Some(bar));
+        assert_eq!(new.get("baz"), None);
+        assert_eq!(new.get_or_intern("qux"), Sym::from_usize(2));
+        assert_eq!(new.resolve(foo), Some("foo"));
+    }
+
+    #[test]
+    fn clone_all_backends() {
+        use crate::backend::{ArcBackend, BucketBackend, BufferBackend, SimpleBackend};
+        clone_is_independent::<SimpleBackend<Sym>>();
+        clone_is_independent::<BucketBackend<Sym>>();
+        clone_is_independent::<BufferBackend<Sym>>();
+        clone_is_independent::<ArcBackend<Sym>>();
+    }
+}
+
+mod shared {
+    use super::*;
+
+    #[test]
+    fn clone_is_shared() {
+        let mut old = SharedStringInterner::<Sym>::new();
+        old.get_or_intern("foo");
+        let new = old.clone();
+        assert!(new.is_shared_with(&old));
+        assert_eq!(new, old);
+    }
+
+    #[test]
+    fn lookups_do_not_split() {
+        let mut old = SharedStringInterner::<Sym>::new();
+        let foo = old.get_or_intern("foo");
+        let mut new = old.clone();
+        assert_eq!(new.get_or_intern("foo"), foo);
+        assert_eq!(new.get("foo"), Some(foo));
+        assert_eq!(new.resolve(foo), Some("foo"));
+        assert!(new.is_shared_with(&old));
+    }
+
+    #[test]
+    fn split_on_intern() {
+        let mut old = SharedStringInterner::<Sym>::new();
+        let foo = old.get_or_intern("foo");
+        let bar = old.get_or_intern("bar");
+        let mut new = old.clone();
+
+        let baz = old.get_or_intern("baz");
+        let qux = new.get_or_intern("qux");
+        assert!(!new.is_shared_with(&old));
+
+        // Both copies are independent of each other ...
+        assert_eq!(baz, qux);
+        assert_eq!(old.resolve(baz), Some("baz"));
+        assert_eq!(new.resolve(qux), Some("qux"));
+        assert_eq!(old.get("qux"), None);
+        assert_eq!(new.get("baz"), None);
+
+        // ... but still share the strings of their common prefix.
+        for &sym in &[foo, bar] {
+            let (s0, s1) = (old.resolve(sym).unwrap(), new.resolve(sym).unwrap());
+            assert_eq!(s0, s1);
+            assert_eq!(s0.as_ptr(), s1.as_ptr());
+        }
+    }
+
+    #[test]
+    fn is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SharedStringInterner<Sym>>();
+    }
+}
+
+#[cfg(feature = "serde_support")]
+mod serde_interner {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let interner = vec!["a", "b", "c"]
+            .into_iter()
+            .collect::<DefaultStringInterner>();
+        let json = serde_json::to_string(&interner).unwrap();
+        assert_eq!(json, r#"["a","b","c"]"#);
+        let restored = serde_json::from_str::<DefaultStringInterner>(&json).unwrap();
+        assert_eq!(restored, interner);
+    }
+
+    #[test]
+    fn removed_strings() {
+        let mut interner = DefaultStringInterner::new();
+        let a = interner.get_or_intern("a");
+        let b = interner.get_or_intern("b");
+        let c = interner.get_or_intern("c");
+        interner.remove(a);
+        interner.remove(c);
+        let json = serde_json::to_string(&interner).unwrap();
+        assert_eq!(json, r#"[null,"b",null]"#);
+        let mut restored = serde_json::from_str::<DefaultStringInterner>(&json).unwrap();
+        // Symbols of the source interner are only resolved by index against the restored one.
+        let (a, b, c) = (
+            Sym::from_usize(a.to_usize()),
+            Sym::from_usize(b.to_usize()),
+            Sym::from_usize(c.to_usize()),
+        );
+        assert_eq!(restored.len(), 1);
+        assert_eq!(restored.resolve(a), None);
+        assert_eq!(restored.resolve(b), Some("b"));
+        assert_eq!(restored.resolve(c), None);
+        assert_eq!(restored.get("a"), None);
+        assert_eq!(restored.get_or_intern("d"), Sym::from_usize(3));
+        assert_eq!(
+            restored.iter().collect::<Vec<_>>(),
+            vec![(b, "b"), (Sym::from_usize(3), "d")]
+        );
+    }
+}
+
+#[cfg(feature = "serde_support")]
+mod serde_symbol {
+    use super::*;
+    use crate::{SymbolSeed, SymbolWithInterner};
+    use serde::{
+        de::DeserializeSeed,
+        ser::{Serialize, SerializeStruct, Serializer},
+    };
+
+    struct Field {
+        name: Sym,
+        offset: u32,
+    }
+
+    struct FieldWithInterner<'a> {
+        field: &'a Field,
+        interner: &'a DefaultStringInterner,
+    }
+
+    impl<'a> Serialize for FieldWithInterner<'a> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct("Field", 2)?;
+            state.serialize_field(
+                "name",
+                &SymbolWithInterner::new(self.field.name, self.interner),
+            )?;
+            state.serialize_field("offset", &self.field.offset)?;
+            state.end()
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut source = DefaultStringInterner::new();
+        source.get_or_intern("bar");
+        let field = Field {
+            name: source.get_or_intern("foo"),
+            offset: 42,
+        };
+        let json = serde_json::to_string(&FieldWithInterner {
+            field: &field,
+            interner: &source,
+        })
+        .unwrap();
+        assert_eq!(json, r#"{"name":"foo","offset":42}"#);
+
+        let mut target = DefaultStringInterner::new();
+        let value = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+        let name = SymbolSeed::new(&mut target)
+            .deserialize(value["name"].clone())
+            .unwrap();
+        assert_eq!(name, Sym::from_usize(0));
+        assert_ne!(name, field.name);
+        assert_eq!(target.resolve(name), Some("foo"));
+        assert_eq!(value["offset"], 42);
+    }
+
+    #[test]
+    fn deserialize_existing() {
+        let mut target = DefaultStringInterner::new();
+        let foo = target.get_or_intern("foo");
+        let mut de = serde_json::Deserializer::from_str(r#""foo""#);
+        assert_eq!(
+            SymbolSeed::new(&mut target).deserialize(&mut de).unwrap(),
+            foo
+        );
+        assert_eq!(target.len(), 1);
+    }
+
+    #[test]
+    fn serialize_invalid_symbol() {
+        let interner = DefaultStringInterner::new();
+        assert!(
+            serde_json::to_string(&SymbolWithInterner::new(Sym::from_usize(0), &interner)).is_err()
+        );
+    }
+}
+
+mod concurrent {
+    use super::*;
+
+    #[test]
+    fn simple() {
+        let interner = ConcurrentStringInterner::<Sym>::new();
+        let foo = interner.get_or_intern("foo");
+        let bar = interner.get_or_intern(String::from("bar"));
+        assert_ne!(foo, bar);
+        assert_eq!(interner.get_or_intern("foo"), foo);
+        assert_eq!(interner.get("bar"), Some(bar));
+        assert_eq!(interner.get("baz"), None);
+        assert_eq!(interner.resolve(foo), Some("foo"));
+        assert_eq!(interner.resolve(Sym::from_usize(64)), None);
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn parallel() {
+        let interner = ConcurrentStringInterner::<Sym>::new();
+        let words = (0..100).map(|i| i.to_string()).collect::<Vec<_>>();
+        let syms = std::thread::scope(|scope| {
+            let handles = (0..4)
+                .map(|_| {
+                    scope.spawn(|| {
+                        words
+                            .iter()
+                            .map(|w| interner.get_or_intern(w.as_str()))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(interner.len(), words.len());
+        for thread_syms in &syms {
+            assert_eq!(thread_syms, &syms[0]);
+        }
+        for (word, &sym) in words.iter().zip(&syms[0]) {
+            assert_eq!(interner.resolve(sym), Some(word.as_str()));
+        }
+    }
+
+    #[test]
+    fn is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<ConcurrentStringInterner<Sym>>();
+    }
+
+    #[test]
+    fn dense_symbols() {
+        let interner = ConcurrentStringInterner::<SymU16>::new();
+        for i in 0..usize::from(u16::MAX) {
+            assert_eq!(interner.get_or_intern(i.to_string()).to_usize(), i);
+        }
+        assert_eq!(interner.len(), usize::from(u16::MAX));
+        assert_eq!(interner.resolve(SymU16::from_usize(0)), Some("0"));
+        assert_eq!(interner.get("65534"), Some(SymU16::from_usize(65534)));
+    }
+
+    #[test]
+    fn colliding_hashes() {
+        let interner = ConcurrentStringInterner::<Sym, _>::with_hasher(CollidingState);
+        let foo = interner.get_or_intern("foo");
+        let bar = interner.get_or_intern("bar");
+        let baz = interner.get_or_intern("baz");
+        assert_eq!((foo.to_usize(), bar.to_usize(), baz.to_usize()), (0, 1, 2));
+        assert_eq!(interner.get_or_intern("bar"), bar);
+        assert_eq!(interner.get("baz"), Some(baz));
+        assert_eq!(interner.get("qux"), None);
+        assert_eq!(interner.resolve(baz), Some("baz"));
+    }
+}
+
+mod append_only {
+    use super::*;
+
+    #[test]
+    fn simple() {
+        let interner = AppendOnlyStringInterner::<Sym>::new();
+        assert!(interner.is_empty());
+        let foo = interner.get_or_intern("foo");
+        let bar = interner.get_or_intern(String::from("bar"));
+        assert_ne!(foo, bar);
+        assert_eq!(interner.get_or_intern("foo"), foo);
+        assert_eq!(interner.get("bar"), Some(bar));
+        assert_eq!(interner.get("baz"), None);
+        assert_eq!(interner.resolve(bar), Some("bar"));
+        assert_eq!(interner.resolve(Sym::from_usize(2)), None);
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn many_segments() {
+        let interner = AppendOnlyStringInterner::<usize>::new();
+        let foo = interner.get_or_intern("foo");
+        let resolved = interner.resolve(foo).unwrap();
+        for i in 0..1000 {
+            assert_eq!(interner.get_or_intern(i.to_string()), i + 1);
+        }
+        // References handed out earlier stay valid.
+        assert_eq!(resolved, "foo");
+        for i in 0..1000 {
+            assert_eq!(interner.resolve(i + 1), Some(i.to_string().as_str()));
+        }
+        assert_eq!(interner.resolve(1001), None);
+        assert_eq!(interner.resolve(usize::MAX), None);
+    }
+
+    #[test]
+    fn parallel_resolve() {
+        let interner = AppendOnlyStringInterner::<Sym>::new();
+        let foo = interner.get_or_intern("foo");
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                for i in 0..1000 {
+                    interner.get_or_intern(i.to_string());
+                }
+            });
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..1000 {
+                        assert_eq!(interner.resolve(foo), Some("foo"));
+                    }
+                });
+            }
+        });
+        assert_eq!(interner.len(), 1001);
+    }
+}
+
+mod frozen {
+    use super::*;
+
+    #[test]
+    fn colliding_hashes() {
+        let mut interner =
+            StringInterner::<Sym, crate::backend::DefaultBackend<Sym>, _>::with_hasher(
+                CollidingState,
+            );
+        for s in &["a", "b", "c"] {
+            interner.get_or_intern(s);
+        }
+        interner.remove_str("b");
+        let frozen = interner.freeze();
+        assert_eq!(frozen.get("a"), Some(Sym::from_usize(0)));
+        assert_eq!(frozen.get("b"), None);
+        assert_eq!(frozen.get("c"), Some(Sym::from_usize(2)));
+    }
+
+    #[test]
+    fn send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<crate::FrozenStringInterner<Sym>>();
+    }
+
+    #[test]
+    fn empty() {
+        let frozen = DefaultStringInterner::new().freeze();
+        assert!(frozen.is_empty());
+        assert_eq!(frozen.get("foo"), None);
+        assert_eq!(frozen.resolve(Sym::from_usize(0)), None);
+        assert_eq!(frozen.iter().next(), None);
+    }
+
+    #[test]
+    fn symbols_stay_valid() {
+        let mut interner = DefaultStringInterner::new();
+        let strings = ["foo", "", "bar", "baz"];
+        let syms = strings
+            .iter()
+            .map(|&s| interner.get_or_intern(s))
+            .collect::<Vec<_>>();
+        let frozen = interner.freeze();
+        assert_eq!(frozen.len(), strings.len());
+        for (&s, &sym) in strings.iter().zip(&syms) {
+            assert_eq!(frozen.get(s), Some(sym));
+            assert_eq!(frozen.resolve(sym), Some(s));
+        }
+        assert_eq!(frozen.get("qux"), None);
+        assert_eq!(frozen.resolve(Sym::from_usize(4)), None);
+    }
+
+    #[test]
+    fn iter() {
+        let frozen = vec!["foo", "bar", "baz", "foo"]
+            .into_iter()
+            .collect::<DefaultStringInterner>()
+            .freeze();
+        let mut iter = frozen.iter();
+        assert_eq!(iter.next(), Some((Sym::from_usize(0), "foo")));
+        assert_eq!(iter.next(), Some((Sym::from_usize(1), "bar")));
+        assert_eq!(iter.next(), Some((Sym::from_usize(2), "baz")));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn removed() {
+        let mut interner = DefaultStringInterner::new();
+        let foo = interner.get_or_intern("foo");
+        let empty = interner.get_or_intern("");
+        let bar = interner.get_or_intern("bar");
+        interner.remove(empty);
+        interner.remove(foo);
+        let frozen = interner.freeze();
+        assert_eq!(frozen.len(), 1);
+        assert_eq!(frozen.get(""), None);
+        assert_eq!(frozen.get("foo"), None);
+        assert_eq!(frozen.resolve(empty), None);
+        assert_eq!(frozen.resolve(foo), None);
+        assert_eq!(frozen.resolve(bar), Some("bar"));
+        assert_eq!(frozen.iter().collect::<Vec<_>>(), vec![(bar, "bar")]);
+    }
+}
+
+mod snapshot {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let snapshot = DefaultStringInterner::new().snapshot();
+        assert!(snapshot.is_empty());
+        assert_eq!(snapshot.resolve(Sym::from_usize(0)), None);
+    }
+
+    #[test]
+    fn resolve_while_interning() {
+        let mut interner = DefaultStringInterner::new();
+        let foo = interner.get_or_intern("foo");
+        let bar = interner.get_or_intern("bar");
+        let snapshot = interner.snapshot();
+        let baz = std::thread::scope(|scope| {
+            let worker = {
+                let snapshot = snapshot.clone();
+                scope.spawn(move || {
+                    assert_eq!(snapshot.resolve(foo), Some("foo"));
+                    assert_eq!(snapshot.resolve(bar), Some("bar"));
+                })
+            };
+            let baz = interner.get_or_intern("baz");
+            worker.join().unwrap();
+            baz
+        });
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot.resolve(baz), None);
+        assert_eq!(interner.snapshot().resolve(baz), Some("baz"));
+    }
+
+    #[test]
+    fn removed() {
+        let mut interner = DefaultStringInterner::new();
+        let foo = interner.get_or_intern("foo");
+        let bar = interner.get_or_intern("bar");
+        interner.remove(foo);
+        let snapshot = interner.snapshot();
+        assert_eq!(snapshot.len(), 1);
+        assert_eq!(snapshot.resolve(foo), None);
+        assert_eq!(snapshot.resolve(bar), Some("bar"));
+    }
+}
+
+mod local {
+    use super::*;
+    use crate::{
+        backend::{ArcBackend, BucketBackend, SimpleBackend, StableBackend},
+        DefaultHashBuilder, LocalStringInterner,
+    };
+
+    struct Node<'a> {
+        interner: &'a LocalStringInterner<Sym>,
+    }
+
+    impl<'a> Node<'a> {
+        fn name(&self, name: &str) -> Sym {
+            self.interner.get_or_intern(name)
+        }
+    }
+
+    #[test]
+    fn intern_through_shared_ref() {
+        let interner = LocalStringInterner::new();
+        let (a, b) = (
+            Node {
+                interner: &interner,
+            },
+            Node {
+                interner: &interner,
+            },
+        );
+        let foo = a.name("foo");
+        assert_eq!(b.name("foo"), foo);
+        assert_ne!(b.name("bar"), foo);
+        assert_eq!(interner.get("bar"), Some(Sym::from_usize(1)));
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn from_default_interner() {
+        let mut interner = DefaultStringInterner::new();
+        let foo = interner.get_or_intern("foo");
+        let local: LocalStringInterner<Sym> = interner.into();
+        assert_eq!(local.resolve(foo), Some("foo"));
+    }
+
+    #[test]
+    fn resolve_across_interning() {
+        let interner = LocalStringInterner::<Sym>::new();
+        let foo = interner.get_or_intern("foo");
+        let resolved = interner.resolve(foo).unwrap();
+        for i in 0..100 {
+            interner.get_or_intern(i.to_string());
+        }
+        assert_eq!(resolved, "foo");
+        assert_eq!(interner.resolve(Sym::from_usize(101)), None);
+        assert_eq!(interner.into_inner().len(), 101);
+    }
+
+    fn resolve_across_interning_with<B>()
+    where
+        B: StableBackend<Sym>,
+    {
+        let interner =
+            LocalStringInterner::<Sym, B, DefaultHashBuilder>::with_hasher(Default::default());
+        let resolved = (0..10_000)
+            .map(|i| {
+                interner
+                    .resolve(interner.get_or_intern(i.to_string()))
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        for (i, resolved) in resolved.into_iter().enumerate() {
+            assert_eq!(resolved, i.to_string());
+        }
+    }
+
+    #[test]
+    fn stable_backends() {
+        resolve_across_interning_with::<SimpleBackend<Sym>>();
+        resolve_across_interning_with::<BucketBackend<Sym>>();
+        resolve_across_interning_with::<ArcBackend<Sym>>();
+    }
+}
+
+mod backend {
+    use super::*;
+    use crate::backend::{Backend, SimpleBackend};
+
+    #[test]
+    fn simple_backend() {
+        let mut backend = SimpleBackend::<Sym>::default();
+        let foo = backend.intern("foo");
+        let bar = backend.intern_string(String::from("bar"));
+        assert_eq!(foo, Sym::from_usize(0));
+        assert_eq!(bar, Sym::from_usize(1));
+        assert_eq!(backend.resolve(bar), Some("bar"));
+        assert_eq!(backend.resolve(Sym::from_usize(2)), None);
+        assert_eq!(backend.len(), 2);
+        assert_eq!(
+            backend.into_strings().collect::<Vec<_>>(),
+            vec![Some(String::from("foo")), Some(String::from("bar"))]
+        );
+    }
+
+    #[test]
+    fn simple_backend_static_and_owned() {
+        let mut backend = SimpleBackend::<Sym>::default();
+        let mut owned = String::with_capacity(64);
+        owned.push_str("owned");
+        let owned = backend.intern_string(owned);
+        let keyword = backend.intern_static("fn");
+        let empty = backend.intern("");
+        let other = backend.intern_static("let");
+        assert_eq!(backend.strings_heap_bytes(), 64);
+        assert_eq!(backend.remove(keyword), Some(String::from("fn")));
+        assert_eq!(backend.resolve(keyword), None);
+        let clone = backend.clone();
+        assert_eq!(clone, backend);
+        assert_eq!(clone.resolve(other), Some("let"));
+        backend.truncate(3);
+        assert_eq!(backend.resolve(other), None);
+        assert_eq!(backend.resolve(empty), Some(""));
+        assert_eq!(backend.resolve(owned), Some("owned"));
+        assert_eq!(
+            clone.into_strings().collect::<Vec<_>>(),
+            vec![
+                Some(String::from("owned")),
+                None,
+                Some(String::new()),
+                Some(String::from("let"))
+            ]
+        );
+    }
+
+    #[test]
+    fn explicit_backend() {
+        let mut interner = StringInterner::<Sym, SimpleBackend<Sym>>::new();
+        let foo = interner.get_or_intern("foo");
+        assert_eq!(interner.get_or_intern("foo"), foo);
+        assert_eq!(interner.resolve(foo), Some("foo"));
+    }
+
+    #[test]
+    fn hash_collisions() {
+        let mut interner =
+            StringInterner::<Sym, SimpleBackend<Sym>, _>::with_hasher(CollidingState);
+        let strings = ["foo", "bar", "baz"];
+        let syms = strings
+            .iter()
+            .map(|&s| interner.get_or_intern(s))
+            .collect::<Vec<_>>();
+        assert_eq!(interner.len(), 3);
+        for (&s, &sym) in strings.iter().zip(&syms) {
+            assert_eq!(interner.get(s), Some(sym));
+            assert_eq!(interner.get_or_intern(s), sym);
+        }
+        assert_eq!(interner.get("qux"), None);
+    }
+}
+
+mod bucket_backend {
+    use super::*;
+    use crate::backend::{Backend, BucketBackend};
+
+    type BucketInterner = StringInterner<Sym, BucketBackend<Sym>>;
+
+    #[test]
+    fn simple() {
+        let mut interner = BucketInterner::new();
+        let foo = interner.get_or_intern("foo");
+        let bar = interner.get_or_intern(String::from("bar"));
+        assert_eq!(interner.get_or_intern("foo"), foo);
+        assert_eq!(interner.get("bar"), Some(bar));
+        assert_eq!(interner.resolve(foo), Some("foo"));
+        assert_eq!(interner.resolve(Sym::from_usize(2)), None);
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn shares_buckets() {
+        let mut backend = BucketBackend::<Sym>::with_bucket_size(8);
+        let syms = ["foo", "bar", "baz", "quux", "thisisalongstring", "a"]
+            .iter()
+            .map(|&s| backend.intern(s))
+            .collect::<Vec<_>>();
+        // "foo" + "bar" | "baz" + "quux" | "thisisalongstring" | "a"
+        assert_eq!(backend.num_buckets(), 4);
+        assert_eq!(backend.resolve(syms[1]), Some("bar"));
+        assert_eq!(backend.resolve(syms[3]), Some("quux"));
+        assert_eq!(backend.resolve(syms[4]), Some("thisisalongstring"));
+        assert_eq!(backend.resolve(syms[5]), Some("a"));
+    }
+
+    #[test]
+    fn large_owned_strings() {
+        let mut backend = BucketBackend::<Sym>::with_bucket_size(8);
+        let foo = backend.intern("foo");
+        let large = backend.intern_string(String::from("thisisalongstring"));
+        let bar = backend.intern("bar");
+        // The large string does not interrupt filling the head bucket.
+        assert_eq!(backend.num_buckets(), 2);
+        assert_eq!(backend.resolve(foo), Some("foo"));
+        assert_eq!(backend.resolve(large), Some("thisisalongstring"));
+        assert_eq!(backend.resolve(bar), Some("bar"));
+    }
+
+    #[test]
+    fn into_iter() {
+        let interner = vec!["foo", "bar", "foo"]
+            .into_iter()
+            .collect::<BucketInterner>();
+        assert_eq!(
+            interner.into_iter().collect::<Vec<_>>(),
+            vec![
+                (Sym::from_usize(0), String::from("foo")),
+                (Sym::from_usize(1), String::from("bar")),
+            ]
+        );
+    }
+}
+
+mod buffer_backend {
+    use super::*;
+    use crate::backend::{Backend, BufferBackend};
+
+    type BufferInterner = StringInterner<Sym, BufferBackend<Sym>>;
+
+    #[test]
+    fn simple() {
+        let mut interner = BufferInterner::new();
+        let foo = interner.get_or_intern("foo");
+        let empty = interner.get_or_intern("");
+        let bar = interner.get_or_intern(String::from("bar"));
+        assert_eq!(interner.get_or_intern("foo"), foo);
+        assert_eq!(interner.get(""), Some(empty));
+        assert_eq!(interner.resolve(bar), Some("bar"));
+        assert_eq!(interner.resolve(empty), Some(""));
+        assert_eq!(interner.resolve(Sym::from_usize(3)), None);
+        assert_eq!(unsafe { interner.resolve_unchecked(foo) }, "foo");
+    }
+
+    #[test]
+    fn contiguous() {
+        let mut backend = BufferBackend::<Sym>::default();
+        backend.intern("foo");
+        backend.intern("bar");
+        assert_eq!(backend.buffer_len(), 6);
+        assert_eq!(
+            backend.into_strings().collect::<Vec<_>>(),
+            vec![Some(String::from("foo")), Some(String::from("bar"))]
+        );
+    }
+
+    #[test]
+    fn from_iter() {
+        let interner = vec!["foo", "bar", "baz"]
+            .into_iter()
+            .collect::<BufferInterner>();
+        assert_eq!(
+            interner.iter_values().collect::<Vec<_>>(),
+            vec!["foo", "bar", "baz"]
+        );
+    }
+}
+
+mod span_sym {
+    use super::*;
+    use crate::backend::BufferBackend;
+
+    type BufferInterner = StringInterner<Sym, BufferBackend<Sym>>;
+
+    #[test]
+    fn resolve_span() {
+        let mut interner = BufferInterner::new();
+        let foo = interner.get_or_intern_span("foo");
+        let bar = interner.get_or_intern_span(String::from("bar"));
+        assert_eq!(interner.get_or_intern_span("foo"), foo);
+        assert_eq!((foo.offset(), foo.len()), (0, 3));
+        assert_eq!((bar.offset(), bar.len()), (3, 3));
+        assert_eq!(interner.resolve_span(foo), Some("foo"));
+        assert_eq!(interner.resolve_span(bar), Some("bar"));
+        let sym = interner.get("bar").unwrap();
+        assert_eq!(interner.span_of(sym), Some(bar));
+        assert_eq!(interner.span_of(Sym::from_usize(2)), None);
+    }
+
+    #[test]
+    fn foreign_span() {
+        let mut long = BufferInterner::new();
+        let mut short = BufferInterner::new();
+        let span = long.get_or_intern_span("hello, world");
+        short.get_or_intern("hi");
+        assert_eq!(short.resolve_span(span), None);
+        // Spans that do not lie on character boundaries do not resolve.
+        let mut ascii = BufferInterner::new();
+        ascii.get_or_intern("a");
+        let span = ascii.get_or_intern_span("b");
+        let mut unicode = BufferInterner::new();
+        unicode.get_or_intern("ö");
+        assert_eq!(unicode.resolve_span(span), None);
+    }
+
+    #[test]
+    fn truncated_span() {
+        let mut interner = BufferInterner::new();
+        let foo = interner.get_or_intern_span("foo");
+        let bar = interner.get_or_intern_span("bar");
+        interner.truncate(1);
+        assert_eq!(interner.resolve_span(foo), Some("foo"));
+        assert_eq!(interner.resolve_span(bar), None);
+        interner.pop();
+        assert_eq!(interner.resolve_span(foo), None);
+        interner.get_or_intern("x");
+        assert_eq!(interner.resolve_span(foo), None);
+    }
+}
+
+mod inline_sym {
+    use super::*;
+
+    #[test]
+    fn short_strings_are_inline() {
+        let mut interner = DefaultStringInterner::new();
+        let foo = interner.get_or_intern_inline("foo");
+        let empty = interner.get_or_intern_inline("");
+        let seven = interner.get_or_intern_inline("1234567");
+        assert!(foo.is_inline() && empty.is_inline() && seven.is_inline());
+        assert!(interner.is_empty());
+        assert_eq!(foo.as_inline(), Some("foo"));
+        assert_eq!(interner.resolve_inline(&empty), Some(""));
+        assert_eq!(interner.resolve_inline(&seven), Some("1234567"));
+        assert_eq!(interner.get_inline("foo"), Some(foo));
+        assert_ne!(foo, interner.get_or_intern_inline("fo"));
+    }
+
+    #[test]
+    fn long_strings_are_interned() {
+        let mut interner = DefaultStringInterner::new();
+        let long = interner.get_or_intern_inline("12345678");
+        assert!(!long.is_inline());
+        assert_eq!(long.as_inline(), None);
+        assert_eq!(interner.len(), 1);
+        assert_eq!(
+            interner.get_or_intern_inline(String::from("12345678")),
+            long
+        );
+        assert_eq!(interner.resolve_inline(&long), Some("12345678"));
+        assert_eq!(interner.get_inline("12345678"), Some(long));
+        assert_eq!(interner.get_inline("123456789"), None);
+        assert_eq!(DefaultStringInterner::new().resolve_inline(&long), None);
+    }
+
+    #[test]
+    fn multi_byte() {
+        let mut interner = DefaultStringInterner::new();
+        let inline = interner.get_or_intern_inline("äöü");
+        let long = interner.get_or_intern_inline("äöüß");
+        assert!(inline.is_inline());
+        assert!(!long.is_inline());
+        assert_eq!(interner.resolve_inline(&inline), Some("äöü"));
+        assert_eq!(interner.resolve_inline(&long), Some("äöüß"));
+    }
+}
+
+mod front_coded {
+    use super::*;
+
+    fn paths() -> Vec<String> {
+        (0..40)
+            .map(|n| format!("src/module_{}/file_{}.rs", n / 10, n % 10))
+            .collect()
+    }
+
+    #[test]
+    fn resolve() {
+        let interner = paths().into_iter().collect::<DefaultStringInterner>();
+        let front_coded = interner.freeze_front_coded();
+        assert_eq!(front_coded.len(), interner.len());
+        for (sym, string) in interner.iter() {
+            assert_eq!(front_coded.resolve(sym).as_deref(), Some(string));
+        }
+        assert_eq!(front_coded.resolve(Sym::from_usize(40)), None);
+    }
+
+    #[test]
+    fn compresses() {
+        let interner = paths().into_iter().collect::<DefaultStringInterner>();
+        let total = interner.iter_values().map(str::len).sum::<usize>();
+        let front_coded = interner.freeze_front_coded();
+        assert!(front_coded.encoded_len() < total / 2);
+    }
+
+    #[test]
+    fn iter() {
+        let interner = vec!["", "foo", "foobar", "fo", "äb", "äc", "ö"]
+            .into_iter()
+            .collect::<DefaultStringInterner>();
+        let front_coded = interner.freeze_front_coded();
+        assert_eq!(
+            front_coded.iter().collect::<Vec<_>>(),
+            interner
+                .iter_sorted()
+                .map(|(sym, s)| (sym, s.to_owned()))
+                .collect::<Vec<_>>()
+        );
+        assert!(DefaultStringInterner::new().freeze_front_coded().is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let interner = paths().into_iter().rev().collect::<DefaultStringInterner>();
+        let front_coded = interner.freeze_front_coded();
+        for (sym, string) in interner.iter() {
+            assert_eq!(front_coded.get(string), Some(sym));
+        }
+        assert_eq!(front_coded.get(""), None);
+        assert_eq!(front_coded.get("src"), None);
+        assert_eq!(front_coded.get("src/module_1/file_10.rs"), None);
+        assert_eq!(front_coded.get("zzz"), None);
+        assert_eq!(
+            DefaultStringInterner::new().freeze_front_coded().get(""),
+            None
+        );
+    }
+
+    #[test]
+    fn removed() {
+        let mut interner = paths().into_iter().collect::<DefaultStringInterner>();
+        for index in [0, 5, 16, 17, 39] {
+            interner.remove(Sym::from_usize(index));
+        }
+        let front_coded = interner.freeze_front_coded();
+        assert_eq!(front_coded.len(), 35);
+        for index in [0, 5, 16, 17, 39] {
+            assert_eq!(front_coded.resolve(Sym::from_usize(index)), None);
+        }
+        for (sym, string) in interner.iter() {
+            assert_eq!(front_coded.resolve(sym).as_deref(), Some(string));
+        }
+        for index in [0, 5, 16, 17, 39] {
+            let string = paths().swap_remove(index);
+            assert_eq!(front_coded.get(string), None);
+        }
+        assert_eq!(
+            front_coded.iter().collect::<Vec<_>>(),
+            interner
+                .iter_sorted()
+                .map(|(sym, s)| (sym, s.to_owned()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(front_coded.iter().len(), 35);
+    }
+}
+
+mod fixed {
+    use super::*;
+
+    #[test]
+    fn get_or_intern() {
+        let mut interner = FixedStringInterner::<Sym, 4, 16>::new();
+        let foo = interner.get_or_intern("foo").unwrap();
+        let bar = interner.get_or_intern("bar").unwrap();
+        assert_ne!(foo, bar);
+        assert_eq!(interner.get_or_intern("foo"), Ok(foo));
+        assert_eq!(interner.get("bar"), Some(bar));
+        assert_eq!(interner.get("baz"), None);
+        assert_eq!(interner.resolve(foo), Some("foo"));
+        assert_eq!(interner.resolve(Sym::from_usize(2)), None);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.remaining_bytes(), 10);
+    }
+
+    #[test]
+    fn full_symbols() {
+        let mut interner = FixedStringInterner::<Sym, 2, 16>::new();
+        let a = interner.get_or_intern("a").unwrap();
+        let b = interner.get_or_intern("b").unwrap();
+        assert_eq!(interner.get_or_intern("c"), Err(InternerFull));
+        assert_eq!(interner.get_or_intern("a"), Ok(a));
+        assert_eq!(interner.get("b"), Some(b));
+        assert_eq!(interner.get("c"), None);
+        assert_eq!(interner.capacity(), 2);
+    }
+
+    #[test]
+    fn full_bytes() {
+        let mut interner = FixedStringInterner::<Sym, 8, 6>::new();
+        interner.get_or_intern("foo").unwrap();
+        assert_eq!(interner.get_or_intern("quux"), Err(InternerFull));
+        let bar = interner.get_or_intern("bar").unwrap();
+        assert_eq!(interner.remaining_bytes(), 0);
+        assert_eq!(interner.get_or_intern(""), Ok(Sym::from_usize(2)));
+        assert_eq!(interner.resolve(bar), Some("bar"));
+        assert_eq!(
+            FixedStringInterner::<Sym, 0, 0>::new().get_or_intern(""),
+            Err(InternerFull)
+        );
+    }
+}
+
+mod arena {
+    use super::*;
+
+    #[test]
+    fn get_or_intern() {
+        let arena = StringArena::new();
+        let mut interner = ArenaStringInterner::<Sym>::new(&arena);
+        let foo = interner.get_or_intern("foo");
+        let bar = interner.get_or_intern(String::from("bar"));
+        assert_eq!(interner.get_or_intern("foo"), foo);
+        assert_eq!(interner.get("bar"), Some(bar));
+        assert_eq!(interner.get("baz"), None);
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn resolved_strings_outlive_interner() {
+        let arena = StringArena::new();
+        let resolved = {
+            let mut interner = ArenaStringInterner::<Sym>::new(&arena);
+            let syms = (0..1000)
+                .map(|n| interner.get_or_intern(n.to_string()))
+                .collect::<Vec<_>>();
+            syms.into_iter()
+                .map(|sym| interner.resolve(sym).unwrap())
+                .collect::<Vec<_>>()
+        };
+        for (n, s) in resolved.into_iter().enumerate() {
+            assert_eq!(s, n.to_string());
+        }
+    }
+
+    #[test]
+    fn chunks() {
+        let arena = StringArena::new();
+        let small = arena.alloc_str("small");
+        assert_eq!(arena.allocated_bytes(), 1024);
+        let large = arena.alloc_str(&"x".repeat(3000));
+        assert_eq!(arena.allocated_bytes(), 1024 + 3000);
+        assert_eq!(small, "small");
+        assert_eq!(large.len(), 3000);
+    }
+}
+
+mod get_or_intern_static {
+    use super::*;
+
+    #[test]
+    fn references_static() {
+        static KEYWORD: &str = "keyword";
+        let mut interner = DefaultStringInterner::new();
+        let sym = interner.get_or_intern_static(KEYWORD);
+        assert_eq!(interner.get_or_intern("keyword"), sym);
+        assert_eq!(interner.get_or_intern_static(KEYWORD), sym);
+        assert!(std::ptr::eq(interner.resolve(sym).unwrap(), KEYWORD));
+    }
+
+    #[test]
+    fn existing() {
+        let mut interner = DefaultStringInterner::new();
+        let sym = interner.get_or_intern(String::from("fn"));
+        assert_eq!(interner.get_or_intern_static("fn"), sym);
+        assert_eq!(interner.len(), 1);
+        let sym = interner.get_or_intern_static("let");
+        assert_eq!(interner.clone().resolve(sym), Some("let"));
+        assert_eq!(
+            interner.into_iter().map(|(_, s)| s).collect::<Vec<_>>(),
+            vec![String::from("fn"), String::from("let")]
+        );
+    }
+}
+
+mod get_or_intern_owned {
+    use super::*;
+
+    #[test]
+    fn moves_on_miss() {
+        let mut interner = DefaultStringInterner::new();
+        let owned = String::from("foo");
+        let ptr = owned.as_ptr();
+        let sym = interner.get_or_intern_owned(owned);
+        assert_eq!(interner.resolve(sym).map(str::as_ptr), Some(ptr));
+        assert_eq!(interner.get_or_intern("foo"), sym);
+        assert_eq!(interner.get_or_intern_owned(String::from("foo")), sym);
+        assert_eq!(interner.len(), 1);
+    }
+
+    #[test]
+    fn borrowed_types() {
+        let mut interner = DefaultStringInterner::new();
+        let boxed: Box<str> = "bar".into();
+        let sym = interner.get_or_intern(&boxed);
+        assert_eq!(interner.get_or_intern(std::borrow::Cow::from("bar")), sym);
+        assert_eq!(interner.get_or_intern(&*boxed), sym);
+        let local = LocalStringInterner::<Sym>::new();
+        assert_eq!(
+            local.get_or_intern_owned(String::from("baz")),
+            local.get_or_intern("baz")
+        );
+    }
+}
+
+mod get_or_intern_with {
+    use super::*;
+
+    #[test]
+    fn constructs_only_on_miss() {
+        let mut interner = DefaultStringInterner::new();
+        let mut calls = 0;
+        let foo = interner.get_or_intern_with("foo", || {
+            calls += 1;
+            String::from("foo")
+        });
+        assert_eq!(interner.resolve(foo), Some("foo"));
+        let again = interner.get_or_intern_with("foo", || {
+            calls += 1;
+            String::from("foo")
+        });
+        assert_eq!(again, foo);
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn mismatch() {
+        let mut interner = DefaultStringInterner::new();
+        interner.get_or_intern_with("foo", || String::from("bar"));
+    }
+}
+
+mod get_or_intern_cow {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn owned_and_borrowed() {
+        let mut interner = DefaultStringInterner::new();
+        let owned = String::from("unescaped");
+        let ptr = owned.as_ptr();
+        let sym = interner.get_or_intern_cow(Cow::Owned(owned));
+        assert_eq!(interner.resolve(sym).map(str::as_ptr), Some(ptr));
+        assert_eq!(interner.get_or_intern_cow(Cow::Borrowed("unescaped")), sym);
+        let borrowed = interner.get_or_intern_cow(Cow::Borrowed("raw"));
+        assert_eq!(interner.resolve(borrowed), Some("raw"));
+        assert_eq!(
+            interner.get_or_intern_cow(Cow::Owned(String::from("raw"))),
+            borrowed
+        );
+        assert_eq!(interner.len(), 2);
+    }
+}
+
+mod zero_copy {
+    use super::*;
+
+    #[test]
+    fn keeps_allocation() {
+        let mut interner = DefaultStringInterner::new();
+        let mut owned = String::with_capacity(64);
+        owned.push_str("foo");
+        let ptr = owned.as_ptr();
+        let sym = interner.get_or_intern_owned(owned);
+        assert_eq!(interner.resolve(sym).map(str::as_ptr), Some(ptr));
+        let boxed: Box<str> = "bar".into();
+        let ptr = boxed.as_ptr();
+        let sym = interner.get_or_intern_owned(boxed.into());
+        assert_eq!(interner.resolve(sym).map(str::as_ptr), Some(ptr));
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        let mut interner = DefaultStringInterner::new();
+        let mut owned = String::with_capacity(64);
+        owned.push_str("foo");
+        let sym = interner.get_or_intern_owned(owned);
+        interner.get_or_intern_static("bar");
+        interner.shrink_to_fit();
+        assert_eq!(interner.resolve(sym), Some("foo"));
+        assert_eq!(interner.get("bar"), Some(Sym::from_usize(1)));
+    }
+}
+
+mod get_or_intern_arc {
+    use super::*;
+    use crate::backend::ArcBackend;
+    use std::sync::Arc;
+
+    #[test]
+    fn shares_arc() {
+        let mut interner = StringInterner::<Sym, ArcBackend<Sym>>::new();
+        let shared: Arc<str> = Arc::from("shared");
+        let sym = interner.get_or_intern_arc(Arc::clone(&shared));
+        assert_eq!(Arc::strong_count(&shared), 2);
+        assert!(std::ptr::eq(interner.resolve(sym).unwrap(), &*shared));
+        assert_eq!(interner.get_or_intern_arc(Arc::from("shared")), sym);
+        assert_eq!(interner.get_or_intern("shared"), sym);
+        assert_eq!(Arc::strong_count(&shared), 2);
+    }
+
+    #[test]
+    fn other_backends_copy() {
+        let mut interner = DefaultStringInterner::new();
+        let shared: Arc<str> = Arc::from("shared");
+        let sym = interner.get_or_intern_arc(Arc::clone(&shared));
+        assert_eq!(Arc::strong_count(&shared), 1);
+        assert_eq!(interner.resolve(sym), Some("shared"));
+    }
+}
+
+mod resolve_arc {
+    use super::*;
+    use crate::backend::ArcBackend;
+    use std::{sync::Arc, thread};
+
+    #[test]
+    fn outlives_interner() {
+        let mut interner = StringInterner::<Sym, ArcBackend<Sym>>::new();
+        let foo = interner.get_or_intern("foo");
+        let resolved = interner.resolve_arc(foo).unwrap();
+        assert_eq!(interner.resolve_arc(Sym::from_usize(1)), None);
+        assert!(std::ptr::eq(&*resolved, interner.resolve(foo).unwrap()));
+        drop(interner);
+        let handle = thread::spawn(move || resolved.len());
+        assert_eq!(handle.join().unwrap(), 3);
+    }
+
+    #[test]
+    fn shares_with_get_or_intern_arc() {
+        let mut interner = StringInterner::<Sym, ArcBackend<Sym>>::new();
+        let shared: Arc<str> = Arc::from("shared");
+        let sym = interner.get_or_intern_arc(Arc::clone(&shared));
+        assert!(Arc::ptr_eq(&interner.resolve_arc(sym).unwrap(), &shared));
+    }
+}
+
+mod intern_display {
+    use super::*;
+    use crate::intern_fmt;
+
+    #[test]
+    fn macro_and_display() {
+        let mut interner = DefaultStringInterner::new();
+        let qualified = intern_fmt!(interner, "{}::{}", "std", "vec");
+        assert_eq!(interner.resolve(qualified), Some("std::vec"));
+        assert_eq!(interner.get("std::vec"), Some(qualified));
+        assert_eq!(intern_fmt!(interner, "std::{}", "vec"), qualified);
+        let num = interner.intern_display(&42);
+        assert_eq!(interner.resolve(num), Some("42"));
+        assert_eq!(interner.intern_display("42"), num);
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn reuses_scratch() {
+        let mut interner = DefaultStringInterner::new();
+        interner.intern_display(&"a very long string that needs an allocation");
+        let short = interner.intern_display(&1);
+        assert_eq!(interner.resolve(short), Some("1"));
+        assert!(interner.scratch.capacity() >= 43);
+    }
+}
+
+mod writer {
+    use super::*;
+    use std::fmt::Write;
+
+    #[test]
+    fn finish() {
+        let mut interner = DefaultStringInterner::new();
+        let (name, id) = ("mangled", 1);
+        let mut writer = interner.writer();
+        write!(writer, "{}_{}", name, id).unwrap();
+        writer.write_char('x').unwrap();
+        assert_eq!(writer.as_str(), "mangled_1x");
+        let sym = writer.finish();
+        assert_eq!(interner.resolve(sym), Some("mangled_1x"));
+        let mut writer = interner.writer();
+        assert_eq!(writer.as_str(), "");
+        writer.write_str("mangled_1x").unwrap();
+        assert_eq!(writer.finish(), sym);
+        assert_eq!(interner.len(), 1);
+    }
+
+    #[test]
+    fn dropped_writer() {
+        let mut interner = DefaultStringInterner::new();
+        {
+            let mut writer = interner.writer();
+            writer.write_str("discarded").unwrap();
+        }
+        assert!(interner.is_empty());
+        assert!(interner.scratch.capacity() >= 9);
+        let sym = interner.writer().finish();
+        assert_eq!(interner.resolve(sym), Some(""));
+    }
+}
+
+mod intern_concat {
+    use super::*;
+
+    #[test]
+    fn concat() {
+        let mut interner = DefaultStringInterner::new();
+        let foo = interner.get_or_intern("foo");
+        let bar = interner.get_or_intern("bar");
+        let foobar = interner.intern_concat(&[foo, bar]).unwrap();
+        assert_eq!(interner.resolve(foobar), Some("foobar"));
+        assert_eq!(interner.intern_concat(&[foo, bar]), Some(foobar));
+        assert_eq!(interner.intern_concat(&[foo]), Some(foo));
+        let empty = interner.intern_concat(&[]).unwrap();
+        assert_eq!(interner.resolve(empty), Some(""));
+    }
+
+    #[test]
+    fn join() {
+        let mut interner = DefaultStringInterner::new();
+        let parts = ["std", "collections", "HashMap"]
+            .iter()
+            .map(|s| interner.get_or_intern(s))
+            .collect::<Vec<_>>();
+        let path = interner.intern_join(&parts, "::").unwrap();
+        assert_eq!(interner.resolve(path), Some("std::collections::HashMap"));
+        assert_eq!(interner.intern_join(&parts[..1], "::"), Some(parts[0]));
+    }
+
+    #[test]
+    fn invalid_part() {
+        let mut interner = DefaultStringInterner::new();
+        let foo = interner.get_or_intern("foo");
+        assert_eq!(interner.intern_concat(&[foo, Sym::from_usize(1)]), None);
+        assert_eq!(interner.len(), 1);
+    }
+}
+
+mod get_or_intern_all {
+    use super::*;
+
+    #[test]
+    fn input_order() {
+        let mut interner = DefaultStringInterner::new();
+        let bar = interner.get_or_intern("bar");
+        let syms = interner.get_or_intern_all(vec!["foo", "bar", "baz", "foo"]);
+        assert_eq!(syms.len(), 4);
+        assert_eq!(syms[1], bar);
+        assert_eq!(syms[0], syms[3]);
+        assert_eq!(
+            syms.iter()
+                .map(|&sym| interner.resolve(sym).unwrap())
+                .collect::<Vec<_>>(),
+            vec!["foo", "bar", "baz", "foo"]
+        );
+        assert_eq!(interner.len(), 3);
+    }
+
+    #[test]
+    fn reserves() {
+        let mut interner = DefaultStringInterner::new();
+        let syms = interner.get_or_intern_all((0..100).map(|n| n.to_string()));
+        assert_eq!(syms, (0..100).map(Sym::from_usize).collect::<Vec<_>>());
+        assert!(interner.capacity() >= 100);
+        assert!(interner.get_or_intern_all(Vec::<String>::new()).is_empty());
+    }
+}
+
+mod from_collections {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn from_vec() {
+        let strings = vec![
+            String::from("foo"),
+            String::from("bar"),
+            String::from("foo"),
+        ];
+        let ptr = strings[1].as_ptr();
+        let interner = DefaultStringInterner::from(strings);
+        assert_eq!(
+            interner.iter_values().collect::<Vec<_>>(),
+            vec!["foo", "bar"]
+        );
+        assert_eq!(
+            interner.resolve(Sym::from_usize(1)).map(str::as_ptr),
+            Some(ptr)
+        );
+    }
+
+    #[test]
+    fn from_slice() {
+        let strings: &[&str] = &["foo", "bar", "foo", "baz"];
+        let interner = DefaultStringInterner::from(strings);
+        assert_eq!(
+            interner.iter_values().collect::<Vec<_>>(),
+            vec!["foo", "bar", "baz"]
+        );
+    }
+
+    #[test]
+    fn from_hash_set() {
+        let strings = vec!["foo", "bar", "baz"]
+            .into_iter()
+            .map(String::from)
+            .collect::<HashSet<_>>();
+        let order = strings.iter().cloned().collect::<Vec<_>>();
+        let interner = DefaultStringInterner::from(strings);
+        assert_eq!(
+            interner.iter_values().map(String::from).collect::<Vec<_>>(),
+            order
+        );
+    }
+}
+
+mod with_preloaded {
+    use super::*;
+
+    const KEYWORDS: &[&str] = &["fn", "let", "if"];
+
+    #[test]
+    fn preloaded_symbols() {
+        let mut interner = DefaultStringInterner::with_preloaded(KEYWORDS);
+        for (n, keyword) in KEYWORDS.iter().enumerate() {
+            let sym = interner.get_or_intern(keyword);
+            assert_eq!(sym, Sym::from_usize(n));
+            assert!(interner.is_preloaded(sym));
+        }
+        let ident = interner.get_or_intern("main");
+        assert!(!interner.is_preloaded(ident));
+        assert!(!DefaultStringInterner::new().is_preloaded(Sym::from_usize(0)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn duplicates() {
+        DefaultStringInterner::with_preloaded(&["fn", "fn"]);
+    }
+}
+
+mod symbols_macro {
+    use super::*;
+
+    mod kw {
+        crate::symbols! {
+            FN: "fn",
+            LET: "let",
+            IF: "if"
+        }
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(kw::FN, Sym::from_usize(0));
+        assert_eq!(kw::IF, Sym::from_usize(2));
+        assert_eq!(kw::SYMBOL_STRINGS, &["fn", "let", "if"]);
+        let mut interner = kw::preloaded_interner();
+        assert_eq!(interner.get_or_intern("let"), kw::LET);
+        assert_eq!(interner.resolve(kw::IF), Some("if"));
+        assert!(interner.is_preloaded(kw::IF));
+    }
+
+    #[test]
+    fn from_u32() {
+        const SYM: Sym = Sym::from_u32(42);
+        assert_eq!(SYM, Sym::from_usize(42));
+        assert_eq!(SYM.to_usize(), 42);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_u32_max() {
+        Sym::from_u32(u32::MAX);
+    }
+}
+
+mod static_interner {
+    use super::*;
+
+    mod ops {
+        crate::symbols! {
+            ADD: "add",
+            SUB: "sub",
+            MUL: "mul",
+        }
+    }
+
+    #[test]
+    fn get_and_resolve() {
+        let interner = &ops::STATIC_INTERNER;
+        assert_eq!(interner.get("sub"), Some(ops::SUB));
+        assert_eq!(interner.get("div"), None);
+        assert_eq!(interner.resolve(ops::MUL), Some("mul"));
+        assert_eq!(interner.resolve(Sym::from_usize(3)), None);
+        assert_eq!(interner.len(), 3);
+        assert_eq!(
+            interner.iter().collect::<Vec<_>>(),
+            vec![(ops::ADD, "add"), (ops::SUB, "sub"), (ops::MUL, "mul")]
+        );
+    }
+
+    #[test]
+    fn manual() {
+        static EMPTY: StaticStringInterner<Sym> = StaticStringInterner::new(&[], |_| None);
+        assert!(EMPTY.is_empty());
+        assert_eq!(EMPTY.get("add"), None);
+    }
+}
+
+mod freeze_perfect {
+    use super::*;
+
+    #[test]
+    fn get_and_resolve() {
+        let interner = (0..1000)
+            .map(|n| format!("ident_{}", n))
+            .collect::<DefaultStringInterner>();
+        let expected = interner
+            .iter()
+            .map(|(sym, s)| (sym, s.to_owned()))
+            .collect::<Vec<_>>();
+        let perfect = interner.freeze_perfect();
+        assert_eq!(perfect.len(), 1000);
+        for (sym, s) in expected {
+            assert_eq!(perfect.get(&s), Some(sym));
+            assert_eq!(perfect.resolve(sym), Some(s.as_str()));
+        }
+        assert_eq!(perfect.get("ident_1000"), None);
+        assert_eq!(perfect.resolve(Sym::from_usize(1000)), None);
+    }
+
+    #[test]
+    fn small() {
+        let perfect = DefaultStringInterner::new().freeze_perfect();
+        assert!(perfect.is_empty());
+        assert_eq!(perfect.get(""), None);
+        let perfect = vec![""]
+            .into_iter()
+            .collect::<DefaultStringInterner>()
+            .freeze_perfect();
+        assert_eq!(perfect.get(""), Some(Sym::from_usize(0)));
+        assert_eq!(perfect.get("a"), None);
+    }
+
+    #[test]
+    fn removed() {
+        let mut interner = vec!["", "foo", "bar"]
+            .into_iter()
+            .collect::<DefaultStringInterner>();
+        interner.remove_str("");
+        interner.remove_str("foo");
+        let perfect = interner.freeze_perfect();
+        assert_eq!(perfect.len(), 1);
+        assert_eq!(perfect.get(""), None);
+        assert_eq!(perfect.get("foo"), None);
+        assert_eq!(perfect.get("bar"), Some(Sym::from_usize(2)));
+        assert_eq!(perfect.resolve(Sym::from_usize(0)), None);
+        assert_eq!(perfect.resolve(Sym::from_usize(1)), None);
+    }
+}
+
+mod remove {
+    use super::*;
+    use crate::backend::{ArcBackend, Backend, BucketBackend, BufferBackend, DefaultBackend};
+
+    fn remove_with<B>()
+    where
+        B: Backend<Sym>,
+    {
+        let mut interner = StringInterner::<Sym, B>::new();
+        let foo = interner.get_or_intern("foo");
+        let bar = interner.get_or_intern("bar");
+        let baz = interner.get_or_intern("baz");
+        assert_eq!(interner.remove(bar), Some(String::from("bar")));
+        assert_eq!(interner.remove(bar), None);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.resolve(bar), None);
+        assert_eq!(interner.resolve(baz), Some("baz"));
+        assert_eq!(interner.get("bar"), None);
+        assert_eq!(interner.get("foo"), Some(foo));
+        let new_bar = interner.get_or_intern("bar");
+        assert_ne!(new_bar, bar);
+        assert_eq!(interner.remove_str("foo"), Some(foo));
+        assert_eq!(interner.remove_str("foo"), None);
+        assert_eq!(
+            interner.iter().collect::<Vec<_>>(),
+            vec![(baz, "baz"), (new_bar, "bar")]
+        );
+        assert_eq!(interner.iter().size_hint(), (2, Some(2)));
+        assert_eq!(
+            interner.into_iter().collect::<Vec<_>>(),
+            vec![(baz, String::from("baz")), (new_bar, String::from("bar"))]
+        );
+    }
+
+    #[test]
+    fn backends() {
+        remove_with::<DefaultBackend<Sym>>();
+        remove_with::<ArcBackend<Sym>>();
+        remove_with::<BucketBackend<Sym>>();
+        remove_with::<BufferBackend<Sym>>();
+    }
+
+    #[test]
+    fn hash_collisions() {
+        let mut interner =
+            StringInterner::<Sym, DefaultBackend<Sym>, _>::with_hasher(CollidingState);
+        let foo = interner.get_or_intern("foo");
+        let bar = interner.get_or_intern("bar");
+        let baz = interner.get_or_intern("baz");
+        interner.remove(foo);
+        assert_eq!(interner.get("foo"), None);
+        assert_eq!(interner.get("bar"), Some(bar));
+        assert_eq!(interner.get("baz"), Some(baz));
+        interner.remove(baz);
+        assert_eq!(interner.get("bar"), Some(bar));
+        assert_eq!(interner.get("baz"), None);
+        interner.remove(bar);
+        assert!(interner.is_empty());
+        assert_eq!(interner.get_or_intern("foo"), Sym::from_usize(3));
+    }
+
+    #[test]
+    fn freeze_keeps_symbols() {
+        let mut interner = vec!["foo", "bar", "baz"]
+            .into_iter()
+            .collect::<DefaultStringInterner>();
+        let bar = interner.remove_str("bar").unwrap();
+        let baz = interner.get("baz").unwrap();
+        assert_eq!(interner.snapshot().resolve(baz), Some("baz"));
+        assert_eq!(
+            interner.freeze_front_coded().resolve(baz).as_deref(),
+            Some("baz")
+        );
+        let frozen = interner.clone().freeze();
+        assert_eq!(frozen.get("baz"), Some(baz));
+        assert_eq!(frozen.get(""), None);
+        let perfect = interner.freeze_perfect();
+        assert_eq!(perfect.get("baz"), Some(baz));
+        assert_eq!(perfect.get("bar"), None);
+        assert_eq!(perfect.resolve(bar), None);
+    }
+}
+
+mod retain {
+    use super::*;
+
+    #[test]
+    fn predicate() {
+        let mut interner = (0..10)
+            .map(|n| n.to_string())
+            .collect::<DefaultStringInterner>();
+        interner.retain(|sym, s| sym.to_usize() % 2 == 0 && s != "4");
+        assert_eq!(
+            interner.iter_values().collect::<Vec<_>>(),
+            vec!["0", "2", "6", "8"]
+        );
+        assert_eq!(interner.get("6"), Some(Sym::from_usize(6)));
+        assert_eq!(interner.get("4"), None);
+        interner.retain(|_, _| false);
+        assert!(interner.is_empty());
+    }
+}
+
+mod drain {
+    use super::*;
+
+    #[test]
+    fn empties_interner() {
+        let mut interner = vec!["foo", "bar", "baz"]
+            .into_iter()
+            .collect::<DefaultStringInterner>();
+        interner.remove_str("bar");
+        let capacity = interner.capacity();
+        let drained = interner.drain().collect::<Vec<_>>();
+        assert_eq!(
+            drained,
+            vec![
+                (Sym::from_usize(0), String::from("foo")),
+                (Sym::from_usize(2), String::from("baz"))
+            ]
+        );
+        assert!(interner.is_empty());
+        assert_eq!(interner.get("foo"), None);
+        assert!(interner.capacity() >= capacity);
+        assert_eq!(interner.get_or_intern("baz"), Sym::from_usize(0));
+    }
+}
+
+mod pop {
+    use super::*;
+    use crate::backend::{ArcBackend, Backend, BucketBackend, BufferBackend, SimpleBackend};
+
+    fn pop_reuses_symbol<B>()
+    where
+        B: Backend<Sym>,
+    {
+        let mut interner = StringInterner::<Sym, B>::new();
+        let foo = interner.get_or_intern("foo");
+        let bar = interner.get_or_intern("bar");
+        let baz = interner.get_or_intern("baz");
+        interner.remove(baz);
+        assert_eq!(interner.pop(), Some((bar, String::from("bar"))));
+        assert_eq!(interner.len(), 1);
+        assert_eq!(interner.get("bar"), None);
+        assert_eq!(interner.get_or_intern("qux"), bar);
+        assert_eq!(interner.resolve(bar), Some("qux"));
+        assert_eq!(interner.resolve(foo), Some("foo"));
+        assert_eq!(interner.pop(), Some((bar, String::from("qux"))));
+        assert_eq!(interner.pop(), Some((foo, String::from("foo"))));
+        assert_eq!(interner.pop(), None);
+        assert!(interner.is_empty());
+    }
+
+    #[test]
+    fn all_backends() {
+        pop_reuses_symbol::<SimpleBackend<Sym>>();
+        pop_reuses_symbol::<BucketBackend<Sym>>();
+        pop_reuses_symbol::<BufferBackend<Sym>>();
+        pop_reuses_symbol::<ArcBackend<Sym>>();
+    }
+}
+
+mod truncate {
+    use super::*;
+    use crate::backend::DefaultBackend;
+
+    #[test]
+    fn drops_trailing_symbols() {
+        let mut interner = vec!["a", "b", "c", "d"]
+            .into_iter()
+            .collect::<DefaultStringInterner>();
+        interner.remove_str("c");
+        interner.truncate(1);
+        assert_eq!(interner.len(), 1);
+        assert_eq!(interner.get("b"), None);
+        assert_eq!(interner.get("d"), None);
+        assert_eq!(interner.resolve(Sym::from_usize(1)), None);
+        assert_eq!(interner.get_or_intern("d"), Sym::from_usize(1));
+        interner.truncate(10);
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn colliding_hashes() {
+        let mut interner =
+            StringInterner::<Sym, DefaultBackend<Sym>, _>::with_hasher(CollidingState);
+        let a = interner.get_or_intern("a");
+        interner.get_or_intern("b");
+        interner.truncate(1);
+        assert_eq!(interner.get("a"), Some(a));
+        assert_eq!(interner.get("b"), None);
+        assert_eq!(interner.get_or_intern("c"), Sym::from_usize(1));
+    }
+}
+
+mod checkpoint {
+    use super::*;
+
+    #[test]
+    fn rollback_discards_newer_symbols() {
+        let mut interner = DefaultStringInterner::new();
+        let foo = interner.get_or_intern("foo");
+        let checkpoint = interner.checkpoint();
+        interner.get_or_intern("bar");
+        assert_eq!(interner.get_or_intern("foo"), foo);
+        interner.rollback(checkpoint);
+        assert_eq!(interner.len(), 1);
+        assert_eq!(interner.get("bar"), None);
+        assert_eq!(interner.resolve(foo), Some("foo"));
+        assert_eq!(interner.checkpoint(), checkpoint);
+        interner.get_or_intern("baz");
+        interner.rollback(checkpoint);
+        interner.rollback(checkpoint);
+        assert_eq!(interner.len(), 1);
+    }
+}
+
+mod compact {
+    use super::*;
+
+    #[test]
+    fn renumbers_densely() {
+        let mut interner = StringInterner::<Sym>::with_preloaded(&["a", "b"]);
+        let c = interner.get_or_intern("c");
+        let d = interner.get_or_intern("d");
+        interner.remove_str("a");
+        interner.remove(c);
+        let remap = interner.compact();
+        assert_eq!(remap.len(), 4);
+        assert_eq!(remap.get(Sym::from_usize(0)), None);
+        assert_eq!(remap.get(c), None);
+        assert_eq!(remap.get(d), Some(Sym::from_usize(1)));
+        assert_eq!(
+            remap.iter().collect::<Vec<_>>(),
+            vec![
+                (Sym::from_usize(1), Sym::from_usize(0)),
+                (d, Sym::from_usize(1))
+            ]
+        );
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.get("b"), Some(Sym::from_usize(0)));
+        assert_eq!(interner.get("d"), Some(Sym::from_usize(1)));
+        assert_eq!(interner.resolve(Sym::from_usize(1)), Some("d"));
+        assert!(interner.is_preloaded(Sym::from_usize(0)));
+        assert!(!interner.is_preloaded(Sym::from_usize(1)));
+        assert_eq!(interner.get_or_intern("e"), Sym::from_usize(2));
+    }
+
+    #[test]
+    fn colliding_hashes() {
+        let mut interner =
+            StringInterner::<Sym, crate::backend::DefaultBackend<Sym>, _>::with_hasher(
+                CollidingState,
+            );
+        interner.get_or_intern("a");
+        interner.get_or_intern("b");
+        interner.get_or_intern("c");
+        interner.remove_str("a");
+        interner.compact();
+        assert_eq!(interner.get("b"), Some(Sym::from_usize(0)));
+        assert_eq!(interner.get("c"), Some(Sym::from_usize(1)));
+    }
+}
+
+mod split_off {
+    use super::*;
+
+    #[test]
+    fn moves_trailing_symbols() {
+        let mut interner = vec!["a", "b", "c", "d"]
+            .into_iter()
+            .collect::<DefaultStringInterner>();
+        interner.remove_str("c");
+        let b = interner.get("b").unwrap();
+        let d = interner.get("d").unwrap();
+        let (other, remap) = interner.split_off(b);
+        assert_eq!(interner.len(), 1);
+        assert_eq!(interner.get("b"), None);
+        assert_eq!(interner.get_or_intern("e"), b);
+        assert_eq!(other.len(), 2);
+        assert_eq!(remap.get(Sym::from_usize(0)), None);
+        assert_eq!(remap.get(b), Some(Sym::from_usize(0)));
+        assert_eq!(remap.get(d), Some(Sym::from_usize(1)));
+        assert_eq!(other.resolve(remap.get(d).unwrap()), Some("d"));
+        assert_eq!(other.get("b"), Some(Sym::from_usize(0)));
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let mut interner = vec!["a"].into_iter().collect::<DefaultStringInterner>();
+        let (other, remap) = interner.split_off(Sym::from_usize(5));
+        assert!(other.is_empty());
+        assert_eq!(remap.iter().count(), 0);
+        assert_eq!(interner.len(), 1);
+    }
+}
+
+mod merge {
+    use super::*;
+    use crate::backend::BucketBackend;
+
+    #[test]
+    fn remaps_other_symbols() {
+        let mut interner = vec!["a", "b"]
+            .into_iter()
+            .collect::<DefaultStringInterner>();
+        let mut other = vec!["c", "b", "x"]
+            .into_iter()
+            .collect::<StringInterner<Sym, BucketBackend<Sym>>>();
+        other.remove_str("x");
+        let remap = interner.merge(&other);
+        assert_eq!(remap.len(), 3);
+        assert_eq!(remap.get(Sym::from_usize(0)), Some(Sym::from_usize(2)));
+        assert_eq!(remap.get(Sym::from_usize(1)), Some(Sym::from_usize(1)));
+        assert_eq!(remap.get(Sym::from_usize(2)), None);
+        assert_eq!(interner.len(), 3);
+        assert_eq!(interner.get("x"), None);
+        for (old, new) in remap.iter() {
+            assert_eq!(interner.resolve(new), other.resolve(old));
+        }
+    }
+}
+
+mod diff {
+    use super::*;
+
+    #[test]
+    fn reports_differences() {
+        let old = vec!["a", "b", "c"]
+            .into_iter()
+            .collect::<DefaultStringInterner>();
+        let new = vec!["c", "d", "a"]
+            .into_iter()
+            .collect::<DefaultStringInterner>();
+        let diff = old.diff(&new);
+        assert!(!diff.is_empty());
+        assert_eq!(diff.only_in_self(), &[(Sym::from_usize(1), "b")]);
+        assert_eq!(diff.only_in_other(), &[(Sym::from_usize(1), "d")]);
+        assert_eq!(
+            diff.shared(),
+            &[
+                ("a", Sym::from_usize(0), Sym::from_usize(2)),
+                ("c", Sym::from_usize(2), Sym::from_usize(0))
+            ]
+        );
+        assert!(old.diff(&old.clone()).is_empty());
+    }
+}
+
+mod set_operations {
+    use super::*;
+
+    fn interners() -> (DefaultStringInterner, DefaultStringInterner) {
+        (
+            vec!["a", "b", "c"].into_iter().collect(),
+            vec!["c", "d", "a"].into_iter().collect(),
+        )
+    }
+
+    #[test]
+    fn union() {
+        let (lhs, rhs) = interners();
+        let union = lhs.union(&rhs);
+        assert_eq!(union.len(), 4);
+        for (sym, string) in lhs.iter() {
+            assert_eq!(union.resolve(sym), Some(string));
+        }
+        assert_eq!(union.get("d"), Some(Sym::from_usize(3)));
+    }
+
+    #[test]
+    fn intersection() {
+        let (lhs, rhs) = interners();
+        assert_eq!(
+            lhs.intersection(&rhs).collect::<Vec<_>>(),
+            vec![(Sym::from_usize(0), "a"), (Sym::from_usize(2), "c")]
+        );
+    }
+
+    #[test]
+    fn difference() {
+        let (lhs, rhs) = interners();
+        assert_eq!(
+            lhs.difference(&rhs).collect::<Vec<_>>(),
+            vec![(Sym::from_usize(1), "b")]
+        );
+        assert_eq!(
+            rhs.difference(&lhs).collect::<Vec<_>>(),
+            vec![(Sym::from_usize(1), "d")]
+        );
+    }
+}
+
+mod iter_from {
+    use super::*;
+
+    #[test]
+    fn yields_newer_strings() {
+        let mut interner = vec!["a", "b", "c", "d"]
+            .into_iter()
+            .collect::<DefaultStringInterner>();
+        interner.remove_str("c");
+        let iter = interner.iter_from(Sym::from_usize(1));
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(
+            iter.collect::<Vec<_>>(),
+            vec![(Sym::from_usize(1), "b"), (Sym::from_usize(3), "d")]
+        );
+        assert_eq!(interner.iter_from(Sym::from_usize(10)).count(), 0);
+    }
+
+    #[test]
+    fn symbols_after_checkpoint() {
+        let mut interner = DefaultStringInterner::new();
+        interner.get_or_intern("a");
+        let checkpoint = interner.checkpoint();
+        assert_eq!(interner.symbols_after(checkpoint).count(), 0);
+        let b = interner.get_or_intern("b");
+        assert_eq!(
+            interner.symbols_after(checkpoint).collect::<Vec<_>>(),
+            vec![(b, "b")]
+        );
+    }
+}
+
+mod gc {
+    use super::*;
+
+    #[test]
+    fn sweeps_unmarked_strings() {
+        let mut interner = StringInterner::<Sym>::with_preloaded(&["fn"]);
+        let a = interner.get_or_intern("a");
+        let b = interner.get_or_intern("b");
+        let live = vec![b];
+        let swept = interner.gc(|marker| {
+            for &sym in &live {
+                marker.mark(sym);
+            }
+            marker.mark(Sym::from_usize(100));
+        });
+        assert_eq!(swept, 1);
+        assert_eq!(interner.resolve(a), None);
+        assert_eq!(interner.resolve(b), Some("b"));
+        assert_eq!(interner.get("fn"), Some(Sym::from_usize(0)));
+        assert_eq!(interner.gc(|_| {}), 1);
+        assert_eq!(interner.len(), 1);
+    }
+}
+
+mod rc_interner {
+    use super::*;
+
+    #[test]
+    fn reclaims_released_strings() {
+        let interner = RcInterner::<Sym>::new();
+        let foo = interner.get_or_intern("foo");
+        let bar = interner.get_or_intern("bar");
+        let foo2 = interner.get_or_intern("foo");
+        assert_eq!(foo, foo2);
+        assert_eq!(&*foo, "foo");
+        assert_eq!(interner.ref_count(foo.symbol()), 2);
+        assert_eq!(interner.len(), 2);
+        let bar_sym = bar.symbol();
+        drop(bar);
+        assert_eq!(interner.len(), 1);
+        assert_eq!(interner.ref_count(bar_sym), 0);
+        assert!(interner.get("bar").is_none());
+        let baz = interner.get_or_intern("baz");
+        assert_eq!(baz.symbol(), bar_sym);
+        assert_eq!(baz.as_str(), "baz");
+        drop(foo);
+        let cloned = foo2.clone();
+        drop(foo2);
+        assert_eq!(interner.get("foo"), Some(cloned));
+    }
+}
+
+mod lru {
+    use super::*;
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut interner = LruStringInterner::<Sym>::with_max_entries(2);
+        let a = interner.get_or_intern("a");
+        let b = interner.get_or_intern("b");
+        assert_eq!(interner.get("a"), Some(a));
+        let c = interner.get_or_intern("c");
+        assert_eq!(interner.len(), 2);
+        assert!(interner.is_live(a));
+        assert!(!interner.is_live(b));
+        assert_eq!(interner.get("b"), None);
+        assert_eq!(interner.resolve(c), Some("c"));
+        let b2 = interner.get_or_intern("b");
+        assert_ne!(b, b2);
+        assert!(!interner.is_live(a));
+        assert_eq!(interner.get_or_intern("c"), c);
+    }
+
+    #[test]
+    fn bounded_storage() {
+        let mut interner = LruStringInterner::<Sym>::with_max_entries(10);
+        let first = interner.get_or_intern("0");
+        let mut recent = Vec::new();
+        for n in 0..10_000 {
+            let sym = interner.get_or_intern(n.to_string());
+            assert!(interner.slots() <= 20);
+            recent.push(sym);
+        }
+        assert_eq!(interner.len(), 10);
+        assert!(!interner.is_live(first));
+        for (n, &sym) in recent.iter().enumerate().skip(9_990) {
+            assert_eq!(interner.resolve(sym), Some(n.to_string().as_str()));
+            assert_eq!(interner.get(n.to_string()), Some(sym));
+        }
+        assert_eq!(interner.resolve(recent[9_989]), None);
+        // Symbols of evicted strings are never handed out again.
+        assert!(recent.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_entries() {
+        LruStringInterner::<Sym>::with_max_entries(0);
+    }
+}
+
+mod max_bytes {
+    use super::*;
+
+    #[test]
+    fn try_get_or_intern_respects_budget() {
+        let mut interner = DefaultStringInterner::with_max_bytes(6);
+        assert_eq!(interner.max_bytes(), Some(6));
+        let foo = interner.try_get_or_intern("foo").unwrap();
+        assert_eq!(interner.try_get_or_intern("foo"), Ok(foo));
+        assert_eq!(interner.try_get_or_intern("long"), Err(InternerFull));
+        assert!(interner.try_get_or_intern("bar").is_ok());
+        assert_eq!(interner.total_bytes(), 6);
+        assert_eq!(interner.try_get_or_intern(""), Ok(Sym::from_usize(2)));
+        interner.remove(foo);
+        assert_eq!(interner.total_bytes(), 3);
+        assert!(interner.try_get_or_intern("baz").is_ok());
+    }
+
+    #[test]
+    fn total_bytes() {
+        let mut interner = DefaultStringInterner::new();
+        assert_eq!(interner.max_bytes(), None);
+        interner.get_or_intern("foo");
+        interner.get_or_intern_owned(String::from("ab"));
+        interner.get_or_intern("foo");
+        assert_eq!(interner.total_bytes(), 5);
+        interner.truncate(1);
+        assert_eq!(interner.total_bytes(), 3);
+        interner.drain();
+        assert_eq!(interner.total_bytes(), 0);
+    }
+}
+
+mod try_reserve {
+    use super::*;
+
+    #[test]
+    fn reserves_capacity() {
+        let mut interner = DefaultStringInterner::new();
+        interner.get_or_intern("foo");
+        assert!(interner.try_reserve(100).is_ok());
+        assert!(interner.capacity() >= 101);
+        assert!(interner.try_reserve(usize::MAX).is_err());
+        assert_eq!(interner.get("foo"), Some(Sym::from_usize(0)));
+    }
+}
+
+mod memory_usage {
+    use super::*;
+    use crate::backend::{ArcBackend, Backend, BucketBackend, BufferBackend, SimpleBackend};
+
+    fn grows_with_contents<B>()
+    where
+        B: Backend<Sym>,
+    {
+        let mut interner = StringInterner::<Sym, B>::new();
+        let empty = interner.memory_usage();
+        assert_eq!(empty.total(), 0);
+        for n in 0..100 {
+            interner.get_or_intern(n.to_string());
+        }
+        let usage = interner.memory_usage();
+        assert!(usage.map() >= 100 * std::mem::size_of::<(u32, Sym)>());
+        assert!(usage.values() >= 100);
+        assert!(usage.strings() >= 190);
+        assert_eq!(usage.auxiliary(), 0);
+        assert_eq!(
+            usage.total(),
+            usage.map() + usage.values() + usage.strings()
+        );
+    }
+
+    #[test]
+    fn all_backends() {
+        grows_with_contents::<SimpleBackend<Sym>>();
+        grows_with_contents::<BucketBackend<Sym>>();
+        grows_with_contents::<BufferBackend<Sym>>();
+        grows_with_contents::<ArcBackend<Sym>>();
+    }
+
+    #[test]
+    fn auxiliary_structures() {
+        let mut interner = DefaultStringInterner::new();
+        for n in 0..100 {
+            interner.get_or_intern(format!("Ident{}", n));
+        }
+        let before = interner.memory_usage();
+        interner.enable_bloom_filter();
+        let bloom = interner.memory_usage();
+        assert_eq!(bloom.map(), before.map());
+        assert!(bloom.auxiliary() > before.auxiliary());
+        interner.get_or_intern_ignore_ascii_case("ident0");
+        let ignore_case = interner.memory_usage();
+        assert!(ignore_case.map() >= before.map() + 100 * std::mem::size_of::<(u32, Sym)>());
+        interner.get_or_intern_folded("Ident99");
+        let folded = interner.memory_usage();
+        assert!(folded.auxiliary() >= ignore_case.auxiliary() + 100 * std::mem::size_of::<Sym>());
+        let parts = [
+            interner.get_or_intern("Ident0"),
+            interner.get_or_intern("Ident1"),
+        ];
+        interner.intern_join(&parts, "_");
+        let usage = interner.memory_usage();
+        assert!(usage.auxiliary() > folded.auxiliary());
+        assert_eq!(
+            usage.total(),
+            usage.map() + usage.values() + usage.strings() + usage.auxiliary()
+        );
+    }
+
+    #[test]
+    fn static_strings_are_free() {
+        let mut interner = DefaultStringInterner::new();
+        interner.get_or_intern_static("foo");
+        assert_eq!(interner.memory_usage().strings(), 0);
+    }
+}
+
+mod stats {
+    use super::*;
+
+    #[test]
+    fn summarizes_contents() {
+        let empty = DefaultStringInterner::new().stats();
+        assert!(empty.is_empty());
+        assert_eq!(empty.average_len(), 0.0);
+        assert_eq!(empty.load_factor(), 0.0);
+        let interner = vec!["a", "bcd", "ef", "a"]
+            .into_iter()
+            .collect::<DefaultStringInterner>();
+        let stats = interner.stats();
+        assert_eq!(stats.len(), 3);
+        assert_eq!(stats.total_bytes(), 6);
+        assert_eq!(stats.average_len(), 2.0);
+        assert_eq!(stats.max_len(), 3);
+        assert!(stats.load_factor() > 0.0 && stats.load_factor() <= 1.0);
+    }
+}
+
+mod metrics {
+    use super::*;
+    use crate::{AtomicMetrics, MetricsRecorder};
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn records_lookups_and_size() {
+        let mut interner = DefaultStringInterner::new();
+        interner.get_or_intern("foo");
+        let metrics = Arc::new(AtomicMetrics::new());
+        interner.set_metrics(metrics.clone());
+        assert_eq!((metrics.len(), metrics.bytes()), (1, 3));
+        assert_eq!(metrics.hit_ratio(), 0.0);
+        for word in "foo bar foo baz".split(' ') {
+            interner.get_or_intern(word);
+        }
+        assert_eq!(interner.get("qux"), None);
+        assert_eq!((metrics.hits(), metrics.misses()), (2, 3));
+        assert_eq!(metrics.hit_ratio(), 0.4);
+        assert_eq!((metrics.len(), metrics.bytes()), (3, 9));
+        interner.remove_str("bar");
+        assert_eq!((metrics.len(), metrics.bytes()), (2, 6));
+        interner.pop();
+        assert_eq!((metrics.len(), metrics.bytes()), (1, 3));
+        interner.clone().drain();
+        assert!(metrics.is_empty());
+        interner.disable_metrics();
+        interner.get_or_intern("bar");
+        assert_eq!((metrics.len(), metrics.misses()), (0, 3));
+    }
+
+    #[test]
+    fn custom_recorder() {
+        #[derive(Default)]
+        struct Sizes(Mutex<Vec<usize>>);
+
+        impl MetricsRecorder for Sizes {
+            fn record_size(&self, len: usize, _bytes: usize) {
+                self.0.lock().unwrap().push(len);
+            }
+        }
+
+        let sizes = Arc::new(Sizes::default());
+        let mut interner = DefaultStringInterner::new();
+        interner.set_metrics(sizes.clone());
+        interner.extend(vec!["a", "b", "a"]);
+        assert_eq!(*sizes.0.lock().unwrap(), vec![0, 1, 2]);
+    }
+}
+
+mod trace {
+    use super::*;
+    use crate::{BulkOperation, Tracer};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct Events(Mutex<Vec<String>>);
+
+    impl Tracer for Events {
+        fn enter(&self, operation: BulkOperation) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("enter {:?}", operation));
+        }
+
+        fn exit(&self, operation: BulkOperation) {
+            self.0.lock().unwrap().push(format!("exit {:?}", operation));
+        }
+
+        fn hash_collision(&self, string: &str) {
+            self.0.lock().unwrap().push(format!("collision {}", string));
+        }
+    }
+
+    impl Events {
+        fn take(&self) -> Vec<String> {
+            std::mem::take(&mut *self.0.lock().unwrap())
+        }
+    }
+
+    #[test]
+    fn bulk_operations() {
+        let events = Arc::new(Events::default());
+        let mut interner = DefaultStringInterner::new();
+        interner.set_tracer(events.clone());
+        let other = vec!["foo", "bar"]
+            .into_iter()
+            .collect::<DefaultStringInterner>();
+        interner.merge(&other);
+        let foo = interner.get("foo").unwrap();
+        interner.gc(|marker| marker.mark(foo));
+        interner.compact();
+        assert_eq!(
+            events.take(),
+            vec![
+                "enter Merge",
+                "exit Merge",
+                "enter Gc",
+                "exit Gc",
+                "enter Compact",
+                "exit Compact"
+            ]
+        );
+        interner.disable_tracer();
+        interner.compact();
+        assert!(events.take().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "serde_support")]
+    fn serialize() {
+        let events = Arc::new(Events::default());
+        let mut interner = DefaultStringInterner::new();
+        interner.set_tracer(events.clone());
+        interner.get_or_intern("foo");
+        assert_eq!(serde_json::to_string(&interner).unwrap(), r#"["foo"]"#);
+        assert_eq!(events.take(), vec!["enter Serialize", "exit Serialize"]);
+    }
+
+    #[test]
+    fn hash_collisions() {
+        let events = Arc::new(Events::default());
+        let mut interner =
+            StringInterner::<Sym, crate::backend::DefaultBackend<Sym>, _>::with_hasher(
+                CollidingState,
+            );
+        interner.get_or_intern("foo");
+        interner.set_tracer(events.clone());
+        interner.get_or_intern("bar");
+        interner.get_or_intern("foo");
+        assert_eq!(events.take(), vec!["collision bar"]);
+    }
+}
+
+mod counting {
+    use super::*;
+
+    #[test]
+    fn counts_interning() {
+        let mut interner = CountingStringInterner::<Sym>::new();
+        for word in "the cat and the dog and the bird".split(' ') {
+            interner.get_or_intern(word);
+        }
+        let the = interner.get("the").unwrap();
+        assert_eq!(interner.count(the), 3);
+        assert_eq!(interner.count(Sym::from_usize(100)), 0);
+        assert_eq!(interner.len(), 5);
+        assert_eq!(
+            interner.most_common(3),
+            vec![
+                (the, "the", 3),
+                (interner.get("and").unwrap(), "and", 2),
+                (interner.get("cat").unwrap(), "cat", 1)
+            ]
+        );
+        assert_eq!(interner.most_common(10).len(), 5);
+        assert_eq!(interner.into_inner().resolve(the), Some("the"));
+    }
+}
+
+mod on_intern {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn invoked_for_new_strings() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut interner = DefaultStringInterner::new();
+        interner.get_or_intern("before");
+        let hook_log = Arc::clone(&log);
+        interner.on_intern(move |sym, s| hook_log.lock().unwrap().push((sym, s.to_owned())));
+        let foo = interner.get_or_intern("foo");
+        interner.get_or_intern("foo");
+        let bar = interner.get_or_intern_owned(String::from("bar"));
+        interner.get_or_intern("before");
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![(foo, String::from("foo")), (bar, String::from("bar"))]
+        );
+    }
+}
+
+mod single_hash {
+    use super::*;
+    use crate::backend::DefaultBackend;
+    use std::{cell::Cell, collections::hash_map::DefaultHasher, hash::BuildHasher, rc::Rc};
+
+    /// A `BuildHasher` that counts how many hashers it has built.
+    #[derive(Default, Clone)]
+    struct CountingState(Rc<Cell<usize>>);
+
+    impl BuildHasher for CountingState {
+        type Hasher = DefaultHasher;
+
+        fn build_hasher(&self) -> DefaultHasher {
+            self.0.set(self.0.get() + 1);
+            DefaultHasher::new()
+        }
+    }
+
+    #[test]
+    fn hashes_once_per_operation() {
+        let state = CountingState::default();
+        let hashes = Rc::clone(&state.0);
+        let mut interner = StringInterner::<Sym, DefaultBackend<Sym>, _>::with_hasher(state);
+        interner.get_or_intern("foo");
+        assert_eq!(hashes.get(), 1);
+        interner.get_or_intern("foo");
+        assert_eq!(hashes.get(), 2);
+        interner.get_or_intern_owned(String::from("bar"));
+        assert_eq!(hashes.get(), 3);
+        interner.get("bar");
+        assert_eq!(hashes.get(), 4);
+    }
+}
+
+mod hashed {
+    use super::*;
+    use std::hash::BuildHasher;
+
+    #[test]
+    fn precomputed_hashes() {
+        let mut interner = DefaultStringInterner::new();
+        let hash = interner.hasher().hash_one("foo");
+        assert_eq!(interner.get_hashed(hash, "foo"), None);
+        let foo = interner.get_or_intern_hashed(hash, "foo");
+        assert_eq!(interner.get_or_intern_hashed(hash, "foo"), foo);
+        assert_eq!(interner.get_hashed(hash, "foo"), Some(foo));
+        assert_eq!(interner.get("foo"), Some(foo));
+        assert_eq!(interner.get_or_intern("foo"), foo);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn mismatching_hash() {
+        let mut interner = DefaultStringInterner::new();
+        let hash = interner.hasher().hash_one("foo");
+        interner.get_or_intern_hashed(hash, "bar");
+    }
+}
+
+mod fx_hasher {
+    use super::*;
+    use std::hash::{BuildHasher, Hasher};
+
+    #[test]
+    fn deterministic() {
+        let state = crate::FxBuildHasher::default();
+        assert_eq!(state.hash_one("identifier"), state.hash_one("identifier"));
+        assert_ne!(state.hash_one("identifier"), state.hash_one("identifies"));
+        let mut hasher = FxHasher::default();
+        hasher.write(b"");
+        assert_eq!(hasher.finish(), 0);
+    }
+
+    #[test]
+    fn fx_string_interner() {
+        let mut interner = FxStringInterner::with_hasher(Default::default());
+        let foo = interner.get_or_intern("foo");
+        assert_eq!(interner.get("foo"), Some(foo));
+        assert_eq!(interner.resolve(foo), Some("foo"));
+    }
+}
+
+mod map_entries {
+    use super::*;
+
+    #[test]
+    fn small_entries() {
+        let interner = (0..1000)
+            .map(|n| n.to_string())
+            .collect::<DefaultStringInterner>();
+        let usage = interner.memory_usage();
+        // One entry of 8 bytes without `debug-validate` and one control byte per slot.
+        let slot = std::mem::size_of::<(u32, Sym)>() + 1;
+        assert!(usage.map() <= interner.capacity() * 2 * slot);
+        for n in 0..1000 {
+            assert_eq!(interner.get(n.to_string()), Some(Sym::from_usize(n)));
+        }
+    }
+}
+
+mod str_eq {
+    use crate::eq::str_eq;
+
+    #[test]
+    fn matches_slice_equality() {
+        let base = "abcdefghijklmnopqrstuvwxyz0123456789".repeat(3);
+        for len in 0..base.len() {
+            let lhs = &base[..len];
+            assert!(str_eq(lhs, lhs));
+            assert!(str_eq(lhs, &String::from(lhs)));
+            assert!(!str_eq(lhs, &base[..len + 1]));
+            for pos in 0..len {
+                let mut rhs = String::from(lhs).into_bytes();
+                rhs[pos] = b'!';
+                let rhs = String::from_utf8(rhs).unwrap();
+                assert!(!str_eq(lhs, &rhs));
+            }
+        }
+    }
+
+    #[test]
+    fn long_colliding_strings() {
+        let mut interner = crate::StringInterner::<
+            crate::Sym,
+            crate::backend::DefaultBackend<crate::Sym>,
+            _,
+        >::with_hasher(super::CollidingState);
+        let prefix = "/usr/share/very/long/path/to/some/file".repeat(4);
+        let a = interner.get_or_intern(format!("{}a", prefix));
+        let b = interner.get_or_intern(format!("{}b", prefix));
+        assert_ne!(a, b);
+        assert_eq!(interner.get(format!("{}b", prefix)), Some(b));
+        assert_eq!(interner.get(format!("{}c", prefix)), None);
+    }
+}
+
+mod bloom_filter {
+    use super::*;
+    use crate::bloom::BloomFilter;
+    use std::{collections::hash_map::RandomState, hash::BuildHasher};
+
+    #[test]
+    fn no_false_negatives() {
+        let mut interner = DefaultStringInterner::new();
+        interner.get_or_intern("before");
+        interner.enable_bloom_filter();
+        for n in 0..1000 {
+            interner.get_or_intern(n.to_string());
+        }
+        assert_eq!(interner.get("before"), Some(Sym::from_usize(0)));
+        for n in 0..1000 {
+            assert_eq!(interner.get(n.to_string()), Some(Sym::from_usize(n + 1)));
+        }
+        for n in 1000..2000 {
+            assert_eq!(interner.get(n.to_string()), None);
+        }
+        interner.remove_str("5");
+        assert_eq!(interner.get("5"), None);
+        let five = interner.get_or_intern("5");
+        assert_eq!(interner.get("5"), Some(five));
+        interner.drain();
+        assert_eq!(interner.get("6"), None);
+        assert_eq!(interner.get_or_intern("6"), Sym::from_usize(0));
+        interner.disable_bloom_filter();
+        assert_eq!(interner.get("6"), Some(Sym::from_usize(0)));
+    }
+
+    #[test]
+    fn false_positive_rate() {
+        let mut bloom = BloomFilter::with_capacity(1000);
+        let state = RandomState::new();
+        for n in 0..bloom.capacity() {
+            bloom.insert(state.hash_one(n));
+        }
+        let false_positives = (bloom.capacity()..bloom.capacity() + 10_000)
+            .filter(|&n| bloom.may_contain(state.hash_one(n)))
+            .count();
+        assert!(
+            false_positives < 1000,
+            "{} false positives",
+            false_positives
+        );
+    }
+}
+
+#[cfg(feature = "nightly")]
+mod allocator {
+    use super::*;
+    use crate::backend::{Backend, SimpleBackend};
+    use std::{
+        alloc::{AllocError, Allocator, Global, Layout},
+        cell::Cell,
+        ptr::NonNull,
+    };
+
+    thread_local! {
+        static ALLOCATED: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Counts the bytes currently allocated on the current thread.
+    #[derive(Debug, Default, Copy, Clone)]
+    struct Counting;
+
+    unsafe impl Allocator for Counting {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            ALLOCATED.with(|allocated| allocated.set(allocated.get() + layout.size()));
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            ALLOCATED.with(|allocated| allocated.set(allocated.get() - layout.size()));
+            Global.deallocate(ptr, layout)
+        }
+    }
+
+    fn allocated() -> usize {
+        ALLOCATED.with(Cell::get)
+    }
+
+    #[test]
+    fn simple_backend() {
+        let mut backend = SimpleBackend::<Sym, Counting>::with_capacity(4);
+        let values = allocated();
+        assert!(values > 0);
+        let foo = backend.intern("foo");
+        assert_eq!(allocated(), values + 3);
+        let bar = backend.intern_string(String::from("bar"));
+        assert_eq!(allocated(), values + 3);
+        assert_eq!(backend.resolve(foo), Some("foo"));
+        assert_eq!(backend.resolve(bar), Some("bar"));
+        assert_eq!(backend.strings_heap_bytes(), 6);
+        assert_eq!(backend.remove(foo), Some(String::from("foo")));
+        assert_eq!(allocated(), values);
+        drop(backend);
+        assert_eq!(allocated(), 0);
+    }
+
+    #[test]
+    fn interner() {
+        let mut interner = StringInterner::<Sym, SimpleBackend<Sym, Counting>>::new();
+        let foo = interner.get_or_intern("foo");
+        let bar = interner.get_or_intern("bar");
+        assert!(allocated() > 0);
+        let clone = interner.clone();
+        assert_eq!(clone.resolve(foo), Some("foo"));
+        assert_eq!(clone.resolve(bar), Some("bar"));
+        assert_eq!(clone, interner);
+        drop((interner, clone));
+        assert_eq!(allocated(), 0);
     }
 }
@@ -0,0 +1,69 @@
+use std::{fmt, sync::Arc};
+
+/// The bulk operations of a `StringInterner` reported to a `Tracer`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BulkOperation {
+    /// `StringInterner::merge`.
+    Merge,
+    /// `StringInterner::compact`.
+    Compact,
+    /// `StringInterner::gc`.
+    Gc,
+    /// Serializing the interner via `serde`.
+    Serialize,
+}
+
+/// Receives the spans and events of a `StringInterner`, see `StringInterner::set_tracer`.
+///
+/// Bulk operations are reported as pairs of `enter` and `exit` calls so that they can be
+/// forwarded as spans to tracing facades such as the `tracing` crate.
+pub trait Tracer: Send + Sync {
+    /// Called when the given bulk operation starts.
+    fn enter(&self, operation: BulkOperation) {
+        let _ = operation;
+    }
+
+    /// Called when the given bulk operation ends, even if it panicked.
+    fn exit(&self, operation: BulkOperation) {
+        let _ = operation;
+    }
+
+    /// Called when a newly interned string takes the slow path because the hash
+    /// of its contents collides with the hash of a previously interned string.
+    fn hash_collision(&self, string: &str) {
+        let _ = string;
+    }
+}
+
+/// A tracer registered via `StringInterner::set_tracer`.
+#[derive(Clone)]
+pub(crate) struct TracerHook(pub Arc<dyn Tracer>);
+
+impl fmt::Debug for TracerHook {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("TracerHook")
+    }
+}
+
+/// Reports the end of a bulk operation to its tracer when dropped.
+pub(crate) struct Span {
+    tracer: Arc<dyn Tracer>,
+    operation: BulkOperation,
+}
+
+impl Span {
+    /// Reports the start of the given bulk operation to the given tracer.
+    pub fn enter(tracer: &TracerHook, operation: BulkOperation) -> Self {
+        tracer.0.enter(operation);
+        Span {
+            tracer: Arc::clone(&tracer.0),
+            operation,
+        }
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        self.tracer.exit(self.operation);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{slice::SliceInterner, DefaultHashBuilder, Symbol};
+
+use std::hash::BuildHasher;
+
+/// A string interner for UTF-16 encoded strings such as those of the Windows API.
+///
+/// Strings are interned and resolved as `[u16]` slices without converting them to UTF-8,
+/// so unpaired surrogates are preserved. All strings are stored contiguously
+/// within a single buffer.
+#[derive(Debug, Clone)]
+pub struct WideStringInterner<S, H = DefaultHashBuilder>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    inner: SliceInterner<u16, S, H>,
+}
+
+impl<S> Default for WideStringInterner<S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn default() -> Self {
+        WideStringInterner::new()
+    }
+}
+
+impl<S> WideStringInterner<S>
+where
+    S: Symbol,
+{
+    /// Creates a new empty `WideStringInterner`.
+    #[inline]
+    pub fn new() -> Self {
+        WideStringInterner::with_hasher(DefaultHashBuilder::default())
+    }
+}
+
+impl<S, H> WideStringInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `WideStringInterner` with the given hasher.
+    #[inline]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        WideStringInterner {
+            inner: SliceInterner::with_hasher(hash_builder),
+        }
+    }
+
+    /// Interns the given UTF-16 encoded string.
+    ///
+    /// Returns a symbol to access it within this interner.
+    #[inline]
+    pub fn get_or_intern<T>(&mut self, val: T) -> S
+    where
+        T: AsRef<[u16]>,
+    {
+        self.inner.get_or_intern(val.as_ref())
+    }
+
+    /// Interns the UTF-16 encoding of the given string.
+    ///
+    /// Returns a symbol to access it within this interner.
+    #[inline]
+    pub fn get_or_intern_str(&mut self, val: &str) -> S {
+        self.get_or_intern(val.encode_utf16().collect::<Vec<_>>())
+    }
+
+    /// Returns the symbol associated with the given UTF-16 encoded string
+    /// if existent, otherwise returns `None`.
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<S>
+    where
+        T: AsRef<[u16]>,
+    {
+        self.inner.get(val.as_ref())
+    }
+
+    /// Returns the symbol associated with the UTF-16 encoding of the given string
+    /// if existent, otherwise returns `None`.
+    #[inline]
+    pub fn get_str(&self, val: &str) -> Option<S> {
+        self.get(val.encode_utf16().collect::<Vec<_>>())
+    }
+
+    /// Returns the UTF-16 encoded string associated with the given symbol if available,
+    /// otherwise returns `None`.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&[u16]> {
+        self.inner.resolve(symbol)
+    }
+
+    /// Returns the string associated with the given symbol decoded to UTF-8 if available,
+    /// otherwise returns `None`.
+    ///
+    /// Unpaired surrogates are replaced with `U+FFFD REPLACEMENT CHARACTER`.
+    #[inline]
+    pub fn resolve_lossy(&self, symbol: S) -> Option<String> {
+        self.resolve(symbol).map(String::from_utf16_lossy)
+    }
+
+    /// Returns the number of uniquely interned strings within this interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the string interner holds no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the symbols and UTF-16 encoded strings in insertion order.
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (S, &[u16])> + ExactSizeIterator + '_ {
+        self.inner.iter()
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{backend::Backend, StringInterner, Symbol};
+
+use std::{fmt, hash::BuildHasher, mem};
+
+/// Builds a string in place and interns it once finished.
+///
+/// Created by `StringInterner::writer`.
+/// Writes go into a reusable buffer of the interner, so building a string
+/// that has already been interned does not allocate.
+#[derive(Debug)]
+pub struct InternWriter<'a, S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    interner: &'a mut StringInterner<S, B, H>,
+    buffer: String,
+}
+
+impl<S, B, H> StringInterner<S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Returns a writer that interns the string written to it once it is finished.
+    #[inline]
+    pub fn writer(&mut self) -> InternWriter<'_, S, B, H> {
+        let mut buffer = mem::take(&mut self.scratch);
+        buffer.clear();
+        InternWriter {
+            interner: self,
+            buffer,
+        }
+    }
+}
+
+impl<'a, S, B, H> InternWriter<'a, S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    /// Returns the string written so far.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Interns the written string.
+    ///
+    /// Returns a symbol to access it within the interner.
+    pub fn finish(mut self) -> S {
+        let buffer = mem::take(&mut self.buffer);
+        let sym = self.interner.get_or_intern(&buffer);
+        self.interner.scratch = buffer;
+        sym
+    }
+}
+
+impl<'a, S, B, H> fmt::Write for InternWriter<'a, S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.buffer.push_str(s);
+        Ok(())
+    }
+}
+
+impl<'a, S, B, H> Drop for InternWriter<'a, S, B, H>
+where
+    S: Symbol,
+    B: Backend<S>,
+    H: BuildHasher,
+{
+    fn drop(&mut self) {
+        // Hands the buffer back for reuse if the writer was not finished.
+        if self.buffer.capacity() > 0 {
+            self.interner.scratch = mem::take(&mut self.buffer);
+        }
+    }
+}